@@ -35,6 +35,9 @@ export type ListGradient =
     { Same: Gradient } |
     { Alternating: Color[] };
 
+/** Describes the color space that the colors of a gradient are interpolated in. */
+export type GradientColorSpace = "Srgb" | "Linear" | "Oklab";
+
 /**
  * The ID of an image that can be used for looking up an image in an image
  * cache.
@@ -259,6 +262,12 @@ export enum Event {
     LoadingTimesSet = 16,
     /** A custom variable has been set. */
     CustomVariableSet = 17,
+    /** The time of a custom timing method has been set. */
+    CustomTimeSet = 18,
+    /** The active attempt has been reconciled with an edited run. */
+    AttemptReconciled = 19,
+    /** The most recent reset has been undone. */
+    ResetUndone = 20,
 }
 
 /** An error that occurred when a command was being processed. */
@@ -297,6 +306,19 @@ export enum CommandError {
     TimerPaused = -16,
     /** The runner decided to not reset the run. */
     RunnerDecidedAgainstReset = -17,
+    /** The requested custom timing method doesn't exist. */
+    CustomTimingMethodDoesntExist = -18,
+    /**
+     * The split happened too soon after the previous split, so it got
+     * rejected as a duplicate.
+     */
+    SplitWithinConfirmationWindow = -19,
+    /** The amount of split times doesn't match the amount of segments. */
+    SplitCountMismatch = -20,
+    /** The split times are not in increasing order. */
+    SplitTimesNotIncreasing = -21,
+    /** There is no reset to undo. */
+    NoResetToUndo = -22,
 }
 
 /** The result of a command that was processed. */
@@ -394,9 +416,19 @@ export interface SplitsComponentStateJson {
     /**
      * The column labels to visualize about the list of splits. If this is
      * `null`, no labels are supposed to be visualized. The list is specified
-     * from right to left.
+     * from right to left. The column labels form the header of the list. They
+     * are not part of the rows in `splits` and don't depend on which segments
+     * are currently scrolled into view.
      */
     column_labels: string[] | null,
+    /**
+     * The labels of the groups of columns to visualize above the column
+     * labels. Each label spans one or more adjacent columns. If this is
+     * `null`, no column belongs to any group or no column labels are supposed
+     * to be visualized. Renderers that don't support column groups may ignore
+     * them entirely.
+     */
+    column_group_labels: ColumnGroupLabel[] | null,
     /** The list of all the segments to visualize. */
     splits: SplitStateJson[],
     /**
@@ -406,9 +438,18 @@ export interface SplitsComponentStateJson {
      * splits component.
      */
     has_icons: boolean,
+    /**
+     * Specifies whether any of the columns may show secondary values below
+     * their values. If this is the case, the room for them is included in the
+     * height of the rows.
+     */
+    has_sub_values: boolean,
     /**
      * Specifies whether thin separators should be shown between the individual
      * segments shown by the component.
+     *
+     * @deprecated This doesn't describe which of the segments the separators
+     * are shown after. Use `show_separator_after` of each split instead.
      */
     show_thin_separators: boolean,
     /**
@@ -416,6 +457,27 @@ export interface SplitsComponentStateJson {
      * the last segment provided.
      */
     show_final_separator: boolean,
+    /**
+     * The number of segments that are hidden between the segments in the
+     * scrolling window and the last segment, if the last segment is always
+     * shown. Renderers can use this to indicate how many segments are left
+     * out. This is 0 if the last segment is directly adjacent to the segments
+     * shown before it or if it isn't always shown.
+     */
+    hidden_segments_before_last_split: number,
+    /**
+     * The index of the first segment shown. If the segments are shown in a
+     * scrolling window, this is where the window starts, so together with the
+     * number of rows it describes the part of the run that is visible. If
+     * only specific segments are shown, this is the index of the first of
+     * them.
+     */
+    first_visible_index: number,
+    /**
+     * Specifies whether the last segment is pinned to the end of the list,
+     * because it is always shown, but it is outside of the scrolling window.
+     */
+    last_split_is_pinned: boolean,
     /**
      * Specifies whether to display each split as two rows, with the segment
      * name being in one row and the times being in the other.
@@ -426,6 +488,45 @@ export interface SplitsComponentStateJson {
      * being the current segment.
      */
     current_split_gradient: Gradient,
+    /**
+     * The height of each row of segments. The height is specified relative to
+     * the height of a component that shows a single line of text. This is
+     * larger if the segments are displayed as two rows or if the columns may
+     * show secondary values below their values.
+     */
+    row_height_units: number,
+    /**
+     * The height of the row that shows the column labels. The height is
+     * specified relative to the height of a component that shows a single line
+     * of text. This is 0 if no column labels are supposed to be visualized.
+     */
+    header_height_units: number,
+    /**
+     * Specifies whether the header, consisting of the column labels and the
+     * column group labels, is supposed to stay pinned at the top of the list.
+     * This is relevant for renderers that scroll the rows of the list
+     * themselves, for example when all the segments are shown.
+     */
+    sticky_header: boolean,
+    /**
+     * Specifies whether the comparison changed since the last time the state
+     * was updated. This can be used to highlight the columns whose values
+     * suddenly change because of it.
+     */
+    comparison_changed: boolean,
+    /**
+     * The number of completed segments that were split ahead of the current
+     * comparison. This covers all of the segments, not just the ones that
+     * are currently visible. Skipped segments and segments without a
+     * comparison time are neither counted as ahead nor as behind.
+     */
+    splits_ahead: number,
+    /**
+     * The number of completed segments that were split behind the current
+     * comparison. This covers all of the segments, not just the ones that
+     * are currently visible.
+     */
+    splits_behind: number,
 }
 
 /** The state object that describes a single segment's information to visualize. */
@@ -448,6 +549,15 @@ export interface SplitStateJson {
      * on.
      */
     is_current_split: boolean,
+    /**
+     * Specifies whether a thin separator should be shown after this segment.
+     */
+    show_separator_after: boolean,
+    /**
+     * The indicator to show next to the segment's name. This is only ever
+     * shown for the current segment and only if the setting is enabled.
+     */
+    indicator: SplitIndicator,
     /**
      * The index of the segment based on all the segments of the run. This may
      * differ from the index of this `SplitStateJson` in the
@@ -455,6 +565,60 @@ export interface SplitStateJson {
      * showing only a subset of segments. Each index is guaranteed to be unique.
      */
     index: number,
+    /**
+     * Describes if this segment is the segment of the Personal Best that lost
+     * the most time compared to its best segment time. This is only ever set
+     * if the setting is enabled.
+     */
+    is_pb_worst_segment: boolean,
+    /**
+     * The medal awarded to this segment based on how close its segment time
+     * in the active attempt was to its best segment time. This is only ever
+     * set for completed segments and only if medal bands are configured.
+     */
+    medal: MedalTier | null,
+    /**
+     * The color to use for the background of this segment instead of the
+     * background of the list. This is only ever set if the segment is
+     * awarded a medal and the backgrounds are supposed to be colored based
+     * on the medals.
+     */
+    background: Color | null,
+    /**
+     * The reason why this row is blank. This is `null` for rows that show a
+     * segment.
+     */
+    blank_reason: BlankReason | null,
+    /**
+     * The name of the comparison that the coloring of the row as a whole is
+     * based on. This is always the current comparison, regardless of the
+     * comparison overrides of the individual columns, which report their own
+     * comparison instead. It is empty for blank rows.
+     */
+    resolved_comparison: string,
+    /**
+     * A summary of the segment that renderers with support for hovering can
+     * show as a tooltip. It consists of the full name of the segment, its
+     * split time, its delta and whether it is a best segment. It is empty for
+     * blank rows and if tooltips are disabled.
+     */
+    tooltip: string,
+}
+
+/**
+ * Describes the label of a group of adjacent columns to visualize above their
+ * column labels.
+ */
+export interface ColumnGroupLabel {
+    /** The name of the group. */
+    name: string,
+    /**
+     * The index of the first column that belongs to the group. The columns are
+     * counted from right to left.
+     */
+    start_column: number,
+    /** The amount of adjacent columns the label spans. */
+    column_count: number,
 }
 
 /** Describes the state of a single segment's column to visualize. */
@@ -470,6 +634,38 @@ export interface SplitColumnState {
      * updated. This can be used for rendering optimizations.
      */
     updates_frequently: boolean,
+    /** How the value is supposed to be aligned within the column. */
+    alignment: ColumnAlignment,
+    /**
+     * The minimum width of the column in relative layout units. This is
+     * `null` if the column is supposed to be sized based on its values.
+     */
+    min_width: number | null,
+    /**
+     * The maximum width of the column in relative layout units. Values that
+     * don't fit are supposed to be ellipsized. This is `null` if the column
+     * is allowed to grow to fit its values.
+     */
+    max_width: number | null,
+    /**
+     * The name of the comparison the value is based on. This is the column's
+     * comparison override if the run has that comparison, and the current
+     * comparison otherwise. It is empty for columns that don't use a
+     * comparison, such as variable columns.
+     */
+    resolved_comparison: string,
+    /**
+     * A secondary value that is supposed to be shown smaller below the value.
+     * This is `null` if the column doesn't show a secondary value.
+     */
+    sub_value: string | null,
+    /**
+     * Specifies whether the value is the comparison's split time that is
+     * shown as a ghost on the current segment, until the value gets updated
+     * with the current attempt's information. A ghost is supposed to be
+     * visualized more subtly than a regular value.
+     */
+    is_ghost: boolean,
 }
 
 /**
@@ -673,6 +869,11 @@ export interface DetailedTimerComponentComparisonStateJson {
 export interface LayoutEditorStateJson {
     /** The name of all the components in the layout. */
     components: string[],
+    /**
+     * Whether each of the components in the layout is visible. Hidden
+     * components are not visualized.
+     */
+    component_visibility: boolean[],
     /** Describes which actions are currently available. */
     buttons: LayoutEditorButtonsJson,
     /** The index of the currently selected component. */
@@ -739,20 +940,31 @@ export type SettingsDescriptionValueJson =
     { Bool: boolean } |
     { UInt: number } |
     { Int: number } |
+    { Float: number } |
     { String: string } |
     { OptionalString: string | null } |
+    { OptionalFloat: number | null } |
     { Accuracy: AccuracyJson } |
+    { OptionalAccuracy: AccuracyJson | null } |
     { DigitsFormat: DigitsFormatJson } |
     { OptionalTimingMethod: TimingMethodJson | null } |
     { Color: Color } |
     { OptionalColor: Color | null } |
     { Gradient: Gradient } |
     { ListGradient: ListGradient } |
+    { GradientColorSpace: GradientColorSpace } |
     { Alignment: Alignment } |
     { ColumnKind: ColumnKind } |
     { ColumnStartWith: ColumnStartWith } |
     { ColumnUpdateWith: ColumnUpdateWith } |
     { ColumnUpdateTrigger: ColumnUpdateTrigger } |
+    { OptionalColumnAlignment: ColumnAlignment | null } |
+    { OptionalSignStyle: SignStyle | null } |
+    { SeparatorMode: SeparatorMode } |
+    { SkippedSegmentDelta: SkippedSegmentDelta } |
+    { CurrentSplitAnchor: CurrentSplitAnchor } |
+    { OptionalMedalBands: MedalBands | null } |
+    { OptionalMedalColors: MedalColors | null } |
     { Hotkey: string } |
     { LayoutDirection: LayoutDirection } |
     { Font: Font | null } |
@@ -801,7 +1013,63 @@ export type ColumnUpdateWith =
     "DeltaWithFallback" |
     "SegmentTime" |
     "SegmentDelta" |
-    "SegmentDeltaWithFallback";
+    "SegmentDeltaWithFallback" |
+    "DeltaPercent";
+
+/** Specifies how the values of a column are aligned within the column. */
+export type ColumnAlignment = "Start" | "Center" | "End";
+
+/** Specifies how the signs of the deltas shown in a column are displayed. */
+export type SignStyle = "Always" | "NegativeOnly" | "None";
+
+/** An indicator that is shown next to a segment's name to highlight it. */
+export type SplitIndicator = "None" | "Arrow";
+
+/**
+ * The reason why a row of the Splits Component is blank. Padding rows fill up
+ * the remaining space and are supposed to be empty. Gap rows are shown in
+ * place of hidden segments and are supposed to show an ellipsis.
+ */
+export type BlankReason = "Padding" | "Gap";
+
+/**
+ * The time bands that determine which medal a segment is awarded. Each band is
+ * the maximum amount of time a segment may be slower than its best segment
+ * time to be awarded the respective medal, stored as a string of seconds.
+ */
+export interface MedalBands {
+    gold: string,
+    silver: string,
+    bronze: string,
+}
+
+/** The colors that are associated with each of the medals. */
+export interface MedalColors {
+    gold: Color,
+    silver: Color,
+    bronze: Color,
+}
+
+/** A medal that a segment can be awarded based on the medal bands. */
+export type MedalTier = "Gold" | "Silver" | "Bronze";
+
+/**
+ * Specifies between which rows of the Splits Component thin separators are
+ * shown.
+ */
+export type SeparatorMode = "None" | "All" | "SectionBoundaries";
+
+/**
+ * Specifies how columns of the Splits Component that show segment deltas
+ * display the delta of a segment that follows a skipped segment.
+ */
+export type SkippedSegmentDelta = "Combined" | "Empty" | "Annotated";
+
+/**
+ * Specifies where the Splits Component keeps the current segment within the
+ * scrolling window of segments.
+ */
+export type CurrentSplitAnchor = "Auto" | "Top" | "Center";
 
 /** Specifies when a column's value gets updated. */
 export type ColumnUpdateTrigger =
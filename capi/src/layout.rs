@@ -149,6 +149,23 @@ pub extern "C" fn Layout_push(this: &mut Layout, component: OwnedComponent) {
     this.push(*component);
 }
 
+/// Checks whether the component at the index provided is visible. Hidden
+/// components stay part of the layout, but they are not part of the layout's
+/// state and thus are not visualized. You may not provide an invalid index.
+#[unsafe(no_mangle)]
+pub extern "C" fn Layout_is_component_visible(this: &Layout, index: usize) -> bool {
+    this.is_component_visible(index)
+}
+
+/// Shows or hides the component at the index provided. Hidden components keep
+/// their settings and stay part of the layout, but they are skipped when
+/// calculating the layout's state. The visibility of the components is not
+/// stored when the layout is saved. You may not provide an invalid index.
+#[unsafe(no_mangle)]
+pub extern "C" fn Layout_set_component_visible(this: &mut Layout, index: usize, visible: bool) {
+    this.set_component_visible(index, visible);
+}
+
 /// Scrolls up all the components in the layout that can be scrolled up.
 #[unsafe(no_mangle)]
 pub extern "C" fn Layout_scroll_up(this: &mut Layout) {
@@ -144,6 +144,13 @@ pub extern "C" fn LayoutEditor_duplicate_component(this: &mut LayoutEditor) {
     this.duplicate_component();
 }
 
+/// Shows or hides the selected component. Hidden components keep their
+/// settings and stay part of the layout, but they are not visualized.
+#[unsafe(no_mangle)]
+pub extern "C" fn LayoutEditor_set_component_visible(this: &mut LayoutEditor, visible: bool) {
+    this.set_component_visible(visible);
+}
+
 /// Sets a setting's value of the selected component by its setting index
 /// to the given value.
 ///
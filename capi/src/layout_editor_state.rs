@@ -28,6 +28,15 @@ pub extern "C" fn LayoutEditorState_component_text(
     output_str(&this.components[index])
 }
 
+/// Returns whether the component at the specified index is visible.
+#[unsafe(no_mangle)]
+pub extern "C" fn LayoutEditorState_component_visible(
+    this: &LayoutEditorState,
+    index: usize,
+) -> bool {
+    this.component_visibility[index]
+}
+
 /// Returns a bitfield corresponding to which buttons are active.
 ///
 /// The bits are as follows:
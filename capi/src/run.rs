@@ -133,6 +133,22 @@ pub extern "C" fn Run_game_icon_len(this: &Run) -> usize {
     this.game_icon().data().len()
 }
 
+/// Accesses the unique identifier of this Run. It stays the same across
+/// saving and loading the splits, even if the file gets renamed. If the Run
+/// doesn't have an identifier yet, an empty string is returned.
+#[unsafe(no_mangle)]
+pub extern "C" fn Run_id(this: &Run) -> *const c_char {
+    output_str(this.id().unwrap_or_default())
+}
+
+/// Accesses the unique identifier of this Run and generates a new one if it
+/// doesn't have one yet. This is meant to be called before saving the splits,
+/// so the identifier gets stored.
+#[unsafe(no_mangle)]
+pub extern "C" fn Run_ensure_id(this: &mut Run) -> *const c_char {
+    output_str(this.ensure_id())
+}
+
 /// Accesses the name of the category this Run is for.
 #[unsafe(no_mangle)]
 pub extern "C" fn Run_category_name(this: &Run) -> *const c_char {
@@ -3,15 +3,20 @@
 
 use crate::{Json, output_vec, str};
 use livesplit_core::{
-    TimingMethod,
+    TimeSpan, TimingMethod,
     component::{
-        splits::{ColumnStartWith, ColumnUpdateTrigger, ColumnUpdateWith},
+        splits::{
+            ColumnAlignment, ColumnStartWith, ColumnUpdateTrigger, ColumnUpdateWith, SeparatorMode,
+            CurrentSplitAnchor, MedalBands, MedalColors, SignStyle,
+            SkippedSegmentDelta,
+        },
         timer::DeltaGradient,
     },
     layout::LayoutDirection,
     settings::{
         Alignment, BackgroundImage, Color, ColumnKind, Font, FontStretch, FontStyle, FontWeight,
-        Gradient, ImageId, LayoutBackground, ListGradient, Value as SettingValue,
+        Gradient, GradientColorSpace, ImageId, LayoutBackground, ListGradient,
+        Value as SettingValue,
     },
     timing::formatter::{Accuracy, DigitsFormat},
 };
@@ -54,6 +59,12 @@ pub extern "C" fn SettingValue_from_int(value: i32) -> OwnedSettingValue {
     Box::new((value as i64).into())
 }
 
+/// Creates a new setting value from a floating point number.
+#[unsafe(no_mangle)]
+pub extern "C" fn SettingValue_from_float(value: f64) -> OwnedSettingValue {
+    Box::new(value.into())
+}
+
 /// Creates a new setting value from a string.
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn SettingValue_from_string(value: *const c_char) -> OwnedSettingValue {
@@ -81,6 +92,19 @@ pub extern "C" fn SettingValue_from_optional_empty_string() -> OwnedSettingValue
     Box::new(None::<String>.into())
 }
 
+/// Creates a new setting value from a floating point number that has the type
+/// `optional float`.
+#[unsafe(no_mangle)]
+pub extern "C" fn SettingValue_from_optional_float(value: f64) -> OwnedSettingValue {
+    Box::new(Some(value).into())
+}
+
+/// Creates a new empty setting value that has the type `optional float`.
+#[unsafe(no_mangle)]
+pub extern "C" fn SettingValue_from_optional_empty_float() -> OwnedSettingValue {
+    Box::new(None::<f64>.into())
+}
+
 /// Creates a new setting value from an accuracy name. If it doesn't match a
 /// known accuracy, <NULL> is returned.
 #[unsafe(no_mangle)]
@@ -99,6 +123,34 @@ pub unsafe extern "C" fn SettingValue_from_accuracy(
     Some(Box::new(value.into()))
 }
 
+/// Creates a new setting value from an accuracy name with the type `optional
+/// accuracy`. If it doesn't match a known accuracy, <NULL> is returned.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn SettingValue_from_optional_accuracy(
+    value: *const c_char,
+) -> NullableOwnedSettingValue {
+    if value.is_null() {
+        Some(Box::new(None::<Accuracy>.into()))
+    } else {
+        // SAFETY: The caller guarantees that `value` is valid.
+        let value = unsafe { str(value) };
+        let value = match value {
+            "Seconds" => Accuracy::Seconds,
+            "Tenths" => Accuracy::Tenths,
+            "Hundredths" => Accuracy::Hundredths,
+            "Milliseconds" => Accuracy::Milliseconds,
+            _ => return None,
+        };
+        Some(Box::new(Some(value).into()))
+    }
+}
+
+/// Creates a new empty setting value with the type `optional accuracy`.
+#[unsafe(no_mangle)]
+pub extern "C" fn SettingValue_from_optional_empty_accuracy() -> OwnedSettingValue {
+    Box::new(None::<Accuracy>.into())
+}
+
 /// Creates a new setting value from a digits format name. If it doesn't match a
 /// known digits format, <NULL> is returned.
 #[unsafe(no_mangle)]
@@ -223,6 +275,23 @@ pub extern "C" fn SettingValue_from_alternating_gradient(
     )
 }
 
+/// Creates a new setting value from the gradient color space name provided. If
+/// it doesn't match a known gradient color space, <NULL> is returned.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn SettingValue_from_gradient_color_space(
+    value: *const c_char,
+) -> NullableOwnedSettingValue {
+    // SAFETY: The caller guarantees that `value` is valid.
+    let value = unsafe { str(value) };
+    let value = match value {
+        "Srgb" => GradientColorSpace::Srgb,
+        "Linear" => GradientColorSpace::Linear,
+        "Oklab" => GradientColorSpace::Oklab,
+        _ => return None,
+    };
+    Some(Box::new(value.into()))
+}
+
 /// Creates a new setting value from the alignment name provided. If it doesn't
 /// match a known alignment, <NULL> is returned.
 #[unsafe(no_mangle)]
@@ -290,6 +359,7 @@ pub unsafe extern "C" fn SettingValue_from_column_update_with(
         "SegmentTime" => ColumnUpdateWith::SegmentTime,
         "SegmentDelta" => ColumnUpdateWith::SegmentDelta,
         "SegmentDeltaWithFallback" => ColumnUpdateWith::SegmentDeltaWithFallback,
+        "DeltaPercent" => ColumnUpdateWith::DeltaPercent,
         _ => return None,
     };
     Some(Box::new(value.into()))
@@ -312,6 +382,172 @@ pub unsafe extern "C" fn SettingValue_from_column_update_trigger(
     Some(Box::new(value.into()))
 }
 
+/// Creates a new setting value from the separator mode. If it doesn't match a
+/// known separator mode, <NULL> is returned.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn SettingValue_from_separator_mode(
+    value: *const c_char,
+) -> NullableOwnedSettingValue {
+    // SAFETY: The caller guarantees that `value` is valid.
+    let value = unsafe { str(value) };
+    let value = match value {
+        "None" => SeparatorMode::None,
+        "All" => SeparatorMode::All,
+        "SectionBoundaries" => SeparatorMode::SectionBoundaries,
+        _ => return None,
+    };
+    Some(Box::new(value.into()))
+}
+
+/// Creates a new setting value from the way segment deltas following a
+/// skipped segment are shown. If it doesn't match a known option, <NULL> is
+/// returned.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn SettingValue_from_skipped_segment_delta(
+    value: *const c_char,
+) -> NullableOwnedSettingValue {
+    // SAFETY: The caller guarantees that `value` is valid.
+    let value = unsafe { str(value) };
+    let value = match value {
+        "Combined" => SkippedSegmentDelta::Combined,
+        "Empty" => SkippedSegmentDelta::Empty,
+        "Annotated" => SkippedSegmentDelta::Annotated,
+        _ => return None,
+    };
+    Some(Box::new(value.into()))
+}
+
+/// Creates a new setting value from the position the current segment is kept
+/// at in the scrolling window of segments. If it doesn't match a known option,
+/// <NULL> is returned.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn SettingValue_from_current_split_anchor(
+    value: *const c_char,
+) -> NullableOwnedSettingValue {
+    // SAFETY: The caller guarantees that `value` is valid.
+    let value = unsafe { str(value) };
+    let value = match value {
+        "Auto" => CurrentSplitAnchor::Auto,
+        "Top" => CurrentSplitAnchor::Top,
+        "Center" => CurrentSplitAnchor::Center,
+        _ => return None,
+    };
+    Some(Box::new(value.into()))
+}
+
+/// Creates a new setting value from the thresholds of the gold, silver and
+/// bronze medals in seconds with the type `optional medal bands`. Thresholds
+/// that are out of order are raised to match the previous medal's threshold.
+#[unsafe(no_mangle)]
+pub extern "C" fn SettingValue_from_optional_medal_bands(
+    gold: f64,
+    silver: f64,
+    bronze: f64,
+) -> OwnedSettingValue {
+    Box::new(
+        Some(MedalBands::new(
+            TimeSpan::from_seconds(gold),
+            TimeSpan::from_seconds(silver),
+            TimeSpan::from_seconds(bronze),
+        ))
+        .into(),
+    )
+}
+
+/// Creates a new empty setting value with the type `optional medal bands`.
+#[unsafe(no_mangle)]
+pub extern "C" fn SettingValue_from_optional_empty_medal_bands() -> OwnedSettingValue {
+    Box::new(None::<MedalBands>.into())
+}
+
+/// Creates a new setting value from the colors of the gold, silver and bronze
+/// medals provided as RGBA with the type `optional medal colors`.
+#[unsafe(no_mangle)]
+pub extern "C" fn SettingValue_from_optional_medal_colors(
+    gold_r: f32,
+    gold_g: f32,
+    gold_b: f32,
+    gold_a: f32,
+    silver_r: f32,
+    silver_g: f32,
+    silver_b: f32,
+    silver_a: f32,
+    bronze_r: f32,
+    bronze_g: f32,
+    bronze_b: f32,
+    bronze_a: f32,
+) -> OwnedSettingValue {
+    Box::new(
+        Some(MedalColors {
+            gold: Color::rgba(gold_r, gold_g, gold_b, gold_a),
+            silver: Color::rgba(silver_r, silver_g, silver_b, silver_a),
+            bronze: Color::rgba(bronze_r, bronze_g, bronze_b, bronze_a),
+        })
+        .into(),
+    )
+}
+
+/// Creates a new empty setting value with the type `optional medal colors`.
+#[unsafe(no_mangle)]
+pub extern "C" fn SettingValue_from_optional_empty_medal_colors() -> OwnedSettingValue {
+    Box::new(None::<MedalColors>.into())
+}
+
+/// Creates a new setting value from the column alignment with the type
+/// `optional column alignment`. If it doesn't match a known column alignment,
+/// <NULL> is returned.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn SettingValue_from_optional_column_alignment(
+    value: *const c_char,
+) -> NullableOwnedSettingValue {
+    if value.is_null() {
+        Some(Box::new(None::<ColumnAlignment>.into()))
+    } else {
+        // SAFETY: The caller guarantees that `value` is valid.
+        let value = unsafe { str(value) };
+        let value = match value {
+            "Start" => ColumnAlignment::Start,
+            "Center" => ColumnAlignment::Center,
+            "End" => ColumnAlignment::End,
+            _ => return None,
+        };
+        Some(Box::new(Some(value).into()))
+    }
+}
+
+/// Creates a new empty setting value with the type `optional column alignment`.
+#[unsafe(no_mangle)]
+pub extern "C" fn SettingValue_from_optional_empty_column_alignment() -> OwnedSettingValue {
+    Box::new(None::<ColumnAlignment>.into())
+}
+
+/// Creates a new setting value from the sign style with the type `optional
+/// sign style`. If it doesn't match a known sign style, <NULL> is returned.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn SettingValue_from_optional_sign_style(
+    value: *const c_char,
+) -> NullableOwnedSettingValue {
+    if value.is_null() {
+        Some(Box::new(None::<SignStyle>.into()))
+    } else {
+        // SAFETY: The caller guarantees that `value` is valid.
+        let value = unsafe { str(value) };
+        let value = match value {
+            "Always" => SignStyle::Always,
+            "NegativeOnly" => SignStyle::NegativeOnly,
+            "None" => SignStyle::None,
+            _ => return None,
+        };
+        Some(Box::new(Some(value).into()))
+    }
+}
+
+/// Creates a new empty setting value with the type `optional sign style`.
+#[unsafe(no_mangle)]
+pub extern "C" fn SettingValue_from_optional_empty_sign_style() -> OwnedSettingValue {
+    Box::new(None::<SignStyle>.into())
+}
+
 /// Creates a new setting value from the layout direction. If it doesn't
 /// match a known layout direction, <NULL> is returned.
 #[unsafe(no_mangle)]
@@ -74,6 +74,15 @@ pub extern "C" fn SplitsComponent_scroll_down(this: &mut SplitsComponent) {
     this.scroll_down();
 }
 
+/// Scrolls the window of the segments that are shown just far enough for the
+/// segment with the given index to be shown the next time the state is
+/// updated. Indices beyond the last segment scroll to the last segment. If the
+/// segment is already shown, the window doesn't move.
+#[unsafe(no_mangle)]
+pub extern "C" fn SplitsComponent_scroll_to_segment(this: &mut SplitsComponent, index: usize) {
+    this.scroll_to_segment(index);
+}
+
 /// The amount of segments to show in the list at any given time. If this is
 /// set to 0, all the segments are shown. If this is set to a number lower
 /// than the total amount of segments, only a certain window of all the
@@ -122,6 +122,23 @@ pub extern "C" fn Timer_start(this: &mut Timer) -> i32 {
     convert(this.start())
 }
 
+/// Starts the Timer if there is no attempt in progress, without incrementing
+/// the Run's attempt count. This is useful for attempts that aren't supposed to
+/// count, such as warm-ups. If there already is an attempt in progress, nothing
+/// happens.
+#[unsafe(no_mangle)]
+pub extern "C" fn Timer_start_without_counting(this: &mut Timer) -> i32 {
+    convert(this.start_without_counting())
+}
+
+/// Sets the amount of runs that have been attempted with the Run. This can be
+/// used to correct the attempt count, as the attempt count is otherwise only
+/// incremented when starting the Timer.
+#[unsafe(no_mangle)]
+pub extern "C" fn Timer_set_attempt_count(this: &mut Timer, attempts: u32) {
+    this.set_attempt_count(attempts);
+}
+
 /// If an attempt is in progress, stores the current time as the time of the
 /// current split. The attempt ends if the last split time is stored.
 #[unsafe(no_mangle)]
@@ -151,6 +168,16 @@ pub extern "C" fn Timer_undo_split(this: &mut Timer) -> i32 {
     convert(this.undo_split())
 }
 
+/// Removes the split time from the last split if an attempt is in progress
+/// and there is a previous split. If the attempt is still on its first split,
+/// the attempt is reset instead. If the splits are to be updated, the
+/// information of the attempt is stored in the Run's history when resetting.
+/// Nothing happens if there is no attempt in progress.
+#[unsafe(no_mangle)]
+pub extern "C" fn Timer_undo_split_or_reset(this: &mut Timer, update_splits: bool) -> i32 {
+    convert(this.undo_split_or_reset(update_splits))
+}
+
 /// Checks whether the current attempt has new best segment times in any of the
 /// segments (for both TimingMethods) or a new Personal Best (for the current
 /// TimingMethod). This can be used to ask the user whether to update the splits
@@ -177,6 +204,20 @@ pub extern "C" fn Timer_reset_and_set_attempt_as_pb(this: &mut Timer) -> i32 {
     convert(this.reset_and_set_attempt_as_pb())
 }
 
+/// Undoes the most recent reset, restoring the attempt that was in progress.
+/// This is only possible until a new attempt is started or the Run is
+/// replaced.
+#[unsafe(no_mangle)]
+pub extern "C" fn Timer_undo_reset(this: &mut Timer) -> i32 {
+    convert(this.undo_reset())
+}
+
+/// Returns whether the most recent reset can be undone.
+#[unsafe(no_mangle)]
+pub extern "C" fn Timer_can_undo_reset(this: &Timer) -> bool {
+    this.can_undo_reset()
+}
+
 /// Pauses an active attempt that is not paused.
 #[unsafe(no_mangle)]
 pub extern "C" fn Timer_pause(this: &mut Timer) -> i32 {
@@ -293,7 +334,9 @@ pub extern "C" fn Timer_is_game_time_paused(this: &Timer) -> bool {
 }
 
 /// Pauses the Game Timer such that it doesn't automatically increment
-/// similar to Real Time.
+/// similar to Real Time. If Game Time is not initialized yet, it gets
+/// initialized first, such that the Game Time is frozen at the current Real
+/// Time.
 #[unsafe(no_mangle)]
 pub extern "C" fn Timer_pause_game_time(this: &mut Timer) -> i32 {
     convert(this.pause_game_time())
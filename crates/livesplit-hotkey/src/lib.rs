@@ -94,6 +94,24 @@ impl Hook {
         self.0.unregister(hotkey)
     }
 
+    /// Simulates the hotkey being pressed. If the hotkey is registered, its
+    /// callback is called the same way as it would be for real input. This is
+    /// meant for testing logic that is driven by hotkeys without requiring any
+    /// actual input. On platforms that don't support hotkeys, no hotkey is ever
+    /// registered, so this always fails.
+    #[cfg(any(test, feature = "simulate"))]
+    pub fn simulate_press(&self, hotkey: Hotkey) -> Result<()> {
+        self.0.simulate(hotkey, true)
+    }
+
+    /// Simulates the hotkey being released. Callbacks are only ever called when
+    /// a hotkey is pressed, so this only checks whether the hotkey is
+    /// registered.
+    #[cfg(any(test, feature = "simulate"))]
+    pub fn simulate_release(&self, hotkey: Hotkey) -> Result<()> {
+        self.0.simulate(hotkey, false)
+    }
+
     /// On the web you can use this to listen to keyboard events on an
     /// additional child window as well.
     #[cfg(all(target_family = "wasm", feature = "wasm-web"))]
@@ -175,6 +193,41 @@ mod tests {
         hook.unregister(KeyCode::Numpad1.into()).unwrap();
     }
 
+    #[test]
+    fn simulate() {
+        use std::sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        };
+
+        let hook = Hook::new().unwrap();
+        let hotkey = KeyCode::Numpad1.with_modifiers(Modifiers::SHIFT);
+        let presses = Arc::new(AtomicUsize::new(0));
+
+        let counter = presses.clone();
+        hook.register(hotkey, move || {
+            counter.fetch_add(1, Ordering::SeqCst);
+        })
+        .unwrap();
+
+        hook.simulate_press(hotkey).unwrap();
+        hook.simulate_release(hotkey).unwrap();
+        hook.simulate_press(hotkey).unwrap();
+        assert_eq!(presses.load(Ordering::SeqCst), 2);
+
+        assert!(matches!(
+            hook.simulate_press(KeyCode::Numpad1.into()),
+            Err(Error::NotRegistered)
+        ));
+
+        hook.unregister(hotkey).unwrap();
+        assert!(matches!(
+            hook.simulate_press(hotkey),
+            Err(Error::NotRegistered)
+        ));
+        assert_eq!(presses.load(Ordering::SeqCst), 2);
+    }
+
     #[test]
     fn resolve() {
         let hook = Hook::new().unwrap();
@@ -335,6 +335,17 @@ pub fn new() -> Result<Hook> {
                             Message::Resolve(key_code, promise) => {
                                 promise.set(resolve(&mut xlib, &mut display, key_code))
                             }
+                            #[cfg(any(test, feature = "simulate"))]
+                            Message::Simulate(key, pressed, promise) => promise.set(
+                                code_for(key.key_code)
+                                    .and_then(|k| hotkeys.get_mut(&(k, key.modifiers)))
+                                    .map(|callback| {
+                                        if pressed {
+                                            callback();
+                                        }
+                                    })
+                                    .ok_or(crate::Error::NotRegistered),
+                            ),
                             Message::End => {
                                 break 'event_loop;
                             }
@@ -45,6 +45,8 @@ enum Message {
     ),
     Unregister(Hotkey, Promise<Result<()>>),
     Resolve(KeyCode, Promise<Option<char>>),
+    #[cfg(any(test, feature = "simulate"))]
+    Simulate(Hotkey, bool, Promise<Result<()>>),
     End,
 }
 
@@ -117,6 +119,19 @@ impl Hook {
         future.value().ok_or(Error::ThreadStopped)?
     }
 
+    #[cfg(any(test, feature = "simulate"))]
+    pub fn simulate(&self, hotkey: Hotkey, pressed: bool) -> Result<()> {
+        let (future, promise) = future_promise();
+
+        self.sender
+            .send(Message::Simulate(hotkey, pressed, promise))
+            .map_err(|_| Error::ThreadStopped)?;
+
+        self.waker.wake().map_err(|_| Error::ThreadStopped)?;
+
+        future.value().ok_or(Error::ThreadStopped)?
+    }
+
     pub fn try_resolve(&self, key_code: KeyCode) -> Option<String> {
         let (future, promise) = future_promise();
 
@@ -197,6 +197,17 @@ pub fn new() -> Result<Hook> {
                                 Message::Resolve(key_code, promise) => {
                                     promise.set(resolve(&xlib, display, key_code))
                                 }
+                                #[cfg(any(test, feature = "simulate"))]
+                                Message::Simulate(key, pressed, promise) => promise.set(
+                                    code_for(key.key_code)
+                                        .and_then(|code| hotkeys.get_mut(&(code, key.modifiers)))
+                                        .map(|callback| {
+                                            if pressed {
+                                                callback();
+                                            }
+                                        })
+                                        .ok_or(crate::Error::NotRegistered),
+                                ),
                                 Message::End => {
                                     break 'event_loop;
                                 }
@@ -1103,3 +1114,39 @@ fn keysym_to_utf32(keysym: u64) -> u32 {
         _ => 0,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::keysym_to_utf32;
+
+    #[test]
+    fn latin_1_keysyms_map_directly() {
+        assert_eq!(keysym_to_utf32(0x0061), 'a' as u32);
+        assert_eq!(keysym_to_utf32(0x0031), '1' as u32);
+        assert_eq!(keysym_to_utf32(0x00e9), 'é' as u32);
+    }
+
+    #[test]
+    fn legacy_keysyms_map_to_their_code_points() {
+        assert_eq!(keysym_to_utf32(0x01b3), 'ł' as u32);
+        assert_eq!(keysym_to_utf32(0x06c1), 'а' as u32);
+        assert_eq!(keysym_to_utf32(0x20ac), '€' as u32);
+    }
+
+    #[test]
+    fn unicode_keysyms_map_to_their_code_points() {
+        assert_eq!(keysym_to_utf32(0x0100_00e4), 'ä' as u32);
+        assert_eq!(keysym_to_utf32(0x0100_3042), 'あ' as u32);
+        assert_eq!(keysym_to_utf32(0x0100_d800), 0);
+    }
+
+    #[test]
+    fn special_keysyms() {
+        assert_eq!(keysym_to_utf32(0xff80), ' ' as u32);
+        assert_eq!(keysym_to_utf32(0xff0d), '\r' as u32);
+        assert_eq!(keysym_to_utf32(0xff1b), '\u{1b}' as u32);
+        assert_eq!(keysym_to_utf32(0xffb5), '5' as u32);
+        // F1 doesn't produce any character.
+        assert_eq!(keysym_to_utf32(0xffbe), 0);
+    }
+}
@@ -196,6 +196,18 @@ impl Hook {
         Ok(())
     }
 
+    #[cfg(any(test, feature = "simulate"))]
+    pub fn simulate(&self, hotkey: Hotkey, pressed: bool) -> Result<()> {
+        let mut hotkeys = self.state.hotkeys.lock().unwrap();
+        let callback = hotkeys
+            .get_mut(&hotkey)
+            .ok_or(crate::Error::NotRegistered)?;
+        if pressed {
+            callback();
+        }
+        Ok(())
+    }
+
     pub fn try_resolve(&self, key_code: KeyCode) -> Option<String> {
         unsafe {
             let current_keyboard_raw = TISCopyCurrentKeyboardInputSource();
@@ -223,59 +235,7 @@ impl Hook {
 
             let keyboard_layout = CFDataGetBytePtr(layout_data.cast());
 
-            let key_code = match key_code {
-                KeyCode::Backquote => 0x32,
-                KeyCode::Backslash => 0x2A,
-                KeyCode::BracketLeft => 0x21,
-                KeyCode::BracketRight => 0x1E,
-                KeyCode::Comma => 0x2B,
-                KeyCode::Digit0 => 0x1D,
-                KeyCode::Digit1 => 0x12,
-                KeyCode::Digit2 => 0x13,
-                KeyCode::Digit3 => 0x14,
-                KeyCode::Digit4 => 0x15,
-                KeyCode::Digit5 => 0x17,
-                KeyCode::Digit6 => 0x16,
-                KeyCode::Digit7 => 0x1A,
-                KeyCode::Digit8 => 0x1C,
-                KeyCode::Digit9 => 0x19,
-                KeyCode::Equal => 0x18,
-                KeyCode::IntlBackslash => 0x0A,
-                KeyCode::IntlRo => 0x5E,
-                KeyCode::IntlYen => 0x5D,
-                KeyCode::KeyA => 0x00,
-                KeyCode::KeyB => 0x0B,
-                KeyCode::KeyC => 0x08,
-                KeyCode::KeyD => 0x02,
-                KeyCode::KeyE => 0x0E,
-                KeyCode::KeyF => 0x03,
-                KeyCode::KeyG => 0x05,
-                KeyCode::KeyH => 0x04,
-                KeyCode::KeyI => 0x22,
-                KeyCode::KeyJ => 0x26,
-                KeyCode::KeyK => 0x28,
-                KeyCode::KeyL => 0x25,
-                KeyCode::KeyM => 0x2E,
-                KeyCode::KeyN => 0x2D,
-                KeyCode::KeyO => 0x1F,
-                KeyCode::KeyP => 0x23,
-                KeyCode::KeyQ => 0x0C,
-                KeyCode::KeyR => 0x0F,
-                KeyCode::KeyS => 0x01,
-                KeyCode::KeyT => 0x11,
-                KeyCode::KeyU => 0x20,
-                KeyCode::KeyV => 0x09,
-                KeyCode::KeyW => 0x0D,
-                KeyCode::KeyX => 0x07,
-                KeyCode::KeyY => 0x10,
-                KeyCode::KeyZ => 0x06,
-                KeyCode::Minus => 0x1B,
-                KeyCode::Period => 0x2F,
-                KeyCode::Quote => 0x27,
-                KeyCode::Semicolon => 0x29,
-                KeyCode::Slash => 0x2C,
-                _ => return None,
-            };
+            let key_code = virtual_key_code(key_code)?;
 
             let mut chars = [0; 4];
             let mut len = 0;
@@ -302,28 +262,67 @@ impl Hook {
     }
 }
 
-unsafe extern "C" fn callback(
-    _: EventTapProxy,
-    _: EventType,
-    event: EventRef,
-    user_info: *mut c_void,
-) -> EventRef {
-    // If the tap ever gets disabled by a timeout, we may need the following code:
-    // // Handle the timeout case by re-enabling the tap.
-    // if (type == kCGEventTapDisabledByTimeout) {
-    //   CGEventTapEnable(shortcut_listener->event_tap_, TRUE);
-    //   return event;
-    // }
-
-    let is_repeating =
-        unsafe { cg::CGEventGetIntegerValueField(event, EventField::KEYBOARD_EVENT_AUTOREPEAT) };
-    if is_repeating != 0 {
-        return event;
-    }
+/// Maps the key codes that are part of the writing system to the virtual key
+/// codes used by `UCKeyTranslate`, so they can be resolved based on the
+/// current keyboard layout.
+const fn virtual_key_code(key_code: KeyCode) -> Option<u16> {
+    Some(match key_code {
+        KeyCode::Backquote => 0x32,
+        KeyCode::Backslash => 0x2A,
+        KeyCode::BracketLeft => 0x21,
+        KeyCode::BracketRight => 0x1E,
+        KeyCode::Comma => 0x2B,
+        KeyCode::Digit0 => 0x1D,
+        KeyCode::Digit1 => 0x12,
+        KeyCode::Digit2 => 0x13,
+        KeyCode::Digit3 => 0x14,
+        KeyCode::Digit4 => 0x15,
+        KeyCode::Digit5 => 0x17,
+        KeyCode::Digit6 => 0x16,
+        KeyCode::Digit7 => 0x1A,
+        KeyCode::Digit8 => 0x1C,
+        KeyCode::Digit9 => 0x19,
+        KeyCode::Equal => 0x18,
+        KeyCode::IntlBackslash => 0x0A,
+        KeyCode::IntlRo => 0x5E,
+        KeyCode::IntlYen => 0x5D,
+        KeyCode::KeyA => 0x00,
+        KeyCode::KeyB => 0x0B,
+        KeyCode::KeyC => 0x08,
+        KeyCode::KeyD => 0x02,
+        KeyCode::KeyE => 0x0E,
+        KeyCode::KeyF => 0x03,
+        KeyCode::KeyG => 0x05,
+        KeyCode::KeyH => 0x04,
+        KeyCode::KeyI => 0x22,
+        KeyCode::KeyJ => 0x26,
+        KeyCode::KeyK => 0x28,
+        KeyCode::KeyL => 0x25,
+        KeyCode::KeyM => 0x2E,
+        KeyCode::KeyN => 0x2D,
+        KeyCode::KeyO => 0x1F,
+        KeyCode::KeyP => 0x23,
+        KeyCode::KeyQ => 0x0C,
+        KeyCode::KeyR => 0x0F,
+        KeyCode::KeyS => 0x01,
+        KeyCode::KeyT => 0x11,
+        KeyCode::KeyU => 0x20,
+        KeyCode::KeyV => 0x09,
+        KeyCode::KeyW => 0x0D,
+        KeyCode::KeyX => 0x07,
+        KeyCode::KeyY => 0x10,
+        KeyCode::KeyZ => 0x06,
+        KeyCode::Minus => 0x1B,
+        KeyCode::Period => 0x2F,
+        KeyCode::Quote => 0x27,
+        KeyCode::Semicolon => 0x29,
+        KeyCode::Slash => 0x2C,
+        _ => return None,
+    })
+}
 
-    let key_code =
-        unsafe { cg::CGEventGetIntegerValueField(event, EventField::KEYBOARD_EVENT_KEYCODE) };
-    let key_code = match key_code {
+const fn key_code_from_virtual(key_code: i64) -> Option<KeyCode> {
+    Some(match key_code {
         0x00 => KeyCode::KeyA,
         0x01 => KeyCode::KeyS,
         0x02 => KeyCode::KeyD,
@@ -447,7 +446,33 @@ unsafe extern "C" fn callback(
         0x7C => KeyCode::ArrowRight,
         0x7D => KeyCode::ArrowDown,
         0x7E => KeyCode::ArrowUp,
-        _ => return event,
+        _ => return None,
+    })
+}
+
+unsafe extern "C" fn callback(
+    _: EventTapProxy,
+    _: EventType,
+    event: EventRef,
+    user_info: *mut c_void,
+) -> EventRef {
+    // If the tap ever gets disabled by a timeout, we may need the following code:
+    // // Handle the timeout case by re-enabling the tap.
+    // if (type == kCGEventTapDisabledByTimeout) {
+    //   CGEventTapEnable(shortcut_listener->event_tap_, TRUE);
+    //   return event;
+    // }
+
+    let is_repeating =
+        unsafe { cg::CGEventGetIntegerValueField(event, EventField::KEYBOARD_EVENT_AUTOREPEAT) };
+    if is_repeating != 0 {
+        return event;
+    }
+
+    let key_code =
+        unsafe { cg::CGEventGetIntegerValueField(event, EventField::KEYBOARD_EVENT_KEYCODE) };
+    let Some(key_code) = key_code_from_virtual(key_code) else {
+        return event;
     };
 
     let state = user_info as *const State;
@@ -496,3 +521,34 @@ unsafe extern "C" fn callback(
         event
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::KeyCodeClass;
+
+    #[test]
+    fn writing_system_keys_map_to_their_virtual_key_codes() {
+        for virtual_key_code in 0..=0x7F {
+            let Some(key_code) = key_code_from_virtual(virtual_key_code) else {
+                continue;
+            };
+            if key_code.classify() == KeyCodeClass::WritingSystem {
+                assert_eq!(
+                    super::virtual_key_code(key_code),
+                    Some(virtual_key_code as u16),
+                    "{key_code:?}",
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn only_writing_system_keys_get_resolved() {
+        assert_eq!(virtual_key_code(KeyCode::KeyA), Some(0x00));
+        assert_eq!(virtual_key_code(KeyCode::Digit5), Some(0x17));
+        assert_eq!(virtual_key_code(KeyCode::Enter), None);
+        assert_eq!(virtual_key_code(KeyCode::Numpad0), None);
+        assert_eq!(virtual_key_code(KeyCode::F1), None);
+    }
+}
@@ -33,6 +33,12 @@ impl Hook {
         Ok(())
     }
 
+    #[cfg(any(test, feature = "simulate"))]
+    #[inline]
+    pub fn simulate(&self, _: Hotkey, _: bool) -> Result<()> {
+        Err(crate::Error::NotRegistered)
+    }
+
     #[inline]
     pub fn try_resolve(&self, _key_code: KeyCode) -> Option<String> {
         None
@@ -245,6 +245,18 @@ impl Hook {
         }
     }
 
+    #[cfg(any(test, feature = "simulate"))]
+    pub fn simulate(&self, hotkey: Hotkey, pressed: bool) -> Result<()> {
+        let mut hotkeys = self.hotkeys.lock().unwrap();
+        let callback = hotkeys
+            .get_mut(&hotkey)
+            .ok_or(crate::Error::NotRegistered)?;
+        if pressed {
+            callback();
+        }
+        Ok(())
+    }
+
     pub fn try_resolve(&self, key_code: KeyCode) -> Option<String> {
         let keyboard_layout_resolver = self.keyboard_layout_resolver.borrow();
         let (layout, resolve_fn) = keyboard_layout_resolver.as_ref()?;
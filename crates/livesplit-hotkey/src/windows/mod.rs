@@ -482,6 +482,18 @@ impl Hook {
         }
     }
 
+    #[cfg(any(test, feature = "simulate"))]
+    pub fn simulate(&self, hotkey: Hotkey, pressed: bool) -> Result<()> {
+        let mut hotkeys = self.hotkeys.lock().unwrap();
+        let callback = hotkeys
+            .get_mut(&hotkey)
+            .ok_or(crate::Error::NotRegistered)?;
+        if pressed {
+            callback();
+        }
+        Ok(())
+    }
+
     pub fn try_resolve(&self, key_code: KeyCode) -> Option<String> {
         use self::KeyCode::*;
         let scan_code = match key_code {
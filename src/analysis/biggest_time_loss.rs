@@ -0,0 +1,71 @@
+//! Provides functionality for finding the segment where the most time is
+//! consistently lost compared to the Personal Best.
+
+use crate::{Run, TimeSpan, TimingMethod};
+
+/// Finds the segment that is the slowest on average compared to the segment
+/// times of the Personal Best. For each segment, the segment times of all the
+/// completed attempts are compared to the segment time of the Personal Best
+/// and the differences are averaged. Segment times that follow a skipped
+/// segment are ignored, as they include the time of the skipped segment. The
+/// index of the segment with the largest average loss is returned, with ties
+/// going to the earliest segment. `None` is returned if no segment is slower on
+/// average than in the Personal Best.
+pub fn biggest_time_loss_segment(run: &Run, method: TimingMethod) -> Option<usize> {
+    let segments = run.segments();
+    let mut biggest: Option<(usize, f64)> = None;
+
+    for (index, segment) in segments.iter().enumerate() {
+        let previous = index.checked_sub(1).map(|i| &segments[i]);
+
+        let pb_segment_time = catch! {
+            let split_time = segment.personal_best_split_time()[method]?;
+            match previous {
+                Some(previous) => split_time - previous.personal_best_split_time()[method]?,
+                None => split_time,
+            }
+        };
+        let Some(pb_segment_time) = pb_segment_time else {
+            continue;
+        };
+
+        let (mut total_loss, mut count) = (TimeSpan::zero(), 0);
+
+        for attempt in run.attempt_history() {
+            if attempt.time()[method].is_none() {
+                continue;
+            }
+            let attempt_index = attempt.index();
+
+            let Some(segment_time) = segment.segment_history().get(attempt_index) else {
+                continue;
+            };
+            let Some(segment_time) = segment_time[method] else {
+                continue;
+            };
+            if let Some(previous) = previous {
+                if previous
+                    .segment_history()
+                    .get(attempt_index)
+                    .is_none_or(|time| time[method].is_none())
+                {
+                    continue;
+                }
+            }
+
+            total_loss += segment_time - pb_segment_time;
+            count += 1;
+        }
+
+        if count == 0 {
+            continue;
+        }
+
+        let average_loss = total_loss.total_seconds() / count as f64;
+        if average_loss > 0.0 && biggest.is_none_or(|(_, biggest)| average_loss > biggest) {
+            biggest = Some((index, average_loss));
+        }
+    }
+
+    biggest.map(|(index, _)| index)
+}
@@ -0,0 +1,64 @@
+//! Provides functionality for calculating how consistently each segment of a
+//! run is performed.
+
+use crate::{
+    Run, TimeSpan, TimingMethod,
+    platform::{math::f64::sqrt, prelude::*},
+};
+
+/// Calculates a consistency score for each segment of the run based on the
+/// segment history. The score is the inverse of one plus the coefficient of
+/// variation of the segment times, which normalizes it into the range from 0
+/// to 1, where 1 means that the segment always took exactly the same amount
+/// of time. A segment is scored as `None` if it has fewer than two segment
+/// times in its history.
+pub fn consistency_scores(run: &Run, method: TimingMethod) -> Vec<Option<f32>> {
+    run.segments()
+        .iter()
+        .map(|segment| {
+            consistency_score(
+                segment
+                    .segment_history()
+                    .iter_actual_runs()
+                    .filter_map(|&(_, time)| time[method]),
+            )
+        })
+        .collect()
+}
+
+fn consistency_score(times: impl Iterator<Item = TimeSpan> + Clone) -> Option<f32> {
+    let (mean, variance) = mean_and_variance(times)?;
+    if mean <= 0.0 {
+        return None;
+    }
+
+    let coefficient_of_variation = sqrt(variance) / mean;
+
+    Some((1.0 / (1.0 + coefficient_of_variation)) as f32)
+}
+
+/// Calculates the mean and the sample variance of the times in seconds. `None`
+/// is returned if there are fewer than two times.
+pub(super) fn mean_and_variance(
+    times: impl Iterator<Item = TimeSpan> + Clone,
+) -> Option<(f64, f64)> {
+    let (mut count, mut sum) = (0usize, 0.0);
+    for time in times.clone() {
+        count += 1;
+        sum += time.total_seconds();
+    }
+
+    if count < 2 {
+        return None;
+    }
+
+    let mean = sum / count as f64;
+    let squared_deviations: f64 = times
+        .map(|time| {
+            let deviation = time.total_seconds() - mean;
+            deviation * deviation
+        })
+        .sum();
+
+    Some((mean, squared_deviations / (count - 1) as f64))
+}
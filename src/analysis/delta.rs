@@ -4,7 +4,9 @@
 //! the moment. This may be the case when the current attempt is slower than the
 //! comparison at the current split.
 
-use crate::{analysis, timing::Snapshot, TimeSpan, TimerPhase};
+use crate::{
+    analysis, settings::SemanticColor, timing::Snapshot, TimeSpan, TimerPhase, TimingMethod,
+};
 
 /// Calculates the delta of the current attempt to the comparison provided.
 /// Additionally a value is returned that indicates whether the delta value is a
@@ -12,7 +14,18 @@ use crate::{analysis, timing::Snapshot, TimeSpan, TimerPhase};
 /// the moment. This may be the case when the current attempt is slower than the
 /// comparison at the current split.
 pub fn calculate(timer: &Snapshot<'_>, comparison: &str) -> (Option<TimeSpan>, bool) {
-    let timing_method = timer.current_timing_method();
+    calculate_with_method(timer, comparison, timer.current_timing_method())
+}
+
+/// Calculates the delta of the current attempt to the comparison provided for
+/// the [`TimingMethod`] provided, instead of the current [`TimingMethod`] of
+/// the timer. Additionally a value is returned that indicates whether the
+/// delta value is a live delta.
+pub fn calculate_with_method(
+    timer: &Snapshot<'_>,
+    comparison: &str,
+    timing_method: TimingMethod,
+) -> (Option<TimeSpan>, bool) {
     let last_segment = timer.run().segments().last().unwrap();
 
     let mut use_live_delta = false;
@@ -47,3 +60,26 @@ pub fn calculate(timer: &Snapshot<'_>, comparison: &str) -> (Option<TimeSpan>, b
 
     (time, use_live_delta)
 }
+
+/// Determines the semantic color of a delta that got calculated with
+/// [`calculate_with_method`]. If the delta is a live delta, it is colored
+/// based on the current split. Otherwise it is colored based on the last
+/// split.
+pub fn semantic_color(
+    timer: &Snapshot<'_>,
+    delta: Option<TimeSpan>,
+    use_live_delta: bool,
+    comparison: &str,
+    method: TimingMethod,
+) -> SemanticColor {
+    let mut index = timer.current_split_index();
+    if !use_live_delta {
+        index = index.and_then(|i| i.checked_sub(1));
+    }
+
+    if let Some(index) = index {
+        analysis::split_color(timer, delta, index, true, false, comparison, method)
+    } else {
+        SemanticColor::Default
+    }
+}
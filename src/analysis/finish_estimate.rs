@@ -0,0 +1,85 @@
+//! Provides functionality for estimating the final time of the current attempt
+//! along with an interval that the final time is likely to fall into.
+
+use super::consistency::mean_and_variance;
+use crate::{TimeSpan, TimerPhase, TimingMethod, platform::math::f64::sqrt, timing::Snapshot};
+
+/// The z-score of a two-sided 95% confidence interval of a normal
+/// distribution.
+const Z_SCORE: f64 = 1.96;
+
+/// Estimates the final time of the current attempt for the timing method
+/// provided. The result is returned as `(low, expected, high)`. The expected
+/// final time is the last split time plus the average times of all the
+/// remaining segments, based on the segment history. The segments are assumed
+/// to be independent and normally distributed, so their variances are summed
+/// up to form an interval around the expected final time that the final time
+/// falls into with a probability of about 95%. If the time spent on the
+/// current segment already exceeds its average, the current segment is assumed
+/// to finish right now instead. If there's no active attempt, the whole run is
+/// estimated. If the attempt already ended, its final time is returned for all
+/// three values. `None` is returned if any of the remaining segments has fewer
+/// than two segment times in its history.
+pub fn finish_estimate_with_interval(
+    timer: &Snapshot<'_>,
+    method: TimingMethod,
+) -> Option<(TimeSpan, TimeSpan, TimeSpan)> {
+    let segments = timer.run().segments();
+
+    let current_split_index = match timer.current_phase() {
+        TimerPhase::NotRunning => 0,
+        TimerPhase::Ended => {
+            let final_time = segments.last()?.split_time()[method]?;
+            return Some((final_time, final_time, final_time));
+        }
+        TimerPhase::Running | TimerPhase::Paused => timer.current_split_index()?,
+    };
+
+    // The segment history stores the time of skipped segments combined with
+    // the segment that follows them, so the remaining time is measured from
+    // the last actual split.
+    let (last_split_index, last_split_time) = segments[..current_split_index]
+        .iter()
+        .enumerate()
+        .rev()
+        .find_map(|(index, segment)| Some((index + 1, segment.split_time()[method]?)))
+        .unwrap_or((0, TimeSpan::zero()));
+
+    let mut current_segment_mean = 0.0;
+    let mut remaining_mean = 0.0;
+    let mut variance = 0.0;
+
+    for (index, segment) in segments.iter().enumerate().skip(last_split_index) {
+        let (mean, segment_variance) = mean_and_variance(
+            segment
+                .segment_history()
+                .iter_actual_runs()
+                .filter_map(|&(_, time)| time[method]),
+        )?;
+
+        if index <= current_split_index {
+            current_segment_mean += mean;
+        } else {
+            remaining_mean += mean;
+        }
+        variance += segment_variance;
+    }
+
+    let last_split_time = last_split_time.total_seconds();
+
+    if timer.current_phase() != TimerPhase::NotRunning {
+        if let Some(current_time) = timer.current_time()[method] {
+            current_segment_mean =
+                current_segment_mean.max(current_time.total_seconds() - last_split_time);
+        }
+    }
+
+    let expected = last_split_time + current_segment_mean + remaining_mean;
+    let margin = Z_SCORE * sqrt(variance);
+
+    Some((
+        TimeSpan::from_seconds(expected - margin),
+        TimeSpan::from_seconds(expected),
+        TimeSpan::from_seconds(expected + margin),
+    ))
+}
@@ -0,0 +1,35 @@
+//! Provides functionality for checking whether the Sum of Best Segments has
+//! ever been achieved in a single attempt, or whether the best segments are
+//! scattered across different attempts.
+
+use crate::{Run, TimeSpan, TimingMethod, analysis::sum_of_segments};
+
+/// Calculates the best final time of all the attempts that were actually
+/// completed, including the Personal Best. `None` is returned if no attempt
+/// has been completed.
+pub fn best_completed_run_time(run: &Run, method: TimingMethod) -> Option<TimeSpan> {
+    let personal_best = run
+        .segments()
+        .last()
+        .and_then(|segment| segment.personal_best_split_time()[method]);
+
+    run.attempt_history()
+        .iter()
+        .filter_map(|attempt| attempt.time()[method])
+        .chain(personal_best)
+        .min()
+}
+
+/// Checks whether all the best segments have been achieved in a single
+/// attempt. This is the case if the best completed attempt matches the Sum of
+/// Best Segments. If it doesn't, the best segments are scattered across
+/// different attempts and [`best_completed_run_time`] can be used to tell how
+/// far the best completed attempt is behind the Sum of Best Segments. `false`
+/// is returned if either of them is unknown.
+pub fn golds_in_single_run(run: &Run, method: TimingMethod) -> bool {
+    catch! {
+        let sum_of_best = sum_of_segments::calculate_best(run.segments(), false, false, method)?;
+        best_completed_run_time(run, method)? <= sum_of_best
+    }
+    .unwrap_or(false)
+}
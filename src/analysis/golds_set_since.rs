@@ -0,0 +1,31 @@
+//! Provides functionality for counting how many of the current best segments
+//! were achieved in attempts that happened on or after a certain date.
+
+use crate::{DateTime, Run, TimingMethod};
+
+/// Counts how many of the current best segments were achieved in attempts
+/// that started on or after the date provided. Each best segment is attributed
+/// to the earliest attempt in the segment history that matches it. If it is
+/// not known when that attempt started, the time it ended is used instead.
+/// Best segments that can't be attributed to a dated attempt are not counted.
+pub fn golds_set_since(run: &Run, since: DateTime, method: TimingMethod) -> usize {
+    run.segments()
+        .iter()
+        .filter(|segment| {
+            catch! {
+                let best_segment = segment.best_segment_time()[method]?;
+                let &(index, _) = segment
+                    .segment_history()
+                    .iter_actual_runs()
+                    .find(|(_, time)| time[method] == Some(best_segment))?;
+                let attempt = run
+                    .attempt_history()
+                    .iter()
+                    .find(|attempt| attempt.index() == index)?;
+                let date = attempt.started().or(attempt.ended())?;
+                date.time >= since
+            }
+            .unwrap_or(false)
+        })
+        .count()
+}
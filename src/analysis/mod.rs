@@ -1,15 +1,27 @@
 //! The analysis module provides a variety of functions for calculating
 //! information about a [`Run`](crate::run::Run).
 
+mod biggest_time_loss;
+mod consistency;
 pub mod current_pace;
 pub mod delta;
+mod finish_estimate;
+mod golds_in_single_run;
+mod golds_set_since;
 pub mod pb_chance;
 pub mod possible_time_save;
+mod segment_trend;
 mod skill_curve;
 pub mod state_helper;
 pub mod sum_of_segments;
 pub mod total_playtime;
 
+pub use self::biggest_time_loss::biggest_time_loss_segment;
+pub use self::consistency::consistency_scores;
+pub use self::finish_estimate::finish_estimate_with_interval;
+pub use self::golds_in_single_run::{best_completed_run_time, golds_in_single_run};
+pub use self::golds_set_since::golds_set_since;
+pub use self::segment_trend::segment_trend;
 pub use self::skill_curve::SkillCurve;
 pub use self::state_helper::*;
 
@@ -0,0 +1,46 @@
+//! Provides functionality for calculating whether the times of a segment are
+//! trending towards faster or slower times over the most recent attempts.
+
+use crate::{Run, TimingMethod};
+
+/// Calculates the trend of a segment's times over the last `k` attempts that
+/// completed the segment. The trend is the slope of a linear least squares fit
+/// through those segment times, in seconds per attempt. A negative slope means
+/// that the segment is getting faster. If there are fewer than two segment
+/// times to fit a line through, `None` is returned.
+///
+/// # Panics
+///
+/// This panics if the segment index provided is out of bounds.
+pub fn segment_trend(
+    run: &Run,
+    segment_index: usize,
+    method: TimingMethod,
+    k: usize,
+) -> Option<f32> {
+    let times = run
+        .segment(segment_index)
+        .segment_history()
+        .iter_actual_runs()
+        .filter_map(|&(_, time)| time[method]);
+
+    let count = times.clone().count();
+    let skip_count = count.saturating_sub(k);
+    if count - skip_count < 2 {
+        return None;
+    }
+
+    let (mut sum_x, mut sum_y, mut sum_xx, mut sum_xy) = (0.0, 0.0, 0.0, 0.0);
+    for (x, time) in times.skip(skip_count).enumerate() {
+        let (x, y) = (x as f64, time.total_seconds());
+        sum_x += x;
+        sum_y += y;
+        sum_xx += x * x;
+        sum_xy += x * y;
+    }
+
+    let n = (count - skip_count) as f64;
+    let slope = (n * sum_xy - sum_x * sum_y) / (n * sum_xx - sum_x * sum_x);
+
+    Some(slope as f32)
+}
@@ -0,0 +1,44 @@
+use crate::{
+    TimingMethod,
+    analysis::biggest_time_loss_segment,
+    util::tests_helper::{create_timer, run_with_splits},
+};
+
+#[test]
+fn consistently_slow_segment_is_selected() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0]);
+    run_with_splits(&mut timer, &[10.0, 25.0, 35.0]);
+    run_with_splits(&mut timer, &[11.0, 26.0, 36.0]);
+    run_with_splits(&mut timer, &[12.0, 26.5, 37.0]);
+
+    // The first segment loses 0, 1 and 2 seconds, while the second segment
+    // loses 5, 5 and 4.5 seconds. The third segment never loses any time.
+    assert_eq!(
+        biggest_time_loss_segment(timer.run(), TimingMethod::GameTime),
+        Some(1),
+    );
+}
+
+#[test]
+fn ties_go_to_the_earliest_segment() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0]);
+    run_with_splits(&mut timer, &[12.0, 24.0, 34.0]);
+
+    assert_eq!(
+        biggest_time_loss_segment(timer.run(), TimingMethod::GameTime),
+        Some(0),
+    );
+}
+
+#[test]
+fn nothing_is_selected_without_any_losses() {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[10.0, 20.0]);
+
+    assert_eq!(
+        biggest_time_loss_segment(timer.run(), TimingMethod::GameTime),
+        None,
+    );
+}
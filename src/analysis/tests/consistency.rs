@@ -0,0 +1,49 @@
+use crate::{
+    TimingMethod,
+    analysis::consistency_scores,
+    util::tests_helper::{create_timer, run_with_splits},
+};
+
+#[test]
+fn low_variance_segment_scores_higher() {
+    let mut timer = create_timer(&["A", "B"]);
+
+    // The first segment always takes about 10 seconds, while the second
+    // segment varies between 10 and 30 seconds.
+    for (first, second) in [(10.0, 30.0), (10.5, 10.0), (9.5, 20.0), (10.0, 25.0)] {
+        run_with_splits(&mut timer, &[first, first + second]);
+    }
+
+    let scores = consistency_scores(timer.run(), TimingMethod::GameTime);
+    assert_eq!(scores.len(), 2);
+
+    let (first, second) = (scores[0].unwrap(), scores[1].unwrap());
+    assert!(first > second, "{first} <= {second}");
+    assert!(first > 0.0 && first <= 1.0, "{first}");
+    assert!(second > 0.0 && second <= 1.0, "{second}");
+}
+
+#[test]
+fn identical_segment_times_are_perfectly_consistent() {
+    let mut timer = create_timer(&["A"]);
+    run_with_splits(&mut timer, &[10.0]);
+    run_with_splits(&mut timer, &[10.0]);
+
+    let scores = consistency_scores(timer.run(), TimingMethod::GameTime);
+    assert_eq!(scores, [Some(1.0)]);
+}
+
+#[test]
+fn insufficient_data_has_no_score() {
+    let mut timer = create_timer(&["A", "B"]);
+
+    let scores = consistency_scores(timer.run(), TimingMethod::GameTime);
+    assert_eq!(scores, [None, None]);
+
+    run_with_splits(&mut timer, &[10.0, 20.0]);
+
+    let scores = consistency_scores(timer.run(), TimingMethod::GameTime);
+    assert_eq!(scores, [None, None]);
+    let scores = consistency_scores(timer.run(), TimingMethod::RealTime);
+    assert_eq!(scores, [None, None]);
+}
@@ -0,0 +1,67 @@
+use crate::{
+    TimeSpan, TimingMethod,
+    analysis::finish_estimate_with_interval,
+    util::tests_helper::{
+        create_timer, make_progress_run_with_splits_opt, run_with_splits, start_run,
+    },
+};
+
+#[track_caller]
+fn assert_close(actual: TimeSpan, expected: f64) {
+    let actual = actual.total_seconds();
+    assert!((actual - expected).abs() < 1e-3, "{actual} != {expected}");
+}
+
+#[test]
+fn interval_brackets_the_expected_value_symmetrically() {
+    let mut timer = create_timer(&["A", "B"]);
+
+    // The first segment takes 10s and 12s, while the second segment takes 5s
+    // and 7s. Each of them has a variance of 2s², so the whole run has a
+    // variance of 4s².
+    run_with_splits(&mut timer, &[10.0, 15.0]);
+    run_with_splits(&mut timer, &[12.0, 19.0]);
+
+    let (low, expected, high) =
+        finish_estimate_with_interval(&timer.snapshot(), TimingMethod::GameTime).unwrap();
+
+    assert_close(expected, 17.0);
+    assert_close(high - expected, 1.96 * 2.0);
+    assert_close(expected - low, 1.96 * 2.0);
+
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(11.0)]);
+
+    let (low, expected, high) =
+        finish_estimate_with_interval(&timer.snapshot(), TimingMethod::GameTime).unwrap();
+
+    assert_close(expected, 17.0);
+    assert_close(high - expected, 1.96 * 2.0f64.sqrt());
+    assert_close(expected - low, 1.96 * 2.0f64.sqrt());
+
+    // Taking longer than the average on the current segment pushes the
+    // estimate back.
+    timer.set_game_time(TimeSpan::from_seconds(20.0)).unwrap();
+
+    let (_, expected, _) =
+        finish_estimate_with_interval(&timer.snapshot(), TimingMethod::GameTime).unwrap();
+
+    assert_close(expected, 20.0);
+}
+
+#[test]
+fn not_enough_history_has_no_estimate() {
+    let mut timer = create_timer(&["A", "B"]);
+
+    assert_eq!(
+        finish_estimate_with_interval(&timer.snapshot(), TimingMethod::GameTime),
+        None
+    );
+
+    run_with_splits(&mut timer, &[10.0, 15.0]);
+
+    assert_eq!(
+        finish_estimate_with_interval(&timer.snapshot(), TimingMethod::GameTime),
+        None
+    );
+}
@@ -0,0 +1,49 @@
+use crate::{
+    TimeSpan, TimingMethod,
+    analysis::{best_completed_run_time, golds_in_single_run, sum_of_segments},
+    util::tests_helper::{create_timer, run_with_splits},
+};
+
+#[test]
+fn scattered_golds_are_not_in_a_single_run() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0]);
+    run_with_splits(&mut timer, &[8.0, 20.0, 31.0]);
+    let run = timer.run();
+
+    // The first attempt has the best second and third segments, while the
+    // second attempt has the best first segment.
+    let sum_of_best =
+        sum_of_segments::calculate_best(run.segments(), false, false, TimingMethod::GameTime)
+            .unwrap();
+    assert_eq!(sum_of_best, TimeSpan::from_seconds(28.0));
+
+    let best_finish = best_completed_run_time(run, TimingMethod::GameTime).unwrap();
+    assert_eq!(best_finish, TimeSpan::from_seconds(30.0));
+    assert!(best_finish > sum_of_best);
+
+    assert!(!golds_in_single_run(run, TimingMethod::GameTime));
+}
+
+#[test]
+fn golds_from_a_single_run() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[11.0, 22.0, 33.0]);
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0]);
+    let run = timer.run();
+
+    assert_eq!(
+        best_completed_run_time(run, TimingMethod::GameTime),
+        Some(TimeSpan::from_seconds(30.0))
+    );
+    assert!(golds_in_single_run(run, TimingMethod::GameTime));
+}
+
+#[test]
+fn no_completed_runs() {
+    let timer = create_timer(&["A", "B"]);
+    let run = timer.run();
+
+    assert_eq!(best_completed_run_time(run, TimingMethod::GameTime), None);
+    assert!(!golds_in_single_run(run, TimingMethod::GameTime));
+}
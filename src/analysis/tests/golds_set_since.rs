@@ -0,0 +1,73 @@
+use crate::{
+    AtomicDateTime, DateTime, Run, Segment, Time, TimeSpan, TimingMethod, analysis::golds_set_since,
+};
+
+const DAY: i64 = 24 * 60 * 60;
+
+fn date(day: i64) -> DateTime {
+    DateTime::from_unix_timestamp(day * DAY).unwrap()
+}
+
+fn time(seconds: f64) -> Time {
+    Time::new().with_real_time(Some(TimeSpan::from_seconds(seconds)))
+}
+
+fn run() -> Run {
+    let mut run = Run::new();
+
+    // Attempt 2 sets the gold on A, attempt 1 the one on B and attempt 3 the
+    // one on C.
+    for (name, times) in [
+        ("A", [10.0, 8.0, 9.0]),
+        ("B", [5.0, 6.0, 7.0]),
+        ("C", [12.0, 11.0, 10.0]),
+    ] {
+        let mut segment = Segment::new(name);
+        let mut best = f64::MAX;
+        for (index, &seconds) in (1..).zip(&times) {
+            segment.segment_history_mut().insert(index, time(seconds));
+            best = best.min(seconds);
+        }
+        *segment.best_segment_time_mut() = time(best);
+        run.push_segment(segment);
+    }
+
+    for (index, day) in [(1, 1), (2, 10), (3, 20)] {
+        let started = AtomicDateTime::new(date(day), false);
+        run.add_attempt_with_index(Time::new(), index, Some(started), None, None);
+    }
+
+    run
+}
+
+#[test]
+fn counts_golds_set_on_or_after_the_cutoff() {
+    let run = run();
+    let method = TimingMethod::RealTime;
+
+    assert_eq!(golds_set_since(&run, date(0), method), 3);
+    assert_eq!(golds_set_since(&run, date(10), method), 2);
+    assert_eq!(golds_set_since(&run, date(15), method), 1);
+    assert_eq!(golds_set_since(&run, date(21), method), 0);
+}
+
+#[test]
+fn golds_without_a_time_in_the_timing_method_are_not_counted() {
+    let run = run();
+
+    assert_eq!(golds_set_since(&run, date(0), TimingMethod::GameTime), 0);
+}
+
+#[test]
+fn golds_of_undated_attempts_are_not_counted() {
+    let mut run = run();
+
+    // Attempt 4 sets the gold on D, but it's not known when it happened.
+    let mut segment = Segment::new("D");
+    segment.segment_history_mut().insert(4, time(3.0));
+    *segment.best_segment_time_mut() = time(3.0);
+    run.push_segment(segment);
+    run.add_attempt_with_index(Time::new(), 4, None, None, None);
+
+    assert_eq!(golds_set_since(&run, date(0), TimingMethod::RealTime), 3);
+}
@@ -1,2 +1,8 @@
+mod biggest_time_loss;
+mod consistency;
 mod empty_run;
+mod finish_estimate;
+mod golds_in_single_run;
+mod golds_set_since;
+mod segment_trend;
 mod semantic_colors;
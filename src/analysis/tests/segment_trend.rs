@@ -0,0 +1,45 @@
+use crate::{
+    analysis::segment_trend,
+    util::tests_helper::{create_timer, run_with_splits},
+    TimingMethod,
+};
+
+#[test]
+fn improving_segment_has_negative_slope() {
+    let mut timer = create_timer(&["A", "B"]);
+
+    // The first segment gets one second faster with every attempt, while the
+    // second segment stays the same.
+    for first_split in [14.0, 13.0, 12.0, 11.0, 10.0] {
+        run_with_splits(&mut timer, &[first_split, first_split + 5.0]);
+    }
+
+    let run = timer.run();
+
+    let slope = segment_trend(run, 0, TimingMethod::GameTime, 5).unwrap();
+    assert!((slope + 1.0).abs() < 1e-4, "{slope}");
+
+    let slope = segment_trend(run, 1, TimingMethod::GameTime, 5).unwrap();
+    assert!(slope.abs() < 1e-4, "{slope}");
+
+    // Only the last two attempts are considered.
+    let slope = segment_trend(run, 0, TimingMethod::GameTime, 2).unwrap();
+    assert!((slope + 1.0).abs() < 1e-4, "{slope}");
+}
+
+#[test]
+fn insufficient_data_has_no_trend() {
+    let mut timer = create_timer(&["A"]);
+
+    assert_eq!(segment_trend(timer.run(), 0, TimingMethod::GameTime, 5), None);
+
+    run_with_splits(&mut timer, &[10.0]);
+
+    assert_eq!(segment_trend(timer.run(), 0, TimingMethod::GameTime, 5), None);
+    assert_eq!(segment_trend(timer.run(), 0, TimingMethod::RealTime, 5), None);
+
+    run_with_splits(&mut timer, &[9.0]);
+
+    assert_eq!(segment_trend(timer.run(), 0, TimingMethod::GameTime, 1), None);
+    assert!(segment_trend(timer.run(), 0, TimingMethod::GameTime, 2).is_some());
+}
@@ -134,6 +134,15 @@ pub fn or_current<'a>(comparison: Option<&'a str>, timer: &'a Timer) -> &'a str
     comparison.unwrap_or_else(|| timer.current_comparison())
 }
 
+/// Helper function for accessing either the given comparison or, if the given
+/// comparison is `None`, the comparison a Timer is currently previewing. If the
+/// Timer is not previewing any comparison, its current comparison is used.
+pub fn or_preview<'a>(comparison: Option<&'a str>, timer: &'a Timer) -> &'a str {
+    comparison
+        .or_else(|| timer.preview_comparison())
+        .unwrap_or_else(|| timer.current_comparison())
+}
+
 /// Tries to resolve the given comparison based on a Timer object. If either
 /// `None` is given or the comparison doesn't exist, `None` is returned.
 /// Otherwise the comparison name stored in the Timer is returned by reference.
@@ -5,10 +5,10 @@
 use super::key_value;
 use crate::{
     GeneralLayoutSettings,
-    analysis::{delta, state_helper},
+    analysis::delta,
     comparison,
     platform::prelude::*,
-    settings::{Color, Field, Gradient, SemanticColor, SettingsDescription, Value},
+    settings::{Color, Field, Gradient, SettingsDescription, Value},
     timing::{
         Snapshot,
         formatter::{Accuracy, Delta, TimeFormatter},
@@ -105,26 +105,10 @@ impl Component {
         let text = comparison.unwrap_or_else(|| timer.current_comparison());
         let comparison = comparison::or_current(comparison, timer);
 
-        let (delta, use_live_delta) = delta::calculate(timer, comparison);
-
-        let mut index = timer.current_split_index();
-        if !use_live_delta {
-            index = index.and_then(|i| i.checked_sub(1));
-        }
-
-        let semantic_color = if let Some(index) = index {
-            state_helper::split_color(
-                timer,
-                delta,
-                index,
-                true,
-                false,
-                comparison,
-                timer.current_timing_method(),
-            )
-        } else {
-            SemanticColor::Default
-        };
+        let method = timer.current_timing_method();
+        let (delta, use_live_delta) = delta::calculate_with_method(timer, comparison, method);
+        let semantic_color =
+            delta::semantic_color(timer, delta, use_live_delta, comparison, method);
 
         let value_color = Some(semantic_color.visualize(layout_settings));
 
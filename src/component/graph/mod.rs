@@ -19,6 +19,9 @@ use crate::{
 use alloc::borrow::Cow;
 use serde_derive::{Deserialize, Serialize};
 
+#[cfg(test)]
+mod tests;
+
 const WIDTH: f32 = 1.0;
 const HEIGHT: f32 = 1.0;
 const DEFAULT_X_AXIS: f32 = HEIGHT / 2.0;
@@ -35,8 +38,8 @@ pub struct Component {
 #[derive(Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct Settings {
-    /// The comparison chosen. Uses the Timer's current comparison if set to
-    /// `None`.
+    /// The comparison chosen. Uses the comparison the Timer is previewing or
+    /// otherwise the Timer's current comparison if set to `None`.
     pub comparison_override: Option<String>,
     /// Specifies if the best segments should be colored with the layout's best
     /// segment color.
@@ -362,7 +365,7 @@ impl Component {
         let settings = &self.settings;
         draw_info.split_index = timer.current_split_index()?;
         let comparison = comparison::resolve(&self.settings.comparison_override, timer);
-        let comparison = comparison::or_current(comparison, timer);
+        let comparison = comparison::or_preview(comparison, timer);
 
         calculate_horizontal_scaling(timer, draw_info, settings.live_graph);
         draw_info.scale_factor_x?;
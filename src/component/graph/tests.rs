@@ -0,0 +1,75 @@
+use super::{Component, State};
+use crate::{
+    GeneralLayoutSettings, Timer, comparison,
+    util::tests_helper::{
+        create_timer, make_progress_run_with_splits_opt, run_with_splits, start_run,
+    },
+};
+
+fn points(state: &State) -> Vec<(f32, f32)> {
+    state.points.iter().map(|p| (p.x, p.y)).collect()
+}
+
+fn timer() -> Timer {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0]);
+    run_with_splits(&mut timer, &[12.0, 19.0, 32.0]);
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(11.0), Some(21.0)]);
+    timer
+}
+
+#[test]
+fn shows_the_preview_comparison() {
+    let mut timer = timer();
+    let component = Component::new();
+    let settings = GeneralLayoutSettings::default();
+
+    let personal_best = component.state(&timer.snapshot(), &settings);
+
+    timer
+        .set_preview_comparison(comparison::best_segments::NAME)
+        .unwrap();
+    assert_eq!(timer.current_comparison(), comparison::personal_best::NAME);
+    assert_eq!(
+        timer.preview_comparison(),
+        Some(comparison::best_segments::NAME)
+    );
+
+    let preview = component.state(&timer.snapshot(), &settings);
+    assert_ne!(points(&preview), points(&personal_best));
+
+    let mut best_segments = self::timer();
+    best_segments
+        .set_current_comparison(comparison::best_segments::NAME)
+        .unwrap();
+    let best_segments = component.state(&best_segments.snapshot(), &settings);
+    assert_eq!(points(&preview), points(&best_segments));
+
+    timer.clear_preview_comparison();
+    assert_eq!(timer.preview_comparison(), None);
+    let cleared = component.state(&timer.snapshot(), &settings);
+    assert_eq!(points(&cleared), points(&personal_best));
+}
+
+#[test]
+fn comparison_override_takes_precedence_over_the_preview() {
+    let mut timer = timer();
+    let mut component = Component::new();
+    component.settings_mut().comparison_override = Some(comparison::personal_best::NAME.into());
+    let settings = GeneralLayoutSettings::default();
+
+    let personal_best = component.state(&timer.snapshot(), &settings);
+
+    timer
+        .set_preview_comparison(comparison::best_segments::NAME)
+        .unwrap();
+    let preview = component.state(&timer.snapshot(), &settings);
+    assert_eq!(points(&preview), points(&personal_best));
+
+    assert!(timer.set_preview_comparison("Doesn't exist").is_err());
+    assert_eq!(
+        timer.preview_comparison(),
+        Some(comparison::best_segments::NAME)
+    );
+}
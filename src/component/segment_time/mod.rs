@@ -5,9 +5,7 @@
 
 use super::key_value;
 use crate::{
-    Timer, TimerPhase,
-    analysis::state_helper::comparison_single_segment_time,
-    comparison,
+    Timer, comparison,
     platform::prelude::*,
     settings::{Color, Field, Gradient, SettingsDescription, Value},
     timing::formatter::{Accuracy, SegmentTime, TimeFormatter},
@@ -109,19 +107,7 @@ impl Component {
         let comparison = comparison::or_current(resolved_comparison, timer);
         let key = self.text(resolved_comparison); // FIXME: Uncow
 
-        let time = catch! {
-            // FIXME: We shouldn't need to manually do this "bounds check".
-            if timer.current_phase() == TimerPhase::Ended {
-                return None;
-            }
-
-            comparison_single_segment_time(
-                timer.run(),
-                timer.current_split_index()?,
-                comparison,
-                timer.current_timing_method(),
-            )?
-        };
+        let time = timer.comparison_segment_time(comparison, timer.current_timing_method());
 
         state.background = self.settings.background;
         state.key_color = self.settings.label_color;
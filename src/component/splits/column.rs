@@ -1,11 +1,15 @@
 use crate::{
     analysis::{self, possible_time_save, split_color},
     comparison,
-    component::splits::Settings as SplitsSettings,
+    component::splits::{
+        MAX_DELTA_PERCENT_DECIMALS, Settings as SplitsSettings, SkippedSegmentDelta,
+    },
     platform::prelude::*,
     settings::{Color, SemanticColor},
     timing::{
-        formatter::{Delta, Regular, SegmentTime, TimeFormatter},
+        formatter::{
+            Accuracy, Delta, MINUS, PLUS, PLUS_MINUS, Regular, SegmentTime, TimeFormatter,
+        },
         Snapshot,
     },
     util::Clear,
@@ -24,6 +28,21 @@ pub struct ColumnSettings {
     /// The kind of the column.
     #[serde(flatten)]
     pub kind: ColumnKind,
+    /// Overrides how the values of the column are aligned. If set to `None`,
+    /// the alignment is based on the kind of the column. Times are aligned to
+    /// the end, while variables are aligned to the start.
+    pub alignment: Option<ColumnAlignment>,
+    /// The name of the group the column belongs to. Adjacent columns with the
+    /// same group share a label that spans all of them above their own column
+    /// labels. If set to `None`, the column doesn't belong to any group.
+    pub group: Option<String>,
+    /// The minimum width of the column in relative layout units. If set to
+    /// `None`, the column is only as wide as its widest value.
+    pub min_width: Option<f32>,
+    /// The maximum width of the column in relative layout units. Values that
+    /// are wider than this are ellipsized. If set to `None`, the column grows
+    /// to fit its widest value.
+    pub max_width: Option<f32>,
 }
 
 /// The kind of a column. It can either be a column that shows a variable or a
@@ -57,6 +76,34 @@ pub struct TimeColumn {
     /// of the Timer is used for showing the time. Otherwise the Timing Method
     /// provided is used.
     pub timing_method: Option<TimingMethod>,
+    /// Specifies the name of a custom timing method registered on the
+    /// [`Timer`](crate::Timer) to show instead. If set, the column shows the
+    /// split times or segment times of the current attempt for that timing
+    /// method, depending on whether the column updates with a segment based
+    /// value. Comparisons are not available for custom timing methods. As
+    /// custom timing methods are live only, the column is empty for all the
+    /// segments that haven't been split during the current attempt.
+    pub custom_timing_method: Option<String>,
+    /// Specifies whether to show the segment time below the value of the
+    /// column. Once the value got updated with the current attempt's
+    /// information, the current attempt's segment time is shown. Before that,
+    /// the comparison's segment time is shown, unless the column starts out
+    /// empty.
+    pub show_segment_time_below: bool,
+    /// Specifies whether the current segment shows the comparison's split time
+    /// as a ghost, until the value of the column gets updated with the current
+    /// attempt's information. The value is marked as a ghost, so it can be
+    /// visualized differently than the regular values.
+    pub show_comparison_ghost: bool,
+    /// Overrides how the signs of the deltas are shown. If set to `None`,
+    /// positive and negative deltas show their sign, while deltas of exactly
+    /// zero don't show any sign.
+    pub sign_style: Option<SignStyle>,
+    /// Overrides the accuracy of the times shown in the column. If set to
+    /// `None`, the accuracy of the splits component for the kind of time shown
+    /// is used, so split times, segment times and deltas can each have their
+    /// own accuracy.
+    pub accuracy_override: Option<Accuracy>,
 }
 
 /// A column that shows a variable.
@@ -66,6 +113,29 @@ pub struct VariableColumn {
     pub variable_name: String,
 }
 
+/// Specifies how the values of a column are aligned within the column.
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ColumnAlignment {
+    /// The values are aligned to the start of the column.
+    Start,
+    /// The values are centered within the column.
+    Center,
+    /// The values are aligned to the end of the column.
+    End,
+}
+
+/// Specifies how the signs of the deltas shown in a column are displayed.
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum SignStyle {
+    /// The sign is always shown. Deltas that are displayed as zero are shown
+    /// with a plus-minus sign.
+    Always,
+    /// Only the sign of negative deltas is shown.
+    NegativeOnly,
+    /// No sign is shown at all.
+    None,
+}
+
 /// Specifies the value a segment starts out with before it gets replaced
 /// with the current attempt's information when splitting.
 #[derive(Copy, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -113,13 +183,21 @@ pub enum ColumnUpdateWith {
     /// Segment component. If there is no time saved or lost, then value gets
     /// replaced by the current attempt's segment time instead.
     SegmentDeltaWithFallback,
+    /// The value gets replaced by the delta of the current attempt's and the
+    /// comparison's split time, expressed as a percentage of the comparison's
+    /// split time. If the comparison has no split time or it is zero, the
+    /// value is empty.
+    DeltaPercent,
 }
 
 /// Specifies when a column's value gets updated.
 #[derive(Copy, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum ColumnUpdateTrigger {
     /// The value gets updated as soon as the segment is started. The value
-    /// constantly updates until the segment ends.
+    /// constantly updates until the segment ends. When combined with
+    /// [`ColumnUpdateWith::Delta`], this means the current segment immediately
+    /// shows the live delta to the comparison's split time, which is negative
+    /// for most of the segment and isn't colored until the segment ends.
     OnStartingSegment,
     /// The value doesn't immediately get updated when the segment is started.
     /// Instead the value constantly gets updated once the segment time is
@@ -135,6 +213,10 @@ impl Default for ColumnSettings {
         ColumnSettings {
             name: String::from("Column"),
             kind: ColumnKind::Time(TimeColumn::default()),
+            alignment: None,
+            group: None,
+            min_width: None,
+            max_width: None,
         }
     }
 }
@@ -147,6 +229,11 @@ impl Default for TimeColumn {
             update_trigger: ColumnUpdateTrigger::Contextual,
             comparison_override: None,
             timing_method: None,
+            custom_timing_method: None,
+            show_segment_time_below: false,
+            show_comparison_ghost: false,
+            sign_style: None,
+            accuracy_override: None,
         }
     }
 }
@@ -163,11 +250,56 @@ pub struct ColumnState {
     /// This value indicates whether the column is currently frequently being
     /// updated. This can be used for rendering optimizations.
     pub updates_frequently: bool,
+    /// How the value is supposed to be aligned within the column.
+    pub alignment: ColumnAlignment,
+    /// The minimum width of the column in relative layout units. This is
+    /// [`None`] if the column is supposed to be sized based on its values.
+    pub min_width: Option<f32>,
+    /// The maximum width of the column in relative layout units. Values that
+    /// don't fit are supposed to be ellipsized. This is [`None`] if the column
+    /// is allowed to grow to fit its values.
+    pub max_width: Option<f32>,
+    /// The name of the comparison the value is based on. This is the column's
+    /// comparison override if the run has that comparison, and the current
+    /// comparison otherwise. It is empty for columns that don't use a
+    /// comparison, such as variable columns.
+    pub resolved_comparison: String,
+    /// A secondary value that is supposed to be shown smaller below the value.
+    /// This is [`None`] if the column doesn't show a secondary value.
+    pub sub_value: Option<String>,
+    /// Specifies whether the value is the comparison's split time that is
+    /// shown as a ghost on the current segment, until the value gets updated
+    /// with the current attempt's information. A ghost is supposed to be
+    /// visualized more subtly than a regular value.
+    pub is_ghost: bool,
 }
 
 impl Clear for ColumnState {
     fn clear(&mut self) {
         self.value.clear();
+        self.resolved_comparison.clear();
+        if let Some(sub_value) = &mut self.sub_value {
+            sub_value.clear();
+        }
+    }
+}
+
+/// Describes the label of a group of adjacent columns to visualize above their
+/// column labels.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ColumnGroupLabel {
+    /// The name of the group.
+    pub name: String,
+    /// The index of the first column that belongs to the group. The columns
+    /// are counted from right to left.
+    pub start_column: usize,
+    /// The amount of adjacent columns the label spans.
+    pub column_count: usize,
+}
+
+impl Clear for ColumnGroupLabel {
+    fn clear(&mut self) {
+        self.name.clear();
     }
 }
 
@@ -175,6 +307,7 @@ enum ColumnFormatter {
     Time,
     Delta,
     SegmentTime,
+    DeltaPercent(Option<TimeSpan>),
 }
 
 pub fn update_state(
@@ -188,6 +321,16 @@ pub fn update_state(
     current_split: Option<usize>,
     method: TimingMethod,
 ) {
+    state.alignment = column_settings
+        .alignment
+        .unwrap_or(match column_settings.kind {
+            ColumnKind::Variable(_) => ColumnAlignment::Start,
+            ColumnKind::Time(_) => ColumnAlignment::End,
+        });
+    state.min_width = column_settings.min_width;
+    state.max_width = column_settings.max_width;
+    state.resolved_comparison.clear();
+
     match &column_settings.kind {
         ColumnKind::Variable(column) => {
             state.value.clear();
@@ -211,6 +354,8 @@ pub fn update_state(
             state.semantic_color = SemanticColor::Default;
             state.visual_color = layout_settings.text_color;
             state.updates_frequently = false;
+            state.sub_value = None;
+            state.is_ghost = false;
         }
         ColumnKind::Time(column) => {
             update_time_column(
@@ -226,6 +371,10 @@ pub fn update_state(
             );
         }
     }
+
+    if state.value.is_empty() {
+        state.value.push_str(&splits_settings.empty_placeholder);
+    }
 }
 
 fn update_time_column(
@@ -239,9 +388,24 @@ fn update_time_column(
     current_split: Option<usize>,
     method: TimingMethod,
 ) {
+    if let Some(custom_timing_method) = &column_settings.custom_timing_method {
+        update_custom_time_column(
+            state,
+            column_settings,
+            custom_timing_method,
+            timer,
+            splits_settings,
+            layout_settings,
+            segment,
+            segment_index,
+        );
+        return;
+    }
+
     let method = column_settings.timing_method.unwrap_or(method);
     let resolved_comparison = comparison::resolve(&column_settings.comparison_override, timer);
     let comparison = comparison::or_current(resolved_comparison, timer);
+    state.resolved_comparison.push_str(comparison);
     let update_value = time_column_update_value(
         column_settings,
         timer,
@@ -252,9 +416,16 @@ fn update_time_column(
         comparison,
     );
     let updated = update_value.is_some();
+    let is_ghost =
+        !updated && column_settings.show_comparison_ghost && Some(segment_index) == current_split;
+    let start_with = if is_ghost {
+        ColumnStartWith::ComparisonTime
+    } else {
+        column_settings.start_with
+    };
     let ((column_value, semantic_color, formatter), is_live) = update_value.unwrap_or_else(|| {
         (
-            match column_settings.start_with {
+            match start_with {
                 ColumnStartWith::Empty => (None, SemanticColor::Default, ColumnFormatter::Time),
                 ColumnStartWith::ComparisonTime => (
                     segment.comparison(comparison)[method],
@@ -272,7 +443,14 @@ fn update_time_column(
                     ColumnFormatter::SegmentTime,
                 ),
                 ColumnStartWith::PossibleTimeSave => (
-                    possible_time_save::calculate(timer, segment_index, comparison, false).0,
+                    possible_time_save::calculate_for_timing_method(
+                        timer,
+                        segment_index,
+                        comparison,
+                        false,
+                        method,
+                    )
+                    .0,
                     SemanticColor::Default,
                     ColumnFormatter::SegmentTime,
                 ),
@@ -281,7 +459,8 @@ fn update_time_column(
         )
     });
 
-    let is_empty = column_settings.start_with == ColumnStartWith::Empty && !updated;
+    let is_empty = start_with == ColumnStartWith::Empty && !updated;
+    state.is_ghost = is_ghost;
 
     state.updates_frequently =
         is_live && column_value.is_some() && timer.current_phase().updates_frequently(method);
@@ -289,18 +468,29 @@ fn update_time_column(
     state.value.clear();
 
     if !is_empty {
+        let is_delta = matches!(
+            formatter,
+            ColumnFormatter::Delta | ColumnFormatter::DeltaPercent(_)
+        );
         let _ = match formatter {
             ColumnFormatter::Time => write!(
                 state.value,
                 "{}",
-                Regular::with_accuracy(splits_settings.split_time_accuracy).format(column_value)
+                Regular::with_accuracy(
+                    column_settings
+                        .accuracy_override
+                        .unwrap_or(splits_settings.split_time_accuracy)
+                )
+                .format(column_value)
             ),
             ColumnFormatter::Delta => write!(
                 state.value,
                 "{}",
                 Delta::custom(
                     splits_settings.delta_drop_decimals,
-                    splits_settings.delta_time_accuracy,
+                    column_settings
+                        .accuracy_override
+                        .unwrap_or(splits_settings.delta_time_accuracy),
                 )
                 .format(column_value)
             ),
@@ -308,14 +498,158 @@ fn update_time_column(
                 write!(
                     state.value,
                     "{}",
-                    SegmentTime::with_accuracy(splits_settings.segment_time_accuracy)
-                        .format(column_value)
+                    SegmentTime::with_accuracy(
+                        column_settings
+                            .accuracy_override
+                            .unwrap_or(splits_settings.segment_time_accuracy)
+                    )
+                    .format(column_value)
                 )
             }
+            ColumnFormatter::DeltaPercent(comparison_time) => {
+                match catch! { delta_percentage(column_value?, comparison_time?)? } {
+                    Some(percentage) => write!(
+                        state.value,
+                        "{}{:.*}%",
+                        if percentage < 0.0 { MINUS } else { PLUS },
+                        usize::from(
+                            splits_settings
+                                .delta_percent_decimals
+                                .min(MAX_DELTA_PERCENT_DECIMALS)
+                        ),
+                        percentage.abs(),
+                    ),
+                    None => Ok(()),
+                }
+            }
         };
+
+        if let Some(sign_style) = column_settings.sign_style {
+            if is_delta && !state.value.is_empty() {
+                apply_sign_style(&mut state.value, sign_style);
+            }
+        }
     }
+
+    let semantic_color = if updated
+        && column_settings.update_with.is_segment_delta()
+        && follows_skipped_segment(timer, segment_index, method)
+    {
+        match splits_settings.skipped_segment_delta {
+            SkippedSegmentDelta::Combined => semantic_color,
+            SkippedSegmentDelta::Empty => {
+                state.value.clear();
+                state.updates_frequently = false;
+                SemanticColor::Default
+            }
+            SkippedSegmentDelta::Annotated => {
+                if !state.value.is_empty() {
+                    state.value.push('*');
+                }
+                semantic_color
+            }
+        }
+    } else {
+        semantic_color
+    };
+
     state.semantic_color = semantic_color;
     state.visual_color = semantic_color.visualize(layout_settings);
+
+    let segment_time = if !column_settings.show_segment_time_below {
+        None
+    } else if updated {
+        if is_live {
+            analysis::live_segment_time(timer, segment_index, method)
+        } else {
+            analysis::previous_segment_time(timer, segment_index, method)
+        }
+    } else if start_with != ColumnStartWith::Empty {
+        analysis::comparison_combined_segment_time(timer.run(), segment_index, comparison, method)
+    } else {
+        None
+    };
+
+    if let Some(segment_time) = segment_time {
+        let sub_value = state.sub_value.get_or_insert_with(String::new);
+        sub_value.clear();
+        let _ = write!(
+            sub_value,
+            "{}",
+            SegmentTime::with_accuracy(
+                column_settings
+                    .accuracy_override
+                    .unwrap_or(splits_settings.segment_time_accuracy)
+            )
+            .format(segment_time)
+        );
+    } else {
+        state.sub_value = None;
+    }
+}
+
+fn follows_skipped_segment(
+    timer: &Snapshot<'_>,
+    segment_index: usize,
+    method: TimingMethod,
+) -> bool {
+    segment_index
+        .checked_sub(1)
+        .is_some_and(|index| timer.run().segment(index).split_time()[method].is_none())
+}
+
+fn update_custom_time_column(
+    state: &mut ColumnState,
+    column_settings: &TimeColumn,
+    custom_timing_method: &str,
+    timer: &Snapshot<'_>,
+    splits_settings: &SplitsSettings,
+    layout_settings: &GeneralLayoutSettings,
+    segment: &Segment,
+    segment_index: usize,
+) {
+    let split_time = segment.custom_split_time(custom_timing_method);
+
+    state.value.clear();
+
+    if column_settings.update_with.is_segment_based() {
+        let previous_split_time = match segment_index.checked_sub(1) {
+            Some(previous_index) => timer
+                .run()
+                .segment(previous_index)
+                .custom_split_time(custom_timing_method),
+            None => Some(TimeSpan::zero()),
+        };
+        if let Some(segment_time) = catch! { split_time? - previous_split_time? } {
+            let _ = write!(
+                state.value,
+                "{}",
+                SegmentTime::with_accuracy(
+                    column_settings
+                        .accuracy_override
+                        .unwrap_or(splits_settings.segment_time_accuracy)
+                )
+                .format(segment_time)
+            );
+        }
+    } else if let Some(split_time) = split_time {
+        let _ = write!(
+            state.value,
+            "{}",
+            Regular::with_accuracy(
+                column_settings
+                    .accuracy_override
+                    .unwrap_or(splits_settings.split_time_accuracy)
+            )
+            .format(split_time)
+        );
+    }
+
+    state.updates_frequently = false;
+    state.semantic_color = SemanticColor::Default;
+    state.visual_color = layout_settings.text_color;
+    state.sub_value = None;
+    state.is_ghost = false;
 }
 
 fn time_column_update_value(
@@ -400,7 +734,11 @@ fn time_column_update_value(
 
         (SegmentTime, false) => (
             analysis::previous_segment_time(timer, segment_index, method),
-            SemanticColor::Default,
+            if analysis::check_best_segment(timer, segment_index, method) {
+                SemanticColor::BestSegment
+            } else {
+                SemanticColor::Default
+            },
             ColumnFormatter::SegmentTime,
         ),
         (SegmentTime, true) => (
@@ -430,17 +768,73 @@ fn time_column_update_value(
             SemanticColor::Default,
             ColumnFormatter::Delta,
         ),
+
+        (DeltaPercent, false) => {
+            let comparison_time = segment.comparison(comparison)[method];
+            let delta = catch! { segment.split_time()[method]? - comparison_time? };
+            (
+                delta,
+                split_color(timer, delta, segment_index, true, true, comparison, method),
+                ColumnFormatter::DeltaPercent(comparison_time),
+            )
+        }
+        (DeltaPercent, true) => {
+            let comparison_time = segment.comparison(comparison)[method];
+            (
+                catch! { timer.current_time()[method]? - comparison_time? },
+                SemanticColor::Default,
+                ColumnFormatter::DeltaPercent(comparison_time),
+            )
+        }
     };
 
     Some((value, is_live))
 }
 
+fn apply_sign_style(value: &mut String, sign_style: SignStyle) {
+    let (sign_len, is_negative) = if value.starts_with(PLUS) {
+        (PLUS.len(), false)
+    } else if value.starts_with(MINUS) {
+        (MINUS.len(), true)
+    } else {
+        (0, false)
+    };
+
+    // Parity is decided based on the value that is displayed, so deltas that
+    // round to zero are considered even too.
+    let is_zero = value[sign_len..]
+        .bytes()
+        .all(|b| !b.is_ascii_digit() || b == b'0');
+
+    let sign = match sign_style {
+        SignStyle::Always if is_zero => PLUS_MINUS,
+        SignStyle::Always => return,
+        SignStyle::NegativeOnly if is_negative && !is_zero => return,
+        SignStyle::NegativeOnly | SignStyle::None => "",
+    };
+
+    value.replace_range(..sign_len, sign);
+}
+
+fn delta_percentage(delta: TimeSpan, comparison_time: TimeSpan) -> Option<f64> {
+    let comparison_time = comparison_time.total_seconds();
+    if comparison_time <= 0.0 {
+        return None;
+    }
+    Some(100.0 * delta.total_seconds() / comparison_time)
+}
+
 impl ColumnUpdateWith {
     const fn is_segment_based(self) -> bool {
         use ColumnUpdateWith::*;
         matches!(self, SegmentDelta | SegmentTime | SegmentDeltaWithFallback)
     }
 
+    const fn is_segment_delta(self) -> bool {
+        use ColumnUpdateWith::*;
+        matches!(self, SegmentDelta | SegmentDeltaWithFallback)
+    }
+
     const fn has_fallback(self) -> bool {
         use ColumnUpdateWith::*;
         matches!(self, DeltaWithFallback | SegmentDeltaWithFallback)
@@ -6,15 +6,25 @@
 //! [`Segment`](crate::run::Segment) needs to be shown all the time.
 
 use crate::{
-    GeneralLayoutSettings,
+    GeneralLayoutSettings, Run, Segment, TimeSpan, TimingMethod,
+    analysis::{self, possible_time_save},
+    comparison,
+    layout::metrics::{DEFAULT_COMPONENT_HEIGHT, SUB_VALUE_HEIGHT, TWO_ROW_HEIGHT},
     platform::prelude::*,
     settings::{
         self, Color, Field, Gradient, ImageCache, ImageId, ListGradient, SettingsDescription, Value,
     },
-    timing::{Snapshot, formatter::Accuracy},
+    timing::{
+        Snapshot,
+        formatter::{Accuracy, Delta, Regular, TimeFormatter},
+    },
     util::{Clear, ClearVec},
 };
-use core::cmp::{max, min};
+use core::{
+    cmp::{Ordering, max, min},
+    fmt::Write,
+};
+use serde::{Deserialize as _, Deserializer, Serializer};
 use serde_derive::{Deserialize, Serialize};
 
 #[cfg(test)]
@@ -23,13 +33,14 @@ mod tests;
 mod column;
 
 pub use column::{
-    ColumnKind, ColumnSettings, ColumnStartWith, ColumnState, ColumnUpdateTrigger,
-    ColumnUpdateWith, TimeColumn, VariableColumn,
+    ColumnAlignment, ColumnGroupLabel, ColumnKind, ColumnSettings, ColumnStartWith, ColumnState,
+    ColumnUpdateTrigger, ColumnUpdateWith, SignStyle, TimeColumn, VariableColumn,
 };
 
-const SETTINGS_BEFORE_COLUMNS: usize = 15;
-const SETTINGS_PER_TIME_COLUMN: usize = 6;
-const SETTINGS_PER_VARIABLE_COLUMN: usize = 2;
+const SETTINGS_BEFORE_COLUMNS: usize = 26;
+const SETTINGS_PER_TIME_COLUMN: usize = 15;
+const SETTINGS_PER_VARIABLE_COLUMN: usize = 6;
+const MAX_DELTA_PERCENT_DECIMALS: u8 = 3;
 
 /// The Splits Component is the main component for visualizing all the split
 /// times. Each [`Segment`](crate::run::Segment) is shown in a tabular fashion
@@ -42,6 +53,8 @@ pub struct Component {
     settings: Settings,
     current_split_index: Option<usize>,
     scroll_offset: isize,
+    scroll_target: Option<usize>,
+    last_comparison: Option<String>,
 }
 
 /// The Settings for this component.
@@ -61,9 +74,19 @@ pub struct Settings {
     /// segments to be shown in this scrolling window when it automatically
     /// scrolls.
     pub split_preview_count: usize,
-    /// Specifies whether thin separators should be shown between the individual
-    /// segments shown by the component.
-    pub show_thin_separators: bool,
+    /// Specifies where the current segment is kept within the scrolling
+    /// window of segments while the window automatically scrolls.
+    pub anchor: CurrentSplitAnchor,
+    /// Specifies between which of the segments shown by the component thin
+    /// separators should be shown. Settings that were stored before this
+    /// setting existed specify whether thin separators are shown through the
+    /// `show_thin_separators` flag, which is mapped to either
+    /// [`SeparatorMode::All`] or [`SeparatorMode::None`].
+    #[serde(
+        alias = "show_thin_separators",
+        deserialize_with = "deserialize_separator_mode"
+    )]
+    pub separator_mode: SeparatorMode,
     /// If the last segment is to always be shown, this determines whether to
     /// show a more pronounced separator in front of the last segment, if it is
     /// not directly adjacent to the segment shown right before it in the
@@ -96,6 +119,54 @@ pub struct Settings {
     pub delta_drop_decimals: bool,
     /// Specifies whether to show the names of the columns above the splits.
     pub show_column_labels: bool,
+    /// The text to show in a column that has no value to display. This is
+    /// empty by default, which means that nothing is shown.
+    pub empty_placeholder: String,
+    /// Specifies whether to show an indicator next to the current segment's
+    /// name, in addition to the gradient shown behind it.
+    pub current_split_indicator: bool,
+    /// The number of decimals to show for columns that show the delta as a
+    /// percentage of the comparison's split time. At most 3 decimals are
+    /// shown.
+    pub delta_percent_decimals: u8,
+    /// Specifies whether to mark the segment of the Personal Best that lost
+    /// the most time compared to its best segment time.
+    pub highlight_pb_worst_segment: bool,
+    /// Specifies whether the header, consisting of the column labels and the
+    /// column group labels, is supposed to stay pinned at the top of the list,
+    /// even if the host scrolls the rows of the list itself.
+    pub sticky_header: bool,
+    /// Specifies whether to show the icons of the segments. If this is
+    /// disabled, no space is reserved for the icons, even if the segments have
+    /// icons.
+    pub show_icons: bool,
+    /// Specifies how columns that show segment deltas display the delta of a
+    /// segment that follows a skipped segment.
+    pub skipped_segment_delta: SkippedSegmentDelta,
+    /// The time bands that determine which medal each completed segment is
+    /// awarded. If this is [`None`], no medals are awarded.
+    pub medal_bands: Option<MedalBands>,
+    /// The colors to tint the backgrounds of the segments with based on the
+    /// medals they are awarded. If this is specified, the backgrounds of the
+    /// segments are no longer alternating. Segments that aren't awarded a
+    /// medal don't have a background then.
+    pub medal_backgrounds: Option<MedalColors>,
+    /// The indices of the segments to show. If this is specified, exactly
+    /// these segments are shown in the order of the run, instead of the
+    /// scrolling window of segments. Separators are shown after segments that
+    /// are followed by hidden segments. Indices that are out of bounds are
+    /// ignored. Scrolling is disabled while this is specified. If there are
+    /// fewer rows than the amount of segments to show, the remaining rows are
+    /// filled with blank space if that is enabled.
+    pub visible_indices: Option<Vec<usize>>,
+    /// Specifies whether to show a blank row in place of the segments that
+    /// are hidden between two of the segments shown, when only specific
+    /// segments are shown.
+    pub show_gap_rows: bool,
+    /// Specifies whether each row provides a tooltip summarizing its segment.
+    /// Building the tooltips has a cost, so they should only be enabled if the
+    /// renderer actually shows them.
+    pub show_tooltips: bool,
     /// The columns to show on the splits. These can be configured in various
     /// way to show split times, segment times, deltas and so on. The columns
     /// are defined from right to left.
@@ -117,11 +188,215 @@ pub struct SplitState {
     /// Describes if this segment is the segment the active attempt is currently
     /// on.
     pub is_current_split: bool,
+    /// Specifies whether a thin separator should be shown after this segment.
+    pub show_separator_after: bool,
+    /// The indicator to show next to the segment's name. This is only ever
+    /// shown for the current segment and only if the setting is enabled.
+    pub indicator: SplitIndicator,
     /// The index of the segment based on all the segments of the run. This may
     /// differ from the index of this `SplitState` in the `State` object, as
     /// there can be a scrolling window, showing only a subset of segments. Each
     /// index is guaranteed to be unique.
     pub index: usize,
+    /// Describes if this segment is the segment of the Personal Best that lost
+    /// the most time compared to its best segment time. This is only ever set
+    /// if the setting is enabled.
+    pub is_pb_worst_segment: bool,
+    /// The medal awarded to this segment based on how close its segment time
+    /// in the active attempt was to its best segment time. This is only ever
+    /// set for completed segments and only if medal bands are configured.
+    pub medal: Option<MedalTier>,
+    /// The color to use for the background of this segment instead of the
+    /// background of the list. This is only ever set if the segment is
+    /// awarded a medal and the backgrounds are supposed to be colored based
+    /// on the medals.
+    pub background: Option<Color>,
+    /// The reason why this row is blank. This is [`None`] for rows that show
+    /// a segment.
+    pub blank_reason: Option<BlankReason>,
+    /// The name of the comparison that the coloring of the row as a whole is
+    /// based on. This is always the current comparison, regardless of the
+    /// comparison overrides of the individual columns, which report their own
+    /// comparison instead. It is empty for blank rows.
+    pub resolved_comparison: String,
+    /// A summary of the segment that renderers with support for hovering can
+    /// show as a tooltip. It consists of the full name of the segment, its
+    /// split time, its delta and whether it is a best segment. It is empty for
+    /// blank rows and if tooltips are disabled.
+    pub tooltip: String,
+}
+
+/// Specifies between which rows of the Splits Component thin separators are
+/// shown.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SeparatorMode {
+    /// No thin separators are shown.
+    None,
+    /// Thin separators are shown between all the rows.
+    #[default]
+    All,
+    /// Thin separators are only shown after the last segment of each section.
+    /// Segments whose names start with a `-` are considered subsplits that
+    /// belong to the section that is ended by the next segment without a `-`.
+    SectionBoundaries,
+}
+
+impl SeparatorMode {
+    fn show_after(self, segment: Option<&Segment>) -> bool {
+        match self {
+            SeparatorMode::None => false,
+            SeparatorMode::All => true,
+            SeparatorMode::SectionBoundaries => {
+                segment.is_some_and(|segment| !segment.name().starts_with('-'))
+            }
+        }
+    }
+}
+
+/// Specifies how columns of the Splits Component that show segment deltas
+/// display the delta of a segment that follows a skipped segment. As the
+/// skipped segment has no split time, such a delta spans both segments.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SkippedSegmentDelta {
+    /// The delta of both segments combined is shown.
+    #[default]
+    Combined,
+    /// No delta is shown.
+    Empty,
+    /// The delta of both segments combined is shown, followed by an asterisk
+    /// to indicate that it spans multiple segments.
+    Annotated,
+}
+
+/// Specifies where the Splits Component keeps the current segment within the
+/// scrolling window of segments.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CurrentSplitAnchor {
+    /// The window only scrolls once the current segment would otherwise not
+    /// be followed by the number of upcoming segments to show.
+    #[default]
+    Auto,
+    /// The current segment is kept at the top of the window.
+    Top,
+    /// The current segment is kept in the center of the window. If the window
+    /// has an even number of rows, the upper of the two center rows is used.
+    Center,
+}
+
+/// The time bands that determine which medal a segment is awarded. A segment
+/// is awarded a medal if its segment time is at most the respective amount of
+/// time slower than its best segment time. New best segments are always
+/// awarded the gold medal.
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MedalBands {
+    /// The maximum amount of time a segment may be slower than its best
+    /// segment time to be awarded the gold medal.
+    #[serde(serialize_with = "serialize_time_span")]
+    pub gold: TimeSpan,
+    /// The maximum amount of time a segment may be slower than its best
+    /// segment time to be awarded the silver medal.
+    #[serde(serialize_with = "serialize_time_span")]
+    pub silver: TimeSpan,
+    /// The maximum amount of time a segment may be slower than its best
+    /// segment time to be awarded the bronze medal.
+    #[serde(serialize_with = "serialize_time_span")]
+    pub bronze: TimeSpan,
+}
+
+impl MedalBands {
+    /// Creates new medal bands from the thresholds provided. The thresholds
+    /// are normalized, such that the silver medal never requires a smaller
+    /// difference than the gold medal and the bronze medal never requires a
+    /// smaller difference than the silver medal.
+    pub fn new(gold: TimeSpan, silver: TimeSpan, bronze: TimeSpan) -> Self {
+        let silver = max(silver, gold);
+        let bronze = max(bronze, silver);
+        Self {
+            gold,
+            silver,
+            bronze,
+        }
+    }
+
+    /// Determines the medal that a segment time is awarded when compared to
+    /// the best segment time provided.
+    pub fn medal(&self, segment_time: TimeSpan, best_segment_time: TimeSpan) -> Option<MedalTier> {
+        let difference = segment_time - best_segment_time;
+        if difference <= self.gold {
+            Some(MedalTier::Gold)
+        } else if difference <= self.silver {
+            Some(MedalTier::Silver)
+        } else if difference <= self.bronze {
+            Some(MedalTier::Bronze)
+        } else {
+            None
+        }
+    }
+}
+
+/// A medal that a segment can be awarded based on the [`MedalBands`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MedalTier {
+    /// The segment time is the closest to the best segment time.
+    Gold,
+    /// The segment time is somewhat close to the best segment time.
+    Silver,
+    /// The segment time is still reasonably close to the best segment time.
+    Bronze,
+}
+
+/// The colors that are associated with each of the medals.
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MedalColors {
+    /// The color associated with the gold medal.
+    pub gold: Color,
+    /// The color associated with the silver medal.
+    pub silver: Color,
+    /// The color associated with the bronze medal.
+    pub bronze: Color,
+}
+
+impl Default for MedalColors {
+    fn default() -> Self {
+        Self {
+            gold: Color::rgba(1.0, 212.0 / 255.0, 0.0, 0.25),
+            silver: Color::rgba(192.0 / 255.0, 192.0 / 255.0, 192.0 / 255.0, 0.25),
+            bronze: Color::rgba(205.0 / 255.0, 127.0 / 255.0, 50.0 / 255.0, 0.25),
+        }
+    }
+}
+
+impl MedalColors {
+    /// Returns the color associated with the medal provided.
+    pub const fn color(&self, medal: MedalTier) -> Color {
+        match medal {
+            MedalTier::Gold => self.gold,
+            MedalTier::Silver => self.silver,
+            MedalTier::Bronze => self.bronze,
+        }
+    }
+}
+
+/// An indicator that is shown next to a segment's name to highlight it.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SplitIndicator {
+    /// No indicator is shown.
+    #[default]
+    None,
+    /// An arrow pointing towards the segment's name is shown.
+    Arrow,
+}
+
+/// The reason why a row of the Splits Component is blank, so that it can be
+/// visualized accordingly.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BlankReason {
+    /// The row fills up the remaining space, because there are not enough
+    /// segments to show. It is supposed to be empty.
+    Padding,
+    /// The row is shown in place of segments that are hidden between the
+    /// segments shown. It is supposed to show an ellipsis.
+    Gap,
 }
 
 impl Clear for SplitState {
@@ -129,6 +404,8 @@ impl Clear for SplitState {
         self.icon = *ImageId::EMPTY;
         self.name.clear();
         self.columns.clear();
+        self.resolved_comparison.clear();
+        self.tooltip.clear();
     }
 }
 
@@ -139,8 +416,16 @@ pub struct State {
     pub background: ListGradient,
     /// The column labels to visualize about the list of splits. If this is
     /// `None`, no labels are supposed to be visualized. The list is specified
-    /// from right to left.
+    /// from right to left. The column labels form the header of the list. They
+    /// are not part of the rows in `splits` and don't depend on which segments
+    /// are currently scrolled into view.
     pub column_labels: Option<ClearVec<String>>,
+    /// The labels of the groups of columns to visualize above the column
+    /// labels. Each label spans one or more adjacent columns. If this is
+    /// `None`, no column belongs to any group or no column labels are supposed
+    /// to be visualized. Renderers that don't support column groups may ignore
+    /// them entirely.
+    pub column_group_labels: Option<ClearVec<ColumnGroupLabel>>,
     /// The list of all the segments to visualize.
     pub splits: ClearVec<SplitState>,
     /// Specifies whether the current run has any icons, even those that are not
@@ -148,18 +433,67 @@ pub struct State {
     /// indenting the icon column, even when the icons are scrolled outside the
     /// splits component.
     pub has_icons: bool,
+    /// Specifies whether any of the columns may show secondary values below
+    /// their values. If this is the case, the room for them is included in the
+    /// height of the rows.
+    pub has_sub_values: bool,
     /// Specifies whether thin separators should be shown between the individual
-    /// segments shown by the component.
+    /// segments shown by the component. This is deprecated, as it doesn't
+    /// describe which of the segments the separators are shown after. Use
+    /// [`SplitState::show_separator_after`] instead.
     pub show_thin_separators: bool,
     /// Describes whether a more pronounced separator should be shown in front
     /// of the last segment provided.
     pub show_final_separator: bool,
+    /// The number of segments that are hidden between the segments in the
+    /// scrolling window and the last segment, if the last segment is always
+    /// shown. Renderers can use this to indicate how many segments are left
+    /// out. This is 0 if the last segment is directly adjacent to the segments
+    /// shown before it or if it isn't always shown.
+    pub hidden_segments_before_last_split: usize,
+    /// The index of the first segment shown. If the segments are shown in a
+    /// scrolling window, this is where the window starts, so together with the
+    /// number of rows it describes the part of the run that is visible. If
+    /// only specific segments are shown, this is the index of the first of
+    /// them.
+    pub first_visible_index: usize,
+    /// Specifies whether the last segment is pinned to the end of the list,
+    /// because it is always shown, but it is outside of the scrolling window.
+    pub last_split_is_pinned: bool,
     /// Specifies whether to display each split as two rows, with the segment
     /// name being in one row and the times being in the other.
     pub display_two_rows: bool,
     /// The gradient to show behind the current segment as an indicator of it
     /// being the current segment.
     pub current_split_gradient: Gradient,
+    /// The height of each row of segments. The height is specified relative
+    /// to the height of a component that shows a single line of text. This is
+    /// larger if the segments are displayed as two rows or if the columns may
+    /// show secondary values below their values.
+    pub row_height_units: f32,
+    /// The height of the row that shows the column labels. The height is
+    /// specified relative to the height of a component that shows a single
+    /// line of text. This is 0 if no column labels are supposed to be
+    /// visualized.
+    pub header_height_units: f32,
+    /// Specifies whether the header, consisting of the column labels and the
+    /// column group labels, is supposed to stay pinned at the top of the list.
+    /// This is relevant for renderers that scroll the rows of the list
+    /// themselves, for example when all the segments are shown.
+    pub sticky_header: bool,
+    /// Specifies whether the comparison changed since the last time the state
+    /// was updated. This can be used to highlight the columns whose values
+    /// suddenly change because of it.
+    pub comparison_changed: bool,
+    /// The number of completed segments that were split ahead of the current
+    /// comparison. This covers all of the segments, not just the ones that
+    /// are currently visible. Skipped segments and segments without a
+    /// comparison time are neither counted as ahead nor as behind.
+    pub splits_ahead: usize,
+    /// The number of completed segments that were split behind the current
+    /// comparison. This covers all of the segments, not just the ones that
+    /// are currently visible.
+    pub splits_behind: usize,
 }
 
 impl Default for Settings {
@@ -171,7 +505,8 @@ impl Default for Settings {
             ),
             visual_split_count: 16,
             split_preview_count: 1,
-            show_thin_separators: true,
+            anchor: CurrentSplitAnchor::Auto,
+            separator_mode: SeparatorMode::All,
             separator_last_split: true,
             always_show_last_split: true,
             fill_with_blank_space: true,
@@ -185,6 +520,18 @@ impl Default for Settings {
             delta_time_accuracy: Accuracy::Tenths,
             delta_drop_decimals: true,
             show_column_labels: false,
+            empty_placeholder: String::new(),
+            current_split_indicator: false,
+            delta_percent_decimals: 1,
+            highlight_pb_worst_segment: false,
+            sticky_header: true,
+            show_icons: true,
+            skipped_segment_delta: SkippedSegmentDelta::Combined,
+            medal_bands: None,
+            medal_backgrounds: None,
+            visible_indices: None,
+            show_gap_rows: false,
+            show_tooltips: false,
             columns: vec![
                 ColumnSettings {
                     name: String::from("Time"),
@@ -194,7 +541,16 @@ impl Default for Settings {
                         update_trigger: ColumnUpdateTrigger::OnEndingSegment,
                         comparison_override: None,
                         timing_method: None,
+                        custom_timing_method: None,
+                        show_segment_time_below: false,
+                        show_comparison_ghost: false,
+                        sign_style: None,
+                        accuracy_override: None,
                     }),
+                    alignment: None,
+                    group: None,
+                    min_width: None,
+                    max_width: None,
                 },
                 ColumnSettings {
                     name: String::from("+/−"),
@@ -204,7 +560,16 @@ impl Default for Settings {
                         update_trigger: ColumnUpdateTrigger::Contextual,
                         comparison_override: None,
                         timing_method: None,
+                        custom_timing_method: None,
+                        show_segment_time_below: false,
+                        show_comparison_ghost: false,
+                        sign_style: None,
+                        accuracy_override: None,
                     }),
+                    alignment: None,
+                    group: None,
+                    min_width: None,
+                    max_width: None,
                 },
             ],
         }
@@ -258,6 +623,14 @@ impl Component {
         self.scroll_offset = self.scroll_offset.saturating_add(1);
     }
 
+    /// Scrolls the window of the segments that are shown just far enough for
+    /// the segment with the given index to be shown the next time the state is
+    /// updated. Indices beyond the last segment scroll to the last segment. If
+    /// the segment is already shown, the window doesn't move.
+    pub const fn scroll_to_segment(&mut self, index: usize) {
+        self.scroll_target = Some(index);
+    }
+
     /// Accesses the name of the component.
     pub const fn name(&self) -> &'static str {
         "Splits"
@@ -281,6 +654,20 @@ impl Component {
             self.scroll_offset = 0;
         }
 
+        let comparison = timer.current_comparison();
+        state.comparison_changed = match &mut self.last_comparison {
+            Some(last_comparison) if last_comparison != comparison => {
+                last_comparison.clear();
+                last_comparison.push_str(comparison);
+                true
+            }
+            Some(_) => false,
+            None => {
+                self.last_comparison = Some(comparison.into());
+                false
+            }
+        };
+
         let run = timer.run();
 
         let mut visual_split_count = self.settings.visual_split_count;
@@ -294,38 +681,95 @@ impl Component {
         let locked_last_split = isize::from(self.settings.always_show_last_split);
         let skip_count = min(
             current_split.map_or(0, |current_split| {
+                let current_split = current_split as isize;
                 max(
                     0,
-                    current_split as isize
-                        + self.settings.split_preview_count as isize
-                        + locked_last_split
-                        + 1
-                        - visual_split_count as isize,
+                    match self.settings.anchor {
+                        CurrentSplitAnchor::Auto => {
+                            current_split
+                                + self.settings.split_preview_count as isize
+                                + locked_last_split
+                                + 1
+                                - visual_split_count as isize
+                        }
+                        CurrentSplitAnchor::Top => current_split,
+                        CurrentSplitAnchor::Center => {
+                            current_split
+                                - (visual_split_count as isize - locked_last_split - 1) / 2
+                        }
+                    },
                 )
             }),
             run.len() as isize - visual_split_count as isize,
         );
-        self.scroll_offset = min(
-            max(self.scroll_offset, -skip_count),
-            run.len() as isize - skip_count - visual_split_count as isize,
-        );
-        let skip_count = max(0, skip_count + self.scroll_offset) as usize;
+        let clamp_scroll_offset = |scroll_offset| {
+            min(
+                max(scroll_offset, -skip_count),
+                run.len() as isize - skip_count - visual_split_count as isize,
+            )
+        };
+        self.scroll_offset = clamp_scroll_offset(self.scroll_offset);
+        let visible_indices = self.settings.visible_indices.as_deref();
+        if let Some(index) = self.scroll_target.take() {
+            let index = min(index, run.len() - 1);
+            let window_len = visual_split_count as isize - locked_last_split;
+            let is_pinned = self.settings.always_show_last_split && index + 1 == run.len();
+            if visible_indices.is_none() && window_len > 0 && !is_pinned {
+                let index = index as isize;
+                let window_start = skip_count + self.scroll_offset;
+                if index < window_start {
+                    self.scroll_offset = clamp_scroll_offset(index - skip_count);
+                } else if index >= window_start + window_len {
+                    self.scroll_offset = clamp_scroll_offset(index + 1 - window_len - skip_count);
+                }
+            }
+        }
+        let skip_count = if visible_indices.is_some() {
+            0
+        } else {
+            max(0, skip_count + self.scroll_offset) as usize
+        };
         let take_count = visual_split_count - locked_last_split as usize;
         let always_show_last_split = self.settings.always_show_last_split;
 
-        let show_final_separator = self.settings.separator_last_split
-            && always_show_last_split
-            && skip_count + take_count + 1 < run.len();
+        let hidden_segments_before_last_split =
+            if always_show_last_split && visible_indices.is_none() {
+                run.len().saturating_sub(skip_count + take_count + 1)
+            } else {
+                0
+            };
+
+        let first_visible_index = match visible_indices {
+            Some(visible_indices) => visible_indices
+                .iter()
+                .copied()
+                .filter(|&i| i < run.len())
+                .min()
+                .unwrap_or_default(),
+            None => skip_count,
+        };
+        let last_split_is_pinned = always_show_last_split
+            && visible_indices.is_none()
+            && skip_count + take_count < run.len();
+
+        let show_final_separator =
+            self.settings.separator_last_split && hidden_segments_before_last_split > 0;
 
         let Settings {
-            show_thin_separators,
+            separator_mode,
             fill_with_blank_space,
             display_two_rows,
+            current_split_indicator,
             ref columns,
             ..
         } = self.settings;
 
-        state.background = self.settings.background;
+        state.background = match self.settings.background {
+            ListGradient::Alternating(..) if self.settings.medal_backgrounds.is_some() => {
+                ListGradient::Same(Gradient::Transparent)
+            }
+            background => background,
+        };
 
         if self.settings.show_column_labels {
             let column_labels = state.column_labels.get_or_insert_with(Default::default);
@@ -333,32 +777,84 @@ impl Component {
             for c in &self.settings.columns {
                 column_labels.push().push_str(&c.name);
             }
+
+            if self.settings.columns.iter().any(|c| c.group.is_some()) {
+                let group_labels = state
+                    .column_group_labels
+                    .get_or_insert_with(Default::default);
+                group_labels.clear();
+                let mut previous_group = None;
+                for (index, column) in self.settings.columns.iter().enumerate() {
+                    let Some(group) = &column.group else {
+                        previous_group = None;
+                        continue;
+                    };
+                    if previous_group == Some(group) {
+                        if let Some(label) = group_labels.last_mut() {
+                            label.column_count += 1;
+                        }
+                    } else {
+                        let label = group_labels.push();
+                        label.name.push_str(group);
+                        label.start_column = index;
+                        label.column_count = 1;
+                    }
+                    previous_group = Some(group);
+                }
+            } else {
+                state.column_group_labels = None;
+            }
         } else {
             state.column_labels = None;
+            state.column_group_labels = None;
         }
 
+        let pb_worst_segment = if self.settings.highlight_pb_worst_segment {
+            pb_worst_segment(timer)
+        } else {
+            None
+        };
+
         state.splits.clear();
+        let mut previous_index = None;
+        let mut gap_count = 0;
         for (i, segment) in run
             .segments()
             .iter()
             .enumerate()
             .skip(skip_count)
-            .filter(|&(i, _)| {
-                i - skip_count < take_count || (always_show_last_split && i + 1 == run.len())
+            .filter(|&(i, _)| match visible_indices {
+                Some(visible_indices) => visible_indices.contains(&i),
+                None => {
+                    i - skip_count < take_count || (always_show_last_split && i + 1 == run.len())
+                }
             })
         {
-            let state = state.splits.push_with(|| SplitState {
-                icon: *ImageId::EMPTY,
-                name: String::new(),
-                columns: ClearVec::new(),
-                is_current_split: false,
-                index: 0,
-            });
+            if self.settings.show_gap_rows
+                && visible_indices.is_some()
+                && previous_index.is_some_and(|previous_index| i > previous_index + 1)
+            {
+                push_blank_split(
+                    &mut state.splits,
+                    usize::MAX - 2 * gap_count,
+                    separator_mode.show_after(None),
+                    BlankReason::Gap,
+                );
+                gap_count += 1;
+            }
+            previous_index = Some(i);
+
+            let state = state.splits.push_with(new_split_state);
 
-            let icon = segment.icon();
-            state.icon = *image_cache.cache(icon.id(), || icon.clone()).id();
+            if self.settings.show_icons {
+                let icon = segment.icon();
+                state.icon = *image_cache.cache(icon.id(), || icon.clone()).id();
+            } else {
+                state.icon = *ImageId::EMPTY;
+            }
 
             state.name.push_str(segment.name());
+            state.resolved_comparison.push_str(comparison);
 
             for column in columns {
                 column::update_state(
@@ -367,6 +863,12 @@ impl Component {
                         semantic_color: Default::default(),
                         visual_color: Color::transparent(),
                         updates_frequently: false,
+                        alignment: ColumnAlignment::End,
+                        min_width: None,
+                        max_width: None,
+                        resolved_comparison: String::new(),
+                        sub_value: None,
+                        is_ghost: false,
                     }),
                     column,
                     timer,
@@ -380,29 +882,99 @@ impl Component {
             }
 
             state.is_current_split = Some(i) == current_split;
+            state.show_separator_after = separator_mode.show_after(Some(segment))
+                || visible_indices.is_some_and(|visible_indices| {
+                    i + 1 < run.len() && !visible_indices.contains(&(i + 1))
+                });
+            state.indicator = if current_split_indicator && state.is_current_split {
+                SplitIndicator::Arrow
+            } else {
+                SplitIndicator::None
+            };
             state.index = i;
+            state.is_pb_worst_segment = Some(i) == pb_worst_segment;
+            state.medal = catch! {
+                current_split.filter(|&current_split| i < current_split)?;
+                let bands = self.settings.medal_bands?;
+                let segment_time = segment_time_of_attempt(run, i, method)?;
+                bands.medal(segment_time, segment.best_segment_time()[method]?)?
+            };
+            state.background = catch! {
+                self.settings.medal_backgrounds?.color(state.medal?)
+            };
+            state.blank_reason = None;
+            if self.settings.show_tooltips {
+                write_tooltip(
+                    &mut state.tooltip,
+                    timer,
+                    &self.settings,
+                    segment,
+                    i,
+                    comparison,
+                    method,
+                );
+            }
         }
 
         if fill_with_blank_space && state.splits.len() < visual_split_count {
             let blank_split_count = visual_split_count - state.splits.len();
             for i in 0..blank_split_count {
-                let state = state.splits.push_with(|| SplitState {
-                    icon: *ImageId::EMPTY,
-                    name: String::new(),
-                    columns: ClearVec::new(),
-                    is_current_split: false,
-                    index: 0,
-                });
-                state.is_current_split = false;
-                state.index = (usize::MAX ^ 1) - 2 * i;
+                push_blank_split(
+                    &mut state.splits,
+                    (usize::MAX ^ 1) - 2 * i,
+                    separator_mode.show_after(None),
+                    BlankReason::Padding,
+                );
             }
         }
 
-        state.has_icons = run.segments().iter().any(|s| !s.icon().is_empty());
-        state.show_thin_separators = show_thin_separators;
+        state.has_icons =
+            self.settings.show_icons && run.segments().iter().any(|s| !s.icon().is_empty());
+        state.show_thin_separators = separator_mode != SeparatorMode::None;
         state.show_final_separator = show_final_separator;
+        state.hidden_segments_before_last_split = hidden_segments_before_last_split;
+        state.first_visible_index = first_visible_index;
+        state.last_split_is_pinned = last_split_is_pinned;
         state.display_two_rows = display_two_rows;
         state.current_split_gradient = self.settings.current_split_gradient;
+        state.splits_ahead = 0;
+        state.splits_behind = 0;
+        for segment in &run.segments()[..current_split.unwrap_or(0)] {
+            if let (Some(split_time), Some(comparison_time)) = (
+                segment.split_time()[method],
+                segment.comparison(comparison)[method],
+            ) {
+                match split_time.cmp(&comparison_time) {
+                    Ordering::Less => state.splits_ahead += 1,
+                    Ordering::Greater => state.splits_behind += 1,
+                    Ordering::Equal => {}
+                }
+            }
+        }
+        state.has_sub_values = self
+            .settings
+            .columns
+            .iter()
+            .any(|column| match &column.kind {
+                ColumnKind::Time(column) => {
+                    column.show_segment_time_below && column.custom_timing_method.is_none()
+                }
+                ColumnKind::Variable(_) => false,
+            });
+        state.row_height_units = if display_two_rows {
+            TWO_ROW_HEIGHT
+        } else {
+            DEFAULT_COMPONENT_HEIGHT
+        };
+        if state.has_sub_values {
+            state.row_height_units += SUB_VALUE_HEIGHT;
+        }
+        state.header_height_units = if state.column_labels.is_some() {
+            DEFAULT_COMPONENT_HEIGHT
+        } else {
+            0.0
+        };
+        state.sticky_header = self.settings.sticky_header;
     }
 
     /// Calculates the component's state based on the timer and layout settings
@@ -441,9 +1013,9 @@ impl Component {
                 Value::UInt(self.settings.split_preview_count as _),
             ),
             Field::new(
-                "Show Thin Separators".into(),
-                "Specifies whether thin separators should be shown between the individual segment rows.".into(),
-                self.settings.show_thin_separators.into(),
+                "Thin Separators".into(),
+                "Specifies between which segment rows thin separators should be shown. They can either be shown between all rows or only after the last segment of each section, where segments whose names start with a dash are considered subsplits of the section.".into(),
+                self.settings.separator_mode.into(),
             ),
             Field::new(
                 "Show Separator Before Last Split".into(),
@@ -495,6 +1067,61 @@ impl Component {
                 "Specifies whether to show the names of the columns at the top of the list.".into(),
                 self.settings.show_column_labels.into(),
             ),
+            Field::new(
+                "Empty Placeholder".into(),
+                "The text to show in a column that has no value to display, such as a dash. If this is empty, nothing is shown.".into(),
+                self.settings.empty_placeholder.clone().into(),
+            ),
+            Field::new(
+                "Show Current Segment Indicator".into(),
+                "Specifies whether to show an arrow next to the name of the current segment, in addition to the gradient shown behind it.".into(),
+                self.settings.current_split_indicator.into(),
+            ),
+            Field::new(
+                "Delta Percentage Decimals".into(),
+                "Specifies the number of decimals to show for columns that show the amount of time you are ahead or behind as a percentage of the comparison's time. At most 3 decimals can be shown.".into(),
+                Value::UInt(self.settings.delta_percent_decimals.into()),
+            ),
+            Field::new(
+                "Highlight Worst Segment".into(),
+                "Specifies whether to mark the segment of the Personal Best that lost the most time compared to its best segment time. This can help with figuring out which segment to practice.".into(),
+                self.settings.highlight_pb_worst_segment.into(),
+            ),
+            Field::new(
+                "Sticky Header".into(),
+                "Specifies whether the column labels and the column group labels stay at the top of the list when the list is scrolled, rather than scrolling out of view with the segments.".into(),
+                self.settings.sticky_header.into(),
+            ),
+            Field::new(
+                "Show Segment Icons".into(),
+                "Specifies whether to show the icons of the segments. If disabled, no space is reserved for the icons, even if the segments have icons.".into(),
+                self.settings.show_icons.into(),
+            ),
+            Field::new(
+                "Skipped Segment Deltas".into(),
+                "Specifies how columns that show the time saved or lost on a segment display it for the segment after a skipped segment. As the skipped segment has no time, the time saved or lost spans both segments. It can either be shown as is, not be shown at all, or be marked with an asterisk.".into(),
+                self.settings.skipped_segment_delta.into(),
+            ),
+            Field::new(
+                "Show Tooltips".into(),
+                "Specifies whether each row provides a tooltip summarizing its segment, which is shown when hovering over it. This is only supported by some renderers.".into(),
+                self.settings.show_tooltips.into(),
+            ),
+            Field::new(
+                "Medal Bands".into(),
+                "Specifies how much slower than its best segment time a completed segment may be to still be awarded a gold, silver or bronze medal. New best segments are always awarded the gold medal. If not specified, no medals are awarded. Thresholds that are out of order are raised to match the previous medal's threshold.".into(),
+                self.settings.medal_bands.into(),
+            ),
+            Field::new(
+                "Medal Backgrounds".into(),
+                "Specifies the colors to tint the backgrounds of the segments with based on the medals they are awarded. If specified, the backgrounds of the segments are no longer alternating and segments that aren't awarded a medal don't have a background. This requires the medal bands to be specified.".into(),
+                self.settings.medal_backgrounds.into(),
+            ),
+            Field::new(
+                "Current Segment Position".into(),
+                "Specifies where the current segment is kept in the window of segments as it scrolls. It can be kept at the top or in the center of the window. Otherwise the window only scrolls when needed to show the number of upcoming segments specified.".into(),
+                self.settings.anchor.into(),
+            ),
             Field::new(
                 "Columns".into(),
                 "The number of columns to show in each row. Each column can be configured to show different information. The columns are defined from right to left.".into(),
@@ -521,7 +1148,6 @@ impl Component {
                     "The name of the column. This is shown at the top of the list if the option to show column labels is enabled.".into(),
                     column.name.clone().into(),
                 ));
-
             match &column.kind {
                 ColumnKind::Variable(column) => {
                     settings.fields.push(Field::new(
@@ -570,8 +1196,53 @@ impl Component {
                         "Specifies the timing method to use for this column. If not specified, the current timing method is used.".into(),
                         column.timing_method.into(),
                     ));
+                    settings.fields.push(Field::new(
+                        "Custom Timing Method".into(),
+                        "The name of a custom timing method, such as a count of in-game frames, to show instead. Custom timing methods need to be provided by the game, for example through an auto splitter. If not specified, the timing method above is used.".into(),
+                        column.custom_timing_method.clone().into(),
+                    ));
+                    settings.fields.push(Field::new(
+                        "Show Segment Time Below".into(),
+                        "Specifies whether to show the segment time in smaller text below the value of this column. Once the value is updated, the segment time of the current attempt is shown. Before that, the segment time of the comparison is shown.".into(),
+                        column.show_segment_time_below.into(),
+                    ));
+                    settings.fields.push(Field::new(
+                        "Show Comparison Ghost".into(),
+                        "Specifies whether the current segment shows the split time of the comparison until the value of this column is updated. The split time is shown more subtly than the regular values.".into(),
+                        column.show_comparison_ghost.into(),
+                    ));
+                    settings.fields.push(Field::new(
+                        "Sign Style".into(),
+                        "Specifies how the signs of the deltas in this column are shown. If not specified, positive and negative deltas show their sign, while deltas of exactly zero don't show any sign.".into(),
+                        column.sign_style.into(),
+                    ));
+                    settings.fields.push(Field::new(
+                        "Accuracy".into(),
+                        "Specifies the accuracy of the times shown in this column. If not specified, the split time, segment time or delta time accuracy of the component is used, depending on the kind of time shown.".into(),
+                        column.accuracy_override.into(),
+                    ));
                 }
             }
+            settings.fields.push(Field::new(
+                "Column Alignment".into(),
+                "Specifies how the values in this column are aligned. If not specified, times are aligned to the end and variables are aligned to the start of the column.".into(),
+                column.alignment.into(),
+            ));
+            settings.fields.push(Field::new(
+                "Column Group".into(),
+                "The group this column belongs to. Adjacent columns in the same group share a label that is shown above their column labels. If not specified, the column doesn't belong to any group.".into(),
+                column.group.clone().into(),
+            ));
+            settings.fields.push(Field::new(
+                "Minimum Width".into(),
+                "The minimum width of the column. If not specified, the column is only as wide as its widest value.".into(),
+                column.min_width.map(f64::from).into(),
+            ));
+            settings.fields.push(Field::new(
+                "Maximum Width".into(),
+                "The maximum width of the column. Values that don't fit are shortened with an ellipsis. If not specified, the column grows to fit its widest value.".into(),
+                column.max_width.map(f64::from).into(),
+            ));
         }
 
         settings
@@ -589,7 +1260,7 @@ impl Component {
             0 => self.settings.background = value.into(),
             1 => self.settings.visual_split_count = value.into_uint().unwrap() as _,
             2 => self.settings.split_preview_count = value.into_uint().unwrap() as _,
-            3 => self.settings.show_thin_separators = value.into(),
+            3 => self.settings.separator_mode = value.into(),
             4 => self.settings.separator_last_split = value.into(),
             5 => self.settings.always_show_last_split = value.into(),
             6 => self.settings.fill_with_blank_space = value.into(),
@@ -600,7 +1271,27 @@ impl Component {
             11 => self.settings.delta_time_accuracy = value.into(),
             12 => self.settings.delta_drop_decimals = value.into(),
             13 => self.settings.show_column_labels = value.into(),
-            14 => {
+            14 => self.settings.empty_placeholder = value.into(),
+            15 => self.settings.current_split_indicator = value.into(),
+            16 => {
+                self.settings.delta_percent_decimals = value
+                    .into_uint()
+                    .unwrap()
+                    .min(MAX_DELTA_PERCENT_DECIMALS.into())
+                    as _
+            }
+            17 => self.settings.highlight_pb_worst_segment = value.into(),
+            18 => self.settings.sticky_header = value.into(),
+            19 => self.settings.show_icons = value.into(),
+            20 => self.settings.skipped_segment_delta = value.into(),
+            21 => self.settings.show_tooltips = value.into(),
+            22 => {
+                self.settings.medal_bands = Option::<MedalBands>::from(value)
+                    .map(|bands| MedalBands::new(bands.gold, bands.silver, bands.bronze))
+            }
+            23 => self.settings.medal_backgrounds = value.into(),
+            24 => self.settings.anchor = value.into(),
+            25 => {
                 let new_len = value.into_uint().unwrap() as usize;
                 self.settings.columns.resize(new_len, Default::default());
             }
@@ -633,22 +1324,169 @@ impl Component {
                             index -= 1;
                         }
                         ColumnKind::Time(column) => {
-                            if index < 5 {
+                            if index < 10 {
                                 match index {
                                     0 => column.start_with = value.into(),
                                     1 => column.update_with = value.into(),
                                     2 => column.update_trigger = value.into(),
                                     3 => column.comparison_override = value.into(),
-                                    _ => column.timing_method = value.into(),
+                                    4 => column.timing_method = value.into(),
+                                    5 => column.custom_timing_method = value.into(),
+                                    6 => column.show_segment_time_below = value.into(),
+                                    7 => column.show_comparison_ghost = value.into(),
+                                    8 => column.sign_style = value.into(),
+                                    _ => column.accuracy_override = value.into(),
                                 }
                                 return;
                             }
-                            index -= 5;
+                            index -= 10;
+                        }
+                    }
+                    if index < 4 {
+                        match index {
+                            0 => column.alignment = value.into(),
+                            1 => column.group = value.into(),
+                            2 => {
+                                column.min_width =
+                                    Option::<f64>::from(value).map(|width| width as f32)
+                            }
+                            _ => {
+                                column.max_width =
+                                    Option::<f64>::from(value).map(|width| width as f32)
+                            }
                         }
+                        return;
                     }
+                    index -= 4;
                 }
                 panic!("Unsupported Setting Index")
             }
         }
     }
 }
+
+/// Finds the segment of the Personal Best that lost the most time compared to
+/// its best segment time.
+fn pb_worst_segment(timer: &Snapshot<'_>) -> Option<usize> {
+    let mut worst = None;
+    for index in 0..timer.run().len() {
+        let (time_save, _) =
+            possible_time_save::calculate(timer, index, comparison::personal_best::NAME, false);
+        if let Some(time_save) = time_save {
+            if time_save > TimeSpan::zero() && worst.is_none_or(|(_, worst)| time_save > worst) {
+                worst = Some((index, time_save));
+            }
+        }
+    }
+    worst.map(|(index, _)| index)
+}
+
+/// Calculates the segment time of the segment in the active attempt. This is
+/// only known if both the segment and the segment before it have a split time.
+fn segment_time_of_attempt(run: &Run, index: usize, method: TimingMethod) -> Option<TimeSpan> {
+    let split_time = run.segment(index).split_time()[method]?;
+    let previous_split_time = match index.checked_sub(1) {
+        Some(previous) => run.segment(previous).split_time()[method]?,
+        None => TimeSpan::zero(),
+    };
+    Some(split_time - previous_split_time)
+}
+
+fn new_split_state() -> SplitState {
+    SplitState {
+        icon: *ImageId::EMPTY,
+        name: String::new(),
+        columns: ClearVec::new(),
+        is_current_split: false,
+        show_separator_after: false,
+        indicator: SplitIndicator::None,
+        index: 0,
+        is_pb_worst_segment: false,
+        medal: None,
+        background: None,
+        blank_reason: None,
+        resolved_comparison: String::new(),
+        tooltip: String::new(),
+    }
+}
+
+/// Pushes a row that doesn't show any segment. The index needs to be unique
+/// across all the rows.
+fn push_blank_split(
+    splits: &mut ClearVec<SplitState>,
+    index: usize,
+    show_separator_after: bool,
+    blank_reason: BlankReason,
+) {
+    let state = splits.push_with(new_split_state);
+    state.is_current_split = false;
+    state.show_separator_after = show_separator_after;
+    state.indicator = SplitIndicator::None;
+    state.index = index;
+    state.is_pb_worst_segment = false;
+    state.medal = None;
+    state.background = None;
+    state.blank_reason = Some(blank_reason);
+}
+
+/// Writes the summary of a segment that is shown as the tooltip of its row.
+fn write_tooltip(
+    tooltip: &mut String,
+    timer: &Snapshot<'_>,
+    settings: &Settings,
+    segment: &Segment,
+    segment_index: usize,
+    comparison: &str,
+    method: TimingMethod,
+) {
+    tooltip.push_str(segment.name());
+
+    if let Some(split_time) = segment.split_time()[method] {
+        let _ = write!(
+            tooltip,
+            "\nSplit Time: {}",
+            Regular::with_accuracy(settings.split_time_accuracy).format(split_time),
+        );
+        if let Some(comparison_time) = segment.comparison(comparison)[method] {
+            let _ = write!(
+                tooltip,
+                "\nDelta: {}",
+                Delta::custom(settings.delta_drop_decimals, settings.delta_time_accuracy)
+                    .format(split_time - comparison_time),
+            );
+        }
+        if analysis::check_best_segment(timer, segment_index, method) {
+            tooltip.push_str("\nBest Segment");
+        }
+    }
+}
+
+fn serialize_time_span<S: Serializer>(
+    time_span: &TimeSpan,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let (secs, nanos) = time_span.to_seconds_and_subsec_nanoseconds();
+    let sign = if secs < 0 || nanos < 0 { "-" } else { "" };
+    serializer.collect_str(&format_args!(
+        "{sign}{}.{:09}",
+        secs.unsigned_abs(),
+        nanos.unsigned_abs(),
+    ))
+}
+
+fn deserialize_separator_mode<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<SeparatorMode, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum SeparatorModeOrBool {
+        SeparatorMode(SeparatorMode),
+        Bool(bool),
+    }
+
+    Ok(match SeparatorModeOrBool::deserialize(deserializer)? {
+        SeparatorModeOrBool::SeparatorMode(mode) => mode,
+        SeparatorModeOrBool::Bool(true) => SeparatorMode::All,
+        SeparatorModeOrBool::Bool(false) => SeparatorMode::None,
+    })
+}
@@ -1,9 +1,9 @@
 use super::{
     ColumnSettings, ColumnStartWith, ColumnUpdateTrigger, ColumnUpdateWith, Component, Settings,
-    State,
+    SkippedSegmentDelta, State,
 };
 use crate::{
-    component::splits::{ColumnKind, TimeColumn},
+    component::splits::{ColumnKind, SignStyle, TimeColumn},
     settings::{
         ImageCache,
         SemanticColor::{
@@ -11,8 +11,11 @@ use crate::{
             BestSegment as Best, Default as Text,
         },
     },
-    util::tests_helper::{make_progress_run_with_splits_opt, run_with_splits_opt, start_run},
-    Run, Segment, TimeSpan, Timer,
+    timing::formatter::Accuracy,
+    util::tests_helper::{
+        make_progress_run_with_splits_opt, run_with_splits, run_with_splits_opt, start_run,
+    },
+    Run, Segment, Time, TimeSpan, Timer, TimingMethod,
 };
 
 type Values = &'static [([&'static str; 6], [SemanticColor; 6])];
@@ -198,19 +201,19 @@ fn column_empty_segment_time() {
             ),
             (
                 ["8.50", "—", "1.50", "", "", ""],
-                [Text, Text, Text, Text, Text, Text],
+                [Text, Text, Best, Text, Text, Text],
             ),
             (
                 ["8.50", "—", "1.50", "7.50", "", ""],
-                [Text, Text, Text, Text, Text, Text],
+                [Text, Text, Best, Best, Text, Text],
             ),
             (
                 ["8.50", "—", "1.50", "7.50", "—", ""],
-                [Text, Text, Text, Text, Text, Text],
+                [Text, Text, Best, Best, Text, Text],
             ),
             (
                 ["8.50", "—", "1.50", "7.50", "—", "7.50"],
-                [Text, Text, Text, Text, Text, Text],
+                [Text, Text, Best, Best, Text, Text],
             ),
         ],
     )
@@ -274,19 +277,19 @@ fn column_comparison_segment_time_segment_time() {
             ),
             (
                 ["8.50", "—", "1.50", "10.00", "5.00", "1:05.00"],
-                [Text, Text, Text, Text, Text, Text],
+                [Text, Text, Best, Text, Text, Text],
             ),
             (
                 ["8.50", "—", "1.50", "7.50", "5.00", "1:05.00"],
-                [Text, Text, Text, Text, Text, Text],
+                [Text, Text, Best, Best, Text, Text],
             ),
             (
                 ["8.50", "—", "1.50", "7.50", "—", "1:05.00"],
-                [Text, Text, Text, Text, Text, Text],
+                [Text, Text, Best, Best, Text, Text],
             ),
             (
                 ["8.50", "—", "1.50", "7.50", "—", "7.50"],
-                [Text, Text, Text, Text, Text, Text],
+                [Text, Text, Best, Best, Text, Text],
             ),
         ],
     )
@@ -1348,6 +1351,574 @@ fn delta_or_split_time() {
     timer.reset(true).unwrap();
 }
 
+#[test]
+fn delta_percent() {
+    let mut timer = timer();
+    run_with_splits_opt(
+        &mut timer,
+        &[
+            Some(10.0),
+            Some(20.0),
+            Some(40.0),
+            None,
+            Some(50.0),
+            Some(60.0),
+        ],
+    );
+
+    let layout_settings = Default::default();
+    let mut component = Component::with_settings(Settings {
+        columns: vec![ColumnSettings {
+            kind: ColumnKind::Time(TimeColumn {
+                start_with: ColumnStartWith::Empty,
+                update_with: ColumnUpdateWith::DeltaPercent,
+                update_trigger: ColumnUpdateTrigger::OnStartingSegment,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }],
+        fill_with_blank_space: false,
+        ..Default::default()
+    });
+
+    let mut image_cache = ImageCache::new();
+
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(
+        &mut timer,
+        &[Some(11.0), Some(19.0), Some(42.0), Some(45.0)],
+    );
+    timer.set_game_time(TimeSpan::from_seconds(47.5)).unwrap();
+
+    let values = |state: &State| {
+        state
+            .splits
+            .iter()
+            .map(|s| s.columns[0].value.clone())
+            .collect::<Vec<_>>()
+    };
+
+    // The fourth segment has no comparison time, so there's no percentage.
+    // The fifth segment is the current one and shows the live delta of −2.5
+    // seconds compared to 50 seconds.
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    assert_eq!(
+        values(&state),
+        ["+10.0%", "−5.0%", "+5.0%", "", "−5.0%", ""],
+    );
+    assert_eq!(state.splits[0].columns[0].semantic_color, BehindLosing);
+    // The segment of 8 seconds is faster than the best segment of 10 seconds.
+    assert_eq!(state.splits[1].columns[0].semantic_color, Best);
+
+    component.settings_mut().delta_percent_decimals = 2;
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    assert_eq!(
+        values(&state),
+        ["+10.00%", "−5.00%", "+5.00%", "", "−5.00%", ""],
+    );
+
+    component.settings_mut().delta_percent_decimals = u8::MAX;
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    assert_eq!(
+        values(&state),
+        ["+10.000%", "−5.000%", "+5.000%", "", "−5.000%", ""],
+    );
+}
+
+#[test]
+fn delta_percent_of_zero_comparison_is_empty() {
+    let mut timer = timer();
+    run_with_splits(&mut timer, &[0.0, 10.0, 15.0, 20.0, 25.0, 30.0]);
+
+    let layout_settings = Default::default();
+    let mut component = Component::with_settings(Settings {
+        columns: vec![ColumnSettings {
+            kind: ColumnKind::Time(TimeColumn {
+                start_with: ColumnStartWith::Empty,
+                update_with: ColumnUpdateWith::DeltaPercent,
+                update_trigger: ColumnUpdateTrigger::OnEndingSegment,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }],
+        fill_with_blank_space: false,
+        ..Default::default()
+    });
+    let mut image_cache = ImageCache::new();
+
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(1.0), Some(11.0)]);
+
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    assert_eq!(state.splits[0].columns[0].value, "");
+    assert_eq!(state.splits[1].columns[0].value, "+10.0%");
+}
+
+#[test]
+fn skipped_segment_delta() {
+    let mut timer = timer();
+    run_with_splits_opt(
+        &mut timer,
+        &[
+            Some(10.0),
+            Some(20.0),
+            Some(30.0),
+            Some(40.0),
+            Some(50.0),
+            Some(60.0),
+        ],
+    );
+
+    let layout_settings = Default::default();
+    let mut component = Component::with_settings(Settings {
+        columns: vec![ColumnSettings {
+            kind: ColumnKind::Time(TimeColumn {
+                start_with: ColumnStartWith::Empty,
+                update_with: ColumnUpdateWith::SegmentDelta,
+                update_trigger: ColumnUpdateTrigger::OnEndingSegment,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }],
+        fill_with_blank_space: false,
+        ..Default::default()
+    });
+
+    let mut image_cache = ImageCache::new();
+
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(11.0), None, Some(29.0)]);
+
+    let values = |state: &State| {
+        state
+            .splits
+            .iter()
+            .map(|s| s.columns[0].value.clone())
+            .collect::<Vec<_>>()
+    };
+
+    // The third segment's delta spans both the skipped second segment and
+    // itself: 18 seconds compared to 20 seconds.
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    assert_eq!(values(&state), ["+1.0", "", "−2.0", "", "", ""]);
+    assert_ne!(state.splits[2].columns[0].semantic_color, Text);
+
+    component.settings_mut().skipped_segment_delta = SkippedSegmentDelta::Empty;
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    assert_eq!(values(&state), ["+1.0", "", "", "", "", ""]);
+    assert_eq!(state.splits[2].columns[0].semantic_color, Text);
+
+    component.settings_mut().skipped_segment_delta = SkippedSegmentDelta::Annotated;
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    assert_eq!(values(&state), ["+1.0", "", "−2.0*", "", "", ""]);
+    assert_ne!(state.splits[2].columns[0].semantic_color, Text);
+}
+
+#[test]
+fn segment_time_below() {
+    let mut timer = timer();
+    run_with_splits_opt(
+        &mut timer,
+        &[
+            Some(5.0),
+            Some(10.0),
+            Some(15.0),
+            Some(20.0),
+            Some(25.0),
+            Some(30.0),
+        ],
+    );
+
+    let layout_settings = Default::default();
+    let mut component = Component::with_settings(Settings {
+        columns: vec![ColumnSettings {
+            kind: ColumnKind::Time(TimeColumn {
+                start_with: ColumnStartWith::ComparisonTime,
+                update_with: ColumnUpdateWith::SplitTime,
+                update_trigger: ColumnUpdateTrigger::OnEndingSegment,
+                show_segment_time_below: true,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }],
+        fill_with_blank_space: false,
+        ..Default::default()
+    });
+
+    let mut image_cache = ImageCache::new();
+
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(6.0)]);
+
+    // The first segment shows the segment time of the current attempt, while
+    // the other segments show the comparison's segment times.
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    let sub_values = state
+        .splits
+        .iter()
+        .map(|s| s.columns[0].sub_value.as_deref())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        sub_values,
+        [
+            Some("6.00"),
+            Some("5.00"),
+            Some("5.00"),
+            Some("5.00"),
+            Some("5.00"),
+            Some("5.00")
+        ],
+    );
+    assert!(state.has_sub_values);
+
+    if let ColumnKind::Time(column) = &mut component.settings_mut().columns[0].kind {
+        column.show_segment_time_below = false;
+    }
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    assert!(
+        state
+            .splits
+            .iter()
+            .all(|s| s.columns[0].sub_value.is_none())
+    );
+    assert!(!state.has_sub_values);
+}
+
+#[test]
+fn delta_references_split_or_segment_time() {
+    let mut timer = timer();
+    run_with_splits_opt(
+        &mut timer,
+        &[
+            Some(5.0),
+            Some(10.0),
+            Some(15.0),
+            Some(20.0),
+            Some(25.0),
+            Some(30.0),
+        ],
+    );
+
+    let column = |update_with| ColumnSettings {
+        kind: ColumnKind::Time(TimeColumn {
+            start_with: ColumnStartWith::Empty,
+            update_with,
+            update_trigger: ColumnUpdateTrigger::OnEndingSegment,
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let layout_settings = Default::default();
+    let mut component = Component::with_settings(Settings {
+        columns: vec![
+            column(ColumnUpdateWith::Delta),
+            column(ColumnUpdateWith::SegmentDelta),
+        ],
+        fill_with_blank_space: false,
+        ..Default::default()
+    });
+    let mut image_cache = ImageCache::new();
+
+    // The second split is still behind overall, but its segment was faster
+    // than the comparison's.
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(6.0), Some(10.5)]);
+
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    let values = |column: usize| {
+        state.splits[..2]
+            .iter()
+            .map(|s| s.columns[column].value.as_str())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(values(0), ["+1.0", "+0.5"]);
+    assert_eq!(values(1), ["+1.0", "−0.5"]);
+}
+
+#[test]
+fn comparison_ghost_on_the_current_split() {
+    let mut timer = timer();
+    run_with_splits_opt(
+        &mut timer,
+        &[
+            Some(5.0),
+            Some(10.0),
+            Some(15.0),
+            Some(20.0),
+            Some(25.0),
+            Some(30.0),
+        ],
+    );
+
+    let layout_settings = Default::default();
+    let mut component = Component::with_settings(Settings {
+        columns: vec![
+            ColumnSettings {
+                kind: ColumnKind::Time(TimeColumn {
+                    start_with: ColumnStartWith::Empty,
+                    update_with: ColumnUpdateWith::SplitTime,
+                    update_trigger: ColumnUpdateTrigger::Contextual,
+                    show_comparison_ghost: true,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            ColumnSettings {
+                kind: ColumnKind::Time(TimeColumn {
+                    start_with: ColumnStartWith::ComparisonTime,
+                    update_with: ColumnUpdateWith::DontUpdate,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+        ],
+        fill_with_blank_space: false,
+        ..Default::default()
+    });
+    let mut image_cache = ImageCache::new();
+
+    // Mid-segment, before the current segment is slower than its best segment.
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(6.0)]);
+    timer.set_game_time(TimeSpan::from_seconds(7.0)).unwrap();
+
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    let [ghost, comparison] = &state.splits[1].columns[..] else {
+        panic!("Expected two columns");
+    };
+    assert!(ghost.is_ghost);
+    assert!(!comparison.is_ghost);
+    assert!(!ghost.value.is_empty());
+    assert_eq!(ghost.value, comparison.value);
+    assert_eq!(ghost.semantic_color, SemanticColor::Default);
+
+    // Only the current split shows the ghost.
+    assert!(!state.splits[0].columns[0].is_ghost);
+    assert!(!state.splits[2].columns[0].is_ghost);
+    assert!(state.splits[2].columns[0].value.is_empty());
+
+    // Once the segment is slower than its best segment, the live value is
+    // shown instead.
+    timer.set_game_time(TimeSpan::from_seconds(16.0)).unwrap();
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    let [live, comparison] = &state.splits[1].columns[..] else {
+        panic!("Expected two columns");
+    };
+    assert!(!live.is_ghost);
+    assert_ne!(live.value, comparison.value);
+}
+
+#[test]
+fn sign_style_of_deltas() {
+    let mut timer = timer();
+    run_with_splits_opt(
+        &mut timer,
+        &[
+            Some(5.0),
+            Some(10.0),
+            Some(15.0),
+            Some(20.0),
+            Some(25.0),
+            Some(30.0),
+        ],
+    );
+
+    let column = |sign_style| ColumnSettings {
+        kind: ColumnKind::Time(TimeColumn {
+            start_with: ColumnStartWith::Empty,
+            update_with: ColumnUpdateWith::Delta,
+            update_trigger: ColumnUpdateTrigger::OnEndingSegment,
+            sign_style,
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let layout_settings = Default::default();
+    let mut component = Component::with_settings(Settings {
+        columns: vec![
+            column(None),
+            column(Some(SignStyle::Always)),
+            column(Some(SignStyle::NegativeOnly)),
+            column(Some(SignStyle::None)),
+        ],
+        fill_with_blank_space: false,
+        ..Default::default()
+    });
+    let mut image_cache = ImageCache::new();
+
+    // Behind, ahead and exactly even.
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(6.0), Some(9.0), Some(15.0)]);
+
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    let values = |column: usize| {
+        state.splits[..3]
+            .iter()
+            .map(|s| s.columns[column].value.as_str())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(values(0), ["+1.0", "−1.0", "0.0"]);
+    assert_eq!(values(1), ["+1.0", "−1.0", "±0.0"]);
+    assert_eq!(values(2), ["1.0", "−1.0", "0.0"]);
+    assert_eq!(values(3), ["1.0", "1.0", "0.0"]);
+}
+
+#[test]
+fn sign_style_of_deltas_that_round_to_zero() {
+    let mut timer = timer();
+    run_with_splits(&mut timer, &[5.0, 10.0, 15.0, 20.0, 25.0, 30.0]);
+
+    let column = |sign_style| ColumnSettings {
+        kind: ColumnKind::Time(TimeColumn {
+            start_with: ColumnStartWith::Empty,
+            update_with: ColumnUpdateWith::Delta,
+            update_trigger: ColumnUpdateTrigger::OnEndingSegment,
+            sign_style,
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let layout_settings = Default::default();
+    let mut component = Component::with_settings(Settings {
+        columns: vec![
+            column(Some(SignStyle::Always)),
+            column(Some(SignStyle::NegativeOnly)),
+            column(Some(SignStyle::None)),
+        ],
+        fill_with_blank_space: false,
+        ..Default::default()
+    });
+    let mut image_cache = ImageCache::new();
+
+    // Slightly behind and slightly ahead, both of which are displayed as zero.
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(5.01), Some(9.99)]);
+
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    let values = |column: usize| {
+        state.splits[..2]
+            .iter()
+            .map(|s| s.columns[column].value.as_str())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(values(0), ["±0.0", "±0.0"]);
+    assert_eq!(values(1), ["0.0", "0.0"]);
+    assert_eq!(values(2), ["0.0", "0.0"]);
+}
+
+#[test]
+fn accuracy_override() {
+    let mut timer = timer();
+    run_with_splits(&mut timer, &[5.0, 10.0, 15.0, 20.0, 25.0, 30.0]);
+
+    let column = |update_with, accuracy_override| ColumnSettings {
+        kind: ColumnKind::Time(TimeColumn {
+            start_with: ColumnStartWith::Empty,
+            update_with,
+            update_trigger: ColumnUpdateTrigger::OnEndingSegment,
+            accuracy_override,
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let layout_settings = Default::default();
+    let mut component = Component::with_settings(Settings {
+        columns: vec![
+            column(ColumnUpdateWith::SplitTime, None),
+            column(ColumnUpdateWith::SplitTime, Some(Accuracy::Hundredths)),
+            column(ColumnUpdateWith::Delta, None),
+            column(ColumnUpdateWith::Delta, Some(Accuracy::Hundredths)),
+        ],
+        fill_with_blank_space: false,
+        ..Default::default()
+    });
+    let mut image_cache = ImageCache::new();
+
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(6.5)]);
+
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    let values = state.splits[0]
+        .columns
+        .iter()
+        .map(|c| c.value.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(values, ["0:06", "0:06.50", "+1.5", "+1.50"]);
+}
+
+#[test]
+fn segment_time_of_a_gold_respects_the_timing_method() {
+    let mut run = run();
+    *run.segment_mut(0).best_segment_time_mut() = Time::new()
+        .with_real_time(Some(TimeSpan::zero()))
+        .with_game_time(Some(TimeSpan::from_seconds(10.0)));
+    let mut timer = Timer::new(run).unwrap();
+
+    let column = |timing_method| ColumnSettings {
+        kind: ColumnKind::Time(TimeColumn {
+            start_with: ColumnStartWith::Empty,
+            update_with: ColumnUpdateWith::SegmentTime,
+            timing_method,
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let layout_settings = Default::default();
+    let mut component = Component::with_settings(Settings {
+        columns: vec![column(None), column(Some(TimingMethod::RealTime))],
+        ..Default::default()
+    });
+    let mut image_cache = ImageCache::new();
+
+    start_run(&mut timer);
+    timer.set_game_time(TimeSpan::from_seconds(5.0)).unwrap();
+
+    // The segment isn't completed yet.
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    assert_eq!(state.splits[0].columns[0].semantic_color, Text);
+
+    timer.split().unwrap();
+
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    assert_eq!(state.splits[0].columns[0].value, "5.00");
+    assert_eq!(state.splits[0].columns[0].semantic_color, Best);
+    assert_eq!(state.splits[0].columns[1].semantic_color, Text);
+}
+
+#[test]
+fn possible_time_save_respects_the_timing_method() {
+    let mut run = run();
+    let segment = run.segment_mut(0);
+    *segment.best_segment_time_mut() = Time::new()
+        .with_real_time(Some(TimeSpan::from_seconds(4.0)))
+        .with_game_time(Some(TimeSpan::from_seconds(3.0)));
+    *segment.personal_best_split_time_mut() = Time::new()
+        .with_real_time(Some(TimeSpan::from_seconds(5.0)))
+        .with_game_time(Some(TimeSpan::from_seconds(5.0)));
+    let mut timer = Timer::new(run).unwrap();
+    timer.set_current_timing_method(TimingMethod::GameTime);
+
+    let column = |timing_method| ColumnSettings {
+        kind: ColumnKind::Time(TimeColumn {
+            start_with: ColumnStartWith::PossibleTimeSave,
+            update_with: ColumnUpdateWith::DontUpdate,
+            timing_method,
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let layout_settings = Default::default();
+    let mut component = Component::with_settings(Settings {
+        columns: vec![column(None), column(Some(TimingMethod::RealTime))],
+        ..Default::default()
+    });
+    let mut image_cache = ImageCache::new();
+
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    assert_eq!(state.splits[0].columns[0].value, "2.00");
+    assert_eq!(state.splits[0].columns[1].value, "1.00");
+}
+
 fn check_column_color(state: &State, split_index: usize, expected_color: SemanticColor) {
     assert_eq!(
         state.splits[split_index].columns[0].semantic_color,
@@ -1,10 +1,16 @@
 use super::{
-    ColumnSettings, ColumnStartWith, ColumnUpdateTrigger, ColumnUpdateWith, Component, Settings,
-    State,
+    BlankReason, ColumnAlignment, ColumnSettings, ColumnStartWith, ColumnUpdateTrigger,
+    ColumnUpdateWith, Component, CurrentSplitAnchor, MedalBands, MedalColors, MedalTier,
+    SeparatorMode, Settings, SplitIndicator, State, SETTINGS_BEFORE_COLUMNS,
 };
 use crate::{
-    component::splits::{ColumnKind, TimeColumn},
-    settings::ImageCache,
+    comparison,
+    component::splits::{ColumnKind, TimeColumn, VariableColumn},
+    event::Error,
+    settings::{ImageCache, Value},
+    util::tests_helper::{
+        create_timer, make_progress_run_with_splits_opt, run_with_splits, start_run,
+    },
     Run, Segment, TimeSpan, Timer, TimingMethod,
 };
 
@@ -118,6 +124,134 @@ fn negative_segment_times() {
     assert_eq!(state.splits[0].columns[0].value, "−1.00");
 }
 
+#[test]
+fn custom_timing_method_columns() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("A"));
+    run.push_segment(Segment::new("B"));
+    run.push_segment(Segment::new("C"));
+    let mut timer = Timer::new(run).unwrap();
+    let layout_settings = Default::default();
+    let mut image_cache = ImageCache::new();
+
+    let column = |update_with| ColumnSettings {
+        kind: ColumnKind::Time(TimeColumn {
+            update_with,
+            custom_timing_method: Some(String::from("Frames")),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let mut component = Component::with_settings(Settings {
+        columns: vec![
+            column(ColumnUpdateWith::SplitTime),
+            column(ColumnUpdateWith::SegmentTime),
+        ],
+        ..Default::default()
+    });
+
+    timer.register_custom_timing_method("Frames");
+    timer.start().unwrap();
+    assert_eq!(
+        timer.set_custom_time("Other", TimeSpan::from_seconds(1.0)),
+        Err(Error::CustomTimingMethodDoesntExist),
+    );
+
+    timer
+        .set_custom_time("Frames", TimeSpan::from_seconds(60.0))
+        .unwrap();
+    timer.split().unwrap();
+    timer
+        .set_custom_time("Frames", TimeSpan::from_seconds(150.0))
+        .unwrap();
+    timer.split().unwrap();
+    assert_eq!(
+        timer.custom_time("Frames"),
+        Some(TimeSpan::from_seconds(150.0)),
+    );
+
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    assert_eq!(state.splits[0].columns[0].value, "1:00");
+    assert_eq!(state.splits[0].columns[1].value, "1:00.00");
+    assert_eq!(state.splits[1].columns[0].value, "2:30");
+    assert_eq!(state.splits[1].columns[1].value, "1:30.00");
+    assert_eq!(state.splits[2].columns[0].value, "");
+    assert_eq!(state.splits[2].columns[1].value, "");
+
+    timer.reset(false).unwrap();
+    assert_eq!(timer.custom_time("Frames"), None);
+}
+
+#[test]
+fn separators_only_at_section_boundaries() {
+    let mut run = Run::new();
+    for name in ["-A1", "-A2", "{A}A3", "-B1", "B2", "C"] {
+        run.push_segment(Segment::new(name));
+    }
+    let timer = Timer::new(run).unwrap();
+    let layout_settings = Default::default();
+    let mut image_cache = ImageCache::new();
+
+    let mut component = Component::with_settings(Settings {
+        separator_mode: SeparatorMode::SectionBoundaries,
+        fill_with_blank_space: false,
+        ..Default::default()
+    });
+
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    let separators: Vec<_> = state
+        .splits
+        .iter()
+        .map(|s| s.show_separator_after)
+        .collect();
+    assert_eq!(separators, [false, false, true, false, true, true]);
+
+    component.settings_mut().separator_mode = SeparatorMode::All;
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    assert!(state.splits.iter().all(|s| s.show_separator_after));
+
+    component.settings_mut().separator_mode = SeparatorMode::None;
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    assert!(state.splits.iter().all(|s| !s.show_separator_after));
+}
+
+#[test]
+fn only_the_current_split_updates_frequently() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("A"));
+    run.push_segment(Segment::new("B"));
+    run.push_segment(Segment::new("C"));
+    let mut timer = Timer::new(run).unwrap();
+    let layout_settings = Default::default();
+    let mut image_cache = ImageCache::new();
+
+    let mut component = Component::with_settings(Settings {
+        columns: vec![ColumnSettings {
+            kind: ColumnKind::Time(TimeColumn {
+                start_with: ColumnStartWith::ComparisonTime,
+                update_with: ColumnUpdateWith::SplitTime,
+                update_trigger: ColumnUpdateTrigger::OnStartingSegment,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }],
+        fill_with_blank_space: false,
+        ..Default::default()
+    });
+
+    timer.start().unwrap();
+    timer.split().unwrap();
+
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    let updates_frequently: Vec<_> = state
+        .splits
+        .iter()
+        .map(|s| s.columns[0].updates_frequently)
+        .collect();
+    assert_eq!(updates_frequently, [false, true, false]);
+}
+
 #[test]
 fn unique_split_indices() {
     let mut run = Run::new();
@@ -146,3 +280,911 @@ fn unique_split_indices() {
 
     assert!(indices.windows(2).all(|pair| pair[0] != pair[1]));
 }
+
+#[test]
+fn two_rows_report_taller_row_height() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("A"));
+    let timer = Timer::new(run).unwrap();
+    let layout_settings = Default::default();
+    let mut image_cache = ImageCache::new();
+
+    let mut component = Component::new();
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    let single_row_height = state.row_height_units;
+    assert_eq!(single_row_height, 1.0);
+    assert_eq!(state.header_height_units, 0.0);
+
+    component.settings_mut().display_two_rows = true;
+    component.settings_mut().show_column_labels = true;
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    assert!(state.row_height_units > single_row_height);
+    assert!(state.row_height_units < 2.0 * single_row_height);
+    assert_eq!(state.header_height_units, single_row_height);
+}
+
+#[test]
+fn reports_comparison_changes() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("A"));
+    let mut timer = Timer::new(run).unwrap();
+    let layout_settings = Default::default();
+    let mut image_cache = ImageCache::new();
+
+    let mut component = Component::new();
+    let mut state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    assert!(!state.comparison_changed);
+
+    timer
+        .set_current_comparison(comparison::best_segments::NAME)
+        .unwrap();
+    component.update_state(
+        &mut state,
+        &mut image_cache,
+        &timer.snapshot(),
+        &layout_settings,
+    );
+    assert!(state.comparison_changed);
+
+    component.update_state(
+        &mut state,
+        &mut image_cache,
+        &timer.snapshot(),
+        &layout_settings,
+    );
+    assert!(!state.comparison_changed);
+}
+
+#[test]
+fn column_alignment_depends_on_kind() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("A"));
+    let timer = Timer::new(run).unwrap();
+    let layout_settings = Default::default();
+    let mut image_cache = ImageCache::new();
+
+    let mut component = Component::with_settings(Settings {
+        columns: vec![
+            ColumnSettings::default(),
+            ColumnSettings {
+                kind: ColumnKind::Variable(VariableColumn::default()),
+                ..Default::default()
+            },
+        ],
+        ..Default::default()
+    });
+
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    assert_eq!(state.splits[0].columns[0].alignment, ColumnAlignment::End);
+    assert_eq!(state.splits[0].columns[1].alignment, ColumnAlignment::Start);
+
+    component.settings_mut().columns[0].alignment = Some(ColumnAlignment::Center);
+    component.settings_mut().columns[1].alignment = Some(ColumnAlignment::End);
+
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    assert_eq!(state.splits[0].columns[0].alignment, ColumnAlignment::Center);
+    assert_eq!(state.splits[0].columns[1].alignment, ColumnAlignment::End);
+}
+
+#[test]
+fn columns_report_the_resolved_comparison() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("A"));
+    let mut timer = Timer::new(run).unwrap();
+    let layout_settings = Default::default();
+    let mut image_cache = ImageCache::new();
+
+    let mut component = Component::with_settings(Settings {
+        columns: vec![
+            ColumnSettings::default(),
+            ColumnSettings {
+                kind: ColumnKind::Time(TimeColumn {
+                    comparison_override: Some(comparison::best_segments::NAME.into()),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            ColumnSettings {
+                kind: ColumnKind::Variable(VariableColumn::default()),
+                ..Default::default()
+            },
+        ],
+        ..Default::default()
+    });
+
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    let columns = &state.splits[0].columns;
+    assert_eq!(
+        columns[0].resolved_comparison,
+        comparison::personal_best::NAME
+    );
+    assert_eq!(
+        columns[1].resolved_comparison,
+        comparison::best_segments::NAME
+    );
+    assert_eq!(columns[2].resolved_comparison, "");
+
+    timer
+        .set_current_comparison(comparison::average_segments::NAME)
+        .unwrap();
+
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    let columns = &state.splits[0].columns;
+    assert_eq!(
+        columns[0].resolved_comparison,
+        comparison::average_segments::NAME
+    );
+    assert_eq!(
+        columns[1].resolved_comparison,
+        comparison::best_segments::NAME
+    );
+    assert_eq!(columns[2].resolved_comparison, "");
+}
+
+#[test]
+fn rows_report_the_current_comparison() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("A"));
+    run.push_segment(Segment::new("B"));
+    run.push_segment(Segment::new("C"));
+    let mut timer = Timer::new(run).unwrap();
+    let layout_settings = Default::default();
+    let mut image_cache = ImageCache::new();
+
+    let mut component = Component::with_settings(Settings {
+        visual_split_count: 4,
+        columns: vec![ColumnSettings {
+            kind: ColumnKind::Time(TimeColumn {
+                comparison_override: Some(comparison::best_segments::NAME.into()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }],
+        ..Default::default()
+    });
+
+    let comparisons = |state: &State| {
+        state
+            .splits
+            .iter()
+            .map(|s| s.resolved_comparison.clone())
+            .collect::<Vec<_>>()
+    };
+
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    let pb = comparison::personal_best::NAME;
+    assert_eq!(comparisons(&state), [pb, pb, pb, ""]);
+
+    timer
+        .set_current_comparison(comparison::average_segments::NAME)
+        .unwrap();
+
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    let average = comparison::average_segments::NAME;
+    assert_eq!(comparisons(&state), [average, average, average, ""]);
+    assert!(
+        state.splits[..3]
+            .iter()
+            .all(|s| s.columns[0].resolved_comparison == comparison::best_segments::NAME)
+    );
+}
+
+#[test]
+fn empty_columns_show_the_placeholder() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("A"));
+    run.push_segment(Segment::new("B"));
+    let mut timer = Timer::new(run).unwrap();
+    let layout_settings = Default::default();
+    let mut image_cache = ImageCache::new();
+
+    let mut component = Component::new();
+    timer.start().unwrap();
+
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    assert_eq!(state.splits[1].columns[1].value, "");
+
+    component.settings_mut().empty_placeholder = String::from("—");
+
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    assert_eq!(state.splits[1].columns[1].value, "—");
+}
+
+#[test]
+fn current_split_indicator_is_only_shown_for_the_current_split() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("A"));
+    run.push_segment(Segment::new("B"));
+    let mut timer = Timer::new(run).unwrap();
+    let layout_settings = Default::default();
+    let mut image_cache = ImageCache::new();
+
+    let mut component = Component::new();
+    timer.start().unwrap();
+    timer.split().unwrap();
+
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    assert!(
+        state
+            .splits
+            .iter()
+            .all(|s| s.indicator == SplitIndicator::None)
+    );
+
+    component.settings_mut().current_split_indicator = true;
+
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    assert_eq!(state.splits[0].indicator, SplitIndicator::None);
+    assert_eq!(state.splits[1].indicator, SplitIndicator::Arrow);
+    assert!(
+        state.splits[2..]
+            .iter()
+            .all(|s| s.indicator == SplitIndicator::None)
+    );
+}
+
+#[test]
+fn pb_worst_segment_is_only_marked_if_enabled() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[10.0, 30.0, 40.0]);
+    run_with_splits(&mut timer, &[5.0, 10.0, 45.0]);
+
+    let layout_settings = Default::default();
+    let mut image_cache = ImageCache::new();
+    let mut component = Component::new();
+
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    assert!(state.splits.iter().all(|s| !s.is_pb_worst_segment));
+
+    component.settings_mut().highlight_pb_worst_segment = true;
+
+    // The Personal Best's segments are 10, 20 and 10 seconds long, while the
+    // best segments are 5, 5 and 10 seconds long, so the second segment lost
+    // the most time.
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    assert!(!state.splits[0].is_pb_worst_segment);
+    assert!(state.splits[1].is_pb_worst_segment);
+    assert!(state.splits[2..].iter().all(|s| !s.is_pb_worst_segment));
+}
+
+#[test]
+fn header_is_independent_of_scrolling() {
+    let mut timer = create_timer(&["A", "B", "C", "D", "E", "F"]);
+    let layout_settings = Default::default();
+    let mut image_cache = ImageCache::new();
+
+    let mut component = Component::with_settings(Settings {
+        visual_split_count: 3,
+        show_column_labels: true,
+        ..Default::default()
+    });
+
+    timer.start().unwrap();
+
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    let labels = state.column_labels.as_deref().unwrap().to_vec();
+    assert_eq!(state.splits[0].name, "A");
+    assert!(state.sticky_header);
+
+    component.scroll_down();
+    component.scroll_down();
+
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    assert_eq!(state.splits[0].name, "C");
+    assert_eq!(state.column_labels.as_deref(), Some(&*labels));
+    assert_eq!(state.header_height_units, 1.0);
+    assert!(state.sticky_header);
+
+    component.settings_mut().sticky_header = false;
+
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    assert_eq!(state.column_labels.as_deref(), Some(&*labels));
+    assert!(!state.sticky_header);
+}
+
+#[test]
+fn column_groups_span_adjacent_columns() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("A"));
+    let timer = Timer::new(run).unwrap();
+    let layout_settings = Default::default();
+    let mut image_cache = ImageCache::new();
+
+    let column = |group: Option<&str>| ColumnSettings {
+        group: group.map(String::from),
+        ..Default::default()
+    };
+
+    let mut component = Component::with_settings(Settings {
+        show_column_labels: true,
+        columns: vec![
+            column(Some("Comparisons")),
+            column(Some("Comparisons")),
+            column(None),
+            column(Some("Deltas")),
+            column(Some("Deltas")),
+            column(Some("Deltas")),
+        ],
+        ..Default::default()
+    });
+
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    let groups = state.column_group_labels.unwrap();
+    assert_eq!(groups.len(), 2);
+    assert_eq!(groups[0].name, "Comparisons");
+    assert_eq!((groups[0].start_column, groups[0].column_count), (0, 2));
+    assert_eq!(groups[1].name, "Deltas");
+    assert_eq!((groups[1].start_column, groups[1].column_count), (3, 3));
+
+    component.settings_mut().show_column_labels = false;
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    assert!(state.column_group_labels.is_none());
+
+    component.settings_mut().show_column_labels = true;
+    for column in &mut component.settings_mut().columns {
+        column.group = None;
+    }
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    assert!(state.column_group_labels.is_none());
+}
+
+#[test]
+fn counts_the_segments_hidden_before_the_last_split() {
+    let mut run = Run::new();
+    for _ in 0..20 {
+        run.push_segment(Segment::new(""));
+    }
+    let mut timer = Timer::new(run).unwrap();
+    let layout_settings = Default::default();
+    let mut image_cache = ImageCache::new();
+    let mut component = Component::with_settings(Settings {
+        visual_split_count: 5,
+        split_preview_count: 1,
+        always_show_last_split: true,
+        ..Default::default()
+    });
+
+    // Segments 0 to 3 and the last segment are shown.
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    assert_eq!(state.hidden_segments_before_last_split, 15);
+
+    timer.start().unwrap();
+    for _ in 0..10 {
+        timer.split().unwrap();
+    }
+
+    // Segments 8 to 11 and the last segment are shown.
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    assert_eq!(state.splits[0].index, 8);
+    assert_eq!(state.hidden_segments_before_last_split, 7);
+
+    for _ in 0..7 {
+        timer.split().unwrap();
+    }
+
+    // Segments 15 to 18 are directly followed by the last segment.
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    assert_eq!(state.hidden_segments_before_last_split, 0);
+
+    component.settings_mut().always_show_last_split = false;
+    timer.reset(false).unwrap();
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    assert_eq!(state.hidden_segments_before_last_split, 0);
+}
+
+#[test]
+fn counts_the_splits_ahead_and_behind() {
+    let mut timer = create_timer(&["A", "B", "C", "D", "E", "F"]);
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0, 40.0, 50.0, 60.0]);
+    let layout_settings = Default::default();
+    let mut image_cache = ImageCache::new();
+    let mut component = Component::with_settings(Settings {
+        visual_split_count: 2,
+        ..Default::default()
+    });
+
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    assert_eq!((state.splits_ahead, state.splits_behind), (0, 0));
+
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(
+        &mut timer,
+        &[Some(9.0), Some(21.0), None, Some(39.0), Some(50.0)],
+    );
+
+    // The skipped segment and the tied segment count as neither, even though
+    // most of the segments are scrolled out of view.
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    assert_eq!((state.splits_ahead, state.splits_behind), (2, 1));
+
+    make_progress_run_with_splits_opt(&mut timer, &[Some(61.0)]);
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    assert_eq!((state.splits_ahead, state.splits_behind), (2, 2));
+}
+
+#[test]
+fn awards_medals_based_on_the_bands() {
+    let mut timer = create_timer(&["A", "B", "C", "D", "E", "F"]);
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0, 40.0, 50.0, 60.0]);
+    let layout_settings = Default::default();
+    let mut image_cache = ImageCache::new();
+    let mut component = Component::new();
+
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(
+        &mut timer,
+        &[Some(10.3), Some(21.8), None, Some(45.0), Some(59.0)],
+    );
+
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    assert!(state.splits.iter().all(|s| s.medal.is_none()));
+
+    component.settings_mut().medal_bands = Some(MedalBands {
+        gold: TimeSpan::from_seconds(0.5),
+        silver: TimeSpan::from_seconds(2.0),
+        bronze: TimeSpan::from_seconds(5.0),
+    });
+
+    // The segment after the skipped segment has no segment time of its own
+    // and the current segment isn't completed yet, so neither gets a medal.
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    let medals: Vec<_> = state.splits[..6].iter().map(|s| s.medal).collect();
+    assert_eq!(
+        medals,
+        [
+            Some(MedalTier::Gold),
+            Some(MedalTier::Silver),
+            None,
+            None,
+            Some(MedalTier::Bronze),
+            None,
+        ]
+    );
+
+    // A new best segment is always awarded the gold medal.
+    make_progress_run_with_splits_opt(&mut timer, &[Some(68.0)]);
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    assert_eq!(state.splits[5].medal, Some(MedalTier::Gold));
+
+    // Segments that are too slow for any of the bands don't get a medal.
+    timer.reset(false).unwrap();
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(16.0)]);
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    assert_eq!(state.splits[0].medal, None);
+}
+
+#[test]
+fn medal_bands_setting_is_kept_in_order() {
+    let mut component = Component::new();
+    let index = SETTINGS_BEFORE_COLUMNS - 4;
+    assert_eq!(
+        component.settings_description().fields[index].text,
+        "Medal Bands"
+    );
+
+    component.set_value(
+        index,
+        Some(MedalBands {
+            gold: TimeSpan::from_seconds(3.0),
+            silver: TimeSpan::from_seconds(1.0),
+            bronze: TimeSpan::from_seconds(2.0),
+        })
+        .into(),
+    );
+    assert_eq!(
+        component.settings().medal_bands,
+        Some(MedalBands {
+            gold: TimeSpan::from_seconds(3.0),
+            silver: TimeSpan::from_seconds(3.0),
+            bronze: TimeSpan::from_seconds(3.0),
+        })
+    );
+
+    component.set_value(index, None::<MedalBands>.into());
+    assert_eq!(component.settings().medal_bands, None);
+}
+
+#[test]
+fn medal_backgrounds_setting_follows_the_medal_bands() {
+    let mut component = Component::new();
+    let index = SETTINGS_BEFORE_COLUMNS - 3;
+    let description = component.settings_description();
+    assert_eq!(description.fields[index - 1].text, "Medal Bands");
+    assert_eq!(description.fields[index].text, "Medal Backgrounds");
+    assert!(matches!(
+        description.fields[index].value,
+        Value::OptionalMedalColors(None)
+    ));
+
+    component.set_value(index, Some(MedalColors::default()).into());
+    assert_eq!(
+        component.settings().medal_backgrounds,
+        Some(MedalColors::default())
+    );
+
+    component.set_value(index, None::<MedalColors>.into());
+    assert_eq!(component.settings().medal_backgrounds, None);
+}
+
+#[test]
+fn delta_percent_decimals_are_clamped() {
+    let mut component = Component::new();
+    let index = component
+        .settings_description()
+        .fields
+        .iter()
+        .position(|field| field.text == "Delta Percentage Decimals")
+        .unwrap();
+
+    component.set_value(index, Value::UInt(2));
+    assert_eq!(component.settings().delta_percent_decimals, 2);
+
+    component.set_value(index, Value::UInt(256));
+    assert_eq!(component.settings().delta_percent_decimals, 3);
+}
+
+#[test]
+fn shows_exactly_the_visible_indices() {
+    let mut run = Run::new();
+    for _ in 0..10 {
+        run.push_segment(Segment::new(""));
+    }
+    let mut timer = Timer::new(run).unwrap();
+    let layout_settings = Default::default();
+    let mut image_cache = ImageCache::new();
+    let mut component = Component::with_settings(Settings {
+        visual_split_count: 4,
+        separator_mode: SeparatorMode::None,
+        visible_indices: Some(vec![0, 4, 5, 9, 20]),
+        ..Default::default()
+    });
+
+    let check = |state: &State| {
+        let indices: Vec<_> = state.splits.iter().map(|s| s.index).collect();
+        assert_eq!(indices, [0, 4, 5, 9]);
+        let separators: Vec<_> = state
+            .splits
+            .iter()
+            .map(|s| s.show_separator_after)
+            .collect();
+        assert_eq!(separators, [true, false, true, false]);
+        assert_eq!(state.hidden_segments_before_last_split, 0);
+        assert!(!state.show_final_separator);
+    };
+
+    check(&component.state(&mut image_cache, &timer.snapshot(), &layout_settings));
+
+    // Neither progressing through the run, nor scrolling moves the segments.
+    timer.start().unwrap();
+    for _ in 0..7 {
+        timer.split().unwrap();
+    }
+    check(&component.state(&mut image_cache, &timer.snapshot(), &layout_settings));
+    component.scroll_down();
+    check(&component.state(&mut image_cache, &timer.snapshot(), &layout_settings));
+
+    component.settings_mut().visible_indices = None;
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    assert_eq!(state.splits.len(), 4);
+    assert_ne!(state.splits[0].index, 0);
+}
+
+#[test]
+fn anchors_the_current_split_within_the_window() {
+    let mut run = Run::new();
+    for _ in 0..10 {
+        run.push_segment(Segment::new(""));
+    }
+    let mut timer = Timer::new(run).unwrap();
+    let layout_settings = Default::default();
+    let mut image_cache = ImageCache::new();
+
+    let mut positions = |anchor| {
+        let mut component = Component::with_settings(Settings {
+            visual_split_count: 5,
+            anchor,
+            ..Default::default()
+        });
+        timer.start().unwrap();
+        let mut positions = Vec::new();
+        for _ in 0..10 {
+            let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+            positions.push(
+                state
+                    .splits
+                    .iter()
+                    .position(|s| s.is_current_split)
+                    .unwrap(),
+            );
+            timer.split().unwrap();
+        }
+        timer.reset(false).unwrap();
+        positions
+    };
+
+    // The last row is reserved for the last split, so the current split can
+    // only be anywhere in the first four rows, until the window can't scroll
+    // any further.
+    assert_eq!(
+        positions(CurrentSplitAnchor::Auto),
+        [0, 1, 2, 2, 2, 2, 2, 2, 3, 4],
+    );
+    assert_eq!(
+        positions(CurrentSplitAnchor::Top),
+        [0, 0, 0, 0, 0, 0, 1, 2, 3, 4],
+    );
+    assert_eq!(
+        positions(CurrentSplitAnchor::Center),
+        [0, 1, 1, 1, 1, 1, 1, 2, 3, 4],
+    );
+}
+
+#[test]
+fn anchor_setting_is_right_before_the_columns() {
+    let mut component = Component::new();
+    let index = SETTINGS_BEFORE_COLUMNS - 2;
+    let description = component.settings_description();
+    assert_eq!(description.fields[index].text, "Current Segment Position");
+    assert_eq!(description.fields[index + 1].text, "Columns");
+
+    component.set_value(index, CurrentSplitAnchor::Center.into());
+    assert_eq!(component.settings().anchor, CurrentSplitAnchor::Center);
+}
+
+#[test]
+fn blank_rows_report_why_they_are_blank() {
+    let mut run = Run::new();
+    for _ in 0..10 {
+        run.push_segment(Segment::new(""));
+    }
+    let timer = Timer::new(run).unwrap();
+    let layout_settings = Default::default();
+    let mut image_cache = ImageCache::new();
+    let mut component = Component::with_settings(Settings {
+        visual_split_count: 8,
+        visible_indices: Some(vec![0, 1, 4, 9]),
+        show_gap_rows: true,
+        ..Default::default()
+    });
+
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    let rows: Vec<_> = state
+        .splits
+        .iter()
+        .map(|s| (s.blank_reason, s.blank_reason.is_none().then_some(s.index)))
+        .collect();
+    assert_eq!(
+        rows,
+        [
+            (None, Some(0)),
+            (None, Some(1)),
+            (Some(BlankReason::Gap), None),
+            (None, Some(4)),
+            (Some(BlankReason::Gap), None),
+            (None, Some(9)),
+            (Some(BlankReason::Padding), None),
+            (Some(BlankReason::Padding), None),
+        ]
+    );
+
+    component.settings_mut().show_gap_rows = false;
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    let reasons: Vec<_> = state.splits.iter().map(|s| s.blank_reason).collect();
+    assert_eq!(
+        reasons,
+        [
+            None,
+            None,
+            None,
+            None,
+            Some(BlankReason::Padding),
+            Some(BlankReason::Padding),
+            Some(BlankReason::Padding),
+            Some(BlankReason::Padding),
+        ]
+    );
+}
+
+#[test]
+fn columns_report_their_width_limits() {
+    let timer = create_timer(&["A", "B"]);
+    let layout_settings = Default::default();
+    let mut image_cache = ImageCache::new();
+    let mut component = Component::new();
+
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    for column in &state.splits[0].columns {
+        assert_eq!((column.min_width, column.max_width), (None, None));
+    }
+
+    let second_column = SETTINGS_BEFORE_COLUMNS + 16;
+    component.set_value(SETTINGS_BEFORE_COLUMNS + 14, Some(2.0).into());
+    component.set_value(second_column + 15, Some(3.5).into());
+
+    let description = component.settings_description();
+    assert_eq!(
+        description.fields[SETTINGS_BEFORE_COLUMNS + 14].text,
+        "Minimum Width"
+    );
+    assert_eq!(description.fields[second_column + 15].text, "Maximum Width");
+    assert_eq!(description.fields[second_column + 1].text, "Column Type");
+
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    for split in &state.splits {
+        assert_eq!(split.columns[0].min_width, Some(2.0));
+        assert_eq!(split.columns[0].max_width, None);
+        assert_eq!(split.columns[1].min_width, None);
+        assert_eq!(split.columns[1].max_width, Some(3.5));
+    }
+}
+
+#[test]
+fn common_column_settings_follow_the_kind_specific_ones() {
+    let mut component = Component::new();
+    component.settings_mut().columns = vec![
+        ColumnSettings {
+            kind: ColumnKind::Variable(VariableColumn::default()),
+            ..Default::default()
+        },
+        ColumnSettings::default(),
+    ];
+
+    let description = component.settings_description();
+    let texts = description.fields[SETTINGS_BEFORE_COLUMNS..]
+        .iter()
+        .map(|field| &*field.text)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        texts[..7],
+        [
+            "Column Name",
+            "Column Type",
+            "Variable Name",
+            "Column Alignment",
+            "Column Group",
+            "Minimum Width",
+            "Maximum Width",
+        ],
+    );
+    assert_eq!(texts[7..10], ["Column Name", "Column Type", "Start With"]);
+    assert_eq!(texts[17], "Column Alignment");
+
+    let first_column = SETTINGS_BEFORE_COLUMNS;
+    component.set_value(first_column + 3, Some(ColumnAlignment::Center).into());
+    component.set_value(first_column + 17, Some(ColumnAlignment::Start).into());
+    let columns = &component.settings().columns;
+    assert_eq!(columns[0].alignment, Some(ColumnAlignment::Center));
+    assert_eq!(columns[1].alignment, Some(ColumnAlignment::Start));
+}
+
+#[test]
+fn tooltips_summarize_the_segments() {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[5.0, 12.0]);
+
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(4.0)]);
+
+    let layout_settings = Default::default();
+    let mut image_cache = ImageCache::new();
+    let mut component = Component::new();
+
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    assert!(state.splits.iter().all(|split| split.tooltip.is_empty()));
+
+    component.settings_mut().show_tooltips = true;
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    let tooltip = &state.splits[0].tooltip;
+    assert!(tooltip.starts_with("A\n"));
+    assert!(tooltip.contains("Delta: −1.0"));
+    assert!(tooltip.ends_with("Best Segment"));
+    assert_eq!(state.splits[1].tooltip, "B");
+}
+
+#[test]
+fn reports_the_visible_window() {
+    let mut timer = create_timer(&["A", "B", "C", "D", "E", "F", "G", "H"]);
+    let layout_settings = Default::default();
+    let mut image_cache = ImageCache::new();
+    let mut component = Component::with_settings(Settings {
+        visual_split_count: 4,
+        split_preview_count: 1,
+        always_show_last_split: true,
+        ..Default::default()
+    });
+
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    assert_eq!(state.first_visible_index, 0);
+    assert!(state.last_split_is_pinned);
+
+    timer.start().unwrap();
+    for _ in 0..3 {
+        timer.split().unwrap();
+    }
+
+    // Segments 2 to 4 and the last segment are shown.
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    assert_eq!(state.first_visible_index, 2);
+    assert_eq!(state.splits[0].index, 2);
+    assert!(state.last_split_is_pinned);
+
+    let json = serde_json::to_string(&state).unwrap();
+    assert!(json.contains(r#""first_visible_index":2"#));
+    assert!(json.contains(r#""last_split_is_pinned":true"#));
+
+    for _ in 0..3 {
+        timer.split().unwrap();
+    }
+
+    // Segments 4 to 6 are directly followed by the last segment.
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    assert_eq!(state.first_visible_index, 4);
+    assert!(state.last_split_is_pinned);
+
+    // Segments 4 to 7 are shown in the scrolling window.
+    component.settings_mut().always_show_last_split = false;
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    assert_eq!(state.first_visible_index, 4);
+    assert!(!state.last_split_is_pinned);
+
+    component.settings_mut().visible_indices = Some(vec![3, 1, 20]);
+    let state = component.state(&mut image_cache, &timer.snapshot(), &layout_settings);
+    assert_eq!(state.first_visible_index, 1);
+    assert!(!state.last_split_is_pinned);
+}
+
+#[test]
+fn scrolls_to_a_specific_segment() {
+    let mut run = Run::new();
+    for _ in 0..20 {
+        run.push_segment(Segment::new(""));
+    }
+    let timer = Timer::new(run).unwrap();
+    let layout_settings = Default::default();
+    let mut image_cache = ImageCache::new();
+    let mut component = Component::with_settings(Settings {
+        visual_split_count: 5,
+        split_preview_count: 1,
+        always_show_last_split: true,
+        ..Default::default()
+    });
+
+    let mut indices = |component: &mut Component| {
+        component
+            .state(&mut image_cache, &timer.snapshot(), &layout_settings)
+            .splits
+            .iter()
+            .map(|split| split.index)
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(indices(&mut component), [0, 1, 2, 3, 19]);
+
+    // Scrolling down only as far as necessary.
+    component.scroll_to_segment(10);
+    assert_eq!(indices(&mut component), [7, 8, 9, 10, 19]);
+
+    // Segments that are already shown don't move the window.
+    component.scroll_to_segment(8);
+    assert_eq!(indices(&mut component), [7, 8, 9, 10, 19]);
+
+    // The last segment is always shown.
+    component.scroll_to_segment(19);
+    assert_eq!(indices(&mut component), [7, 8, 9, 10, 19]);
+
+    component.scroll_to_segment(0);
+    assert_eq!(indices(&mut component), [0, 1, 2, 3, 19]);
+
+    component.settings_mut().always_show_last_split = false;
+
+    component.scroll_to_segment(19);
+    assert_eq!(indices(&mut component), [15, 16, 17, 18, 19]);
+
+    component.scroll_to_segment(0);
+    assert_eq!(indices(&mut component), [0, 1, 2, 3, 4]);
+
+    // Indices beyond the last segment scroll to the last segment.
+    component.scroll_to_segment(100);
+    assert_eq!(indices(&mut component), [15, 16, 17, 18, 19]);
+}
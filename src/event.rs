@@ -59,6 +59,12 @@ pub enum Event {
     LoadingTimesSet = 16,
     /// A custom variable has been set.
     CustomVariableSet = 17,
+    /// The time of a custom timing method has been set.
+    CustomTimeSet = 18,
+    /// The active attempt has been reconciled with an edited run.
+    AttemptReconciled = 19,
+    /// The most recent reset has been undone.
+    ResetUndone = 20,
     /// An unknown event occurred.
     #[serde(other)]
     Unknown,
@@ -85,11 +91,29 @@ impl From<u32> for Event {
             15 => Event::GameTimeResumed,
             16 => Event::LoadingTimesSet,
             17 => Event::CustomVariableSet,
+            18 => Event::CustomTimeSet,
+            19 => Event::AttemptReconciled,
+            20 => Event::ResetUndone,
             _ => Event::Unknown,
         }
     }
 }
 
+/// Describes a new best segment time that was achieved by splitting. This is
+/// reported by [`Timer::split_with_gold_callback`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct GoldAchieved {
+    /// The index of the segment that was just completed with a new best
+    /// segment time.
+    pub segment_index: usize,
+    /// The timing method the new best segment time was achieved with.
+    pub timing_method: TimingMethod,
+    /// The previous best segment time of the segment, if there was one.
+    pub old: Option<TimeSpan>,
+    /// The new best segment time of the segment.
+    pub new: TimeSpan,
+}
+
 /// An error that occurred when a command was being processed.
 #[derive(
     Copy,
@@ -139,6 +163,17 @@ pub enum Error {
     TimerPaused = 15,
     /// The runner decided to not reset the run.
     RunnerDecidedAgainstReset = 16,
+    /// The requested custom timing method doesn't exist.
+    CustomTimingMethodDoesntExist = 17,
+    /// The split happened too soon after the previous split, so it got
+    /// rejected as a duplicate.
+    SplitWithinConfirmationWindow = 18,
+    /// The amount of split times doesn't match the amount of segments.
+    SplitCountMismatch = 19,
+    /// The split times are not in increasing order.
+    SplitTimesNotIncreasing = 20,
+    /// There is no reset to undo.
+    NoResetToUndo = 21,
     /// An unknown error occurred.
     #[serde(other)]
     Unknown,
@@ -164,6 +199,11 @@ impl From<u32> for Error {
             14 => Error::CouldNotParseTime,
             15 => Error::TimerPaused,
             16 => Error::RunnerDecidedAgainstReset,
+            17 => Error::CustomTimingMethodDoesntExist,
+            18 => Error::SplitWithinConfirmationWindow,
+            19 => Error::SplitCountMismatch,
+            20 => Error::SplitTimesNotIncreasing,
+            21 => Error::NoResetToUndo,
             _ => Error::Unknown,
         }
     }
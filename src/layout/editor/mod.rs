@@ -116,7 +116,7 @@ impl Editor {
     /// component instead.
     pub fn remove_component(&mut self) {
         if self.can_remove_component() {
-            self.layout.components.remove(self.selected_component);
+            self.layout.remove_component(self.selected_component);
             if self.selected_component >= self.layout.components.len() {
                 self.selected_component = self.layout.components.len() - 1;
             }
@@ -134,8 +134,7 @@ impl Editor {
     pub fn move_component_up(&mut self) {
         if self.can_move_component_up() {
             self.layout
-                .components
-                .swap(self.selected_component, self.selected_component - 1);
+                .swap_components(self.selected_component, self.selected_component - 1);
             self.selected_component -= 1;
         }
     }
@@ -151,8 +150,7 @@ impl Editor {
     pub fn move_component_down(&mut self) {
         if self.can_move_component_down() {
             self.layout
-                .components
-                .swap(self.selected_component, self.selected_component + 1);
+                .swap_components(self.selected_component, self.selected_component + 1);
             self.selected_component += 1;
         }
     }
@@ -177,11 +175,19 @@ impl Editor {
         let new_index = index + 1;
 
         let component = self.layout.components[index].clone();
-        self.layout.components.insert(new_index, component);
+        let visible = self.layout.is_component_visible(index);
+        self.layout.insert_component(new_index, component, visible);
 
         self.selected_component = new_index;
     }
 
+    /// Shows or hides the selected component. Hidden components keep their
+    /// settings and stay part of the layout, but they are not visualized.
+    pub fn set_component_visible(&mut self, visible: bool) {
+        self.layout
+            .set_component_visible(self.selected_component, visible);
+    }
+
     /// Sets a setting's value of the selected component by its setting index
     /// to the given value.
     ///
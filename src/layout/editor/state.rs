@@ -11,6 +11,9 @@ use serde_derive::{Deserialize, Serialize};
 pub struct State {
     /// The name of all the components in the layout.
     pub components: Vec<String>,
+    /// Whether each of the components in the layout is visible. Hidden
+    /// components are not visualized.
+    pub component_visibility: Vec<bool>,
     /// Describes which actions are currently available.
     pub buttons: Buttons,
     /// The index of the currently selected component.
@@ -64,6 +67,10 @@ impl Editor {
             .map(|c| c.name().into_owned())
             .collect();
 
+        let component_visibility = (0..self.layout.components.len())
+            .map(|index| self.layout.is_component_visible(index))
+            .collect();
+
         let buttons = Buttons {
             can_remove: self.can_remove_component(),
             can_move_up: self.can_move_component_up(),
@@ -72,6 +79,7 @@ impl Editor {
 
         State {
             components,
+            component_visibility,
             buttons,
             selected_component: self.selected_component as u32,
             component_settings: self.layout.components[self.selected_component]
@@ -2,7 +2,8 @@ use super::LayoutDirection;
 use crate::{
     platform::prelude::*,
     settings::{
-        Color, Field, Font, Gradient, ImageCache, LayoutBackground, SettingsDescription, Value,
+        Color, Field, Font, Gradient, GradientColorSpace, ImageCache, LayoutBackground,
+        SettingsDescription, Value,
     },
 };
 use serde_derive::{Deserialize, Serialize};
@@ -22,6 +23,17 @@ pub struct GeneralSettings {
     /// The font to use for regular text. `None` means a default font should be
     /// used.
     pub text_font: Option<Font>,
+    /// The additional spacing to apply between the letters of all the text,
+    /// specified as a fraction of the font size. Positive values spread the
+    /// glyphs apart, while negative values move them closer together.
+    pub letter_spacing: f32,
+    /// Specifies whether the digits of regular text are supposed to be
+    /// monospaced. The timer and the times always use monospaced digits. This
+    /// prevents text such as counters from shifting around as their digits
+    /// change. Tabular figures of the font are used if they are available.
+    pub monospace_text_digits: bool,
+    /// The color space that the colors of gradients are interpolated in.
+    pub gradient_color_space: GradientColorSpace,
     /// The color to use for drawn shadows.
     pub text_shadow: Option<Color>,
     /// The background to show behind the layout.
@@ -61,6 +73,9 @@ impl Default for GeneralSettings {
             timer_font: None,
             times_font: None,
             text_font: None,
+            letter_spacing: 0.0,
+            monospace_text_digits: false,
+            gradient_color_space: GradientColorSpace::Srgb,
             text_shadow: Some(Color::hsla(0.0, 0.0, 0.0, 0.5)),
             background: LayoutBackground::Gradient(Gradient::Plain(Color::hsla(
                 0.0, 0.0, 0.06, 1.0,
@@ -181,6 +196,24 @@ impl GeneralSettings {
                 "The color to use for text that doesn't specify its own color.".into(),
                 self.text_color.into(),
             ),
+            Field::new(
+                "Letter Spacing".into(),
+                "Additional spacing between the letters of all the text, relative to the font size. Positive values spread the letters apart, negative values move them closer together."
+                    .into(),
+                (self.letter_spacing as f64).into(),
+            ),
+            Field::new(
+                "Monospace Text Digits".into(),
+                "Specifies whether the digits of regular text should all have the same width, so that changing numbers don't shift the text around. The timer and the times always use monospaced digits."
+                    .into(),
+                self.monospace_text_digits.into(),
+            ),
+            Field::new(
+                "Gradient Color Space".into(),
+                "The color space that the colors of gradients are blended in. Blending them in linear RGB or Oklab avoids the darker and muddier colors in the middle of the gradients that blending them in sRGB may cause."
+                    .into(),
+                self.gradient_color_space.into(),
+            ),
         ])
     }
 
@@ -210,6 +243,9 @@ impl GeneralSettings {
             14 => self.thin_separators_color = value.into(),
             15 => self.separators_color = value.into(),
             16 => self.text_color = value.into(),
+            17 => self.letter_spacing = f64::from(value) as _,
+            18 => self.monospace_text_digits = value.into(),
+            19 => self.gradient_color_space = value.into(),
             _ => panic!("Unsupported Setting Index"),
         }
     }
@@ -1,9 +1,14 @@
+use core::fmt::{self, Write};
+
 use serde_derive::{Deserialize, Serialize};
 
 use super::{ComponentState, LayoutDirection};
 use crate::{
+    component::{text::TextState, timer},
     platform::prelude::*,
-    settings::{Color, Font, ImageId, LayoutBackground},
+    settings::{
+        Color, Font, Gradient, GradientColorSpace, ImageId, LayoutBackground, ListGradient,
+    },
 };
 
 /// The state object describes the information to visualize for the layout.
@@ -22,6 +27,18 @@ pub struct LayoutState {
     /// The font to use for regular text. `None` means a default font should be
     /// used.
     pub text_font: Option<Font>,
+    /// The additional spacing to apply between the letters of all the text,
+    /// specified as a fraction of the font size.
+    #[serde(default)]
+    pub letter_spacing: f32,
+    /// Specifies whether the digits of regular text are supposed to be
+    /// monospaced, just like they are for the timer and the times.
+    #[serde(default)]
+    pub monospace_text_digits: bool,
+    /// The color space that the colors of gradients are supposed to be
+    /// interpolated in.
+    #[serde(default)]
+    pub gradient_color_space: GradientColorSpace,
     /// An optional text shadow color.
     pub text_shadow: Option<Color>,
     /// The background to show behind the layout.
@@ -44,3 +61,199 @@ impl LayoutState {
         serde_json::to_writer(writer, self)
     }
 }
+
+impl LayoutState {
+    /// Serializes the state object into a compact human readable text. Every
+    /// component is described by a line containing its kind and the
+    /// information it visualizes, such as names, times and colors. Nested
+    /// information, such as the individual splits of the Splits Component, is
+    /// described by indented lines following the component's line. Images and
+    /// fonts are not part of the text. The text only depends on the state
+    /// itself, so it can be used for golden tests that don't depend on any
+    /// rendering or the fonts that are available on the system.
+    pub fn to_debug_string(&self) -> String {
+        let mut s = String::new();
+        let _ = self.write_debug_string(&mut s);
+        s
+    }
+
+    fn write_debug_string(&self, s: &mut String) -> fmt::Result {
+        let direction = match self.direction {
+            LayoutDirection::Vertical => "Vertical",
+            LayoutDirection::Horizontal => "Horizontal",
+        };
+        writeln!(
+            s,
+            "Layout direction={direction} text={} separators={} thin_separators={}",
+            Hex(self.text_color),
+            Hex(self.separators_color),
+            Hex(self.thin_separators_color),
+        )?;
+
+        for component in &self.components {
+            match component {
+                ComponentState::BlankSpace(state) => {
+                    writeln!(
+                        s,
+                        "BlankSpace size={} background={}",
+                        state.size,
+                        DebugGradient(state.background),
+                    )?;
+                }
+                ComponentState::DetailedTimer(state) => {
+                    writeln!(
+                        s,
+                        "DetailedTimer background={} segment_name={:?}",
+                        DebugGradient(state.background),
+                        state.segment_name,
+                    )?;
+                    write_timer(s, "  Timer", &state.timer)?;
+                    write_timer(s, "  SegmentTimer", &state.segment_timer)?;
+                    for comparison in [&state.comparison1, &state.comparison2]
+                        .into_iter()
+                        .flatten()
+                    {
+                        writeln!(
+                            s,
+                            "  Comparison {:?} {:?}",
+                            comparison.name, comparison.time,
+                        )?;
+                    }
+                }
+                ComponentState::Graph(state) => {
+                    write!(
+                        s,
+                        "Graph height={} middle={} flipped={} live={} points=",
+                        state.height, state.middle, state.is_flipped, state.is_live_delta_active,
+                    )?;
+                    for (i, point) in state.points.iter().enumerate() {
+                        if i != 0 {
+                            s.push(' ');
+                        }
+                        write!(s, "({},{})", point.x, point.y)?;
+                        if point.is_best_segment {
+                            s.push('*');
+                        }
+                    }
+                    s.push('\n');
+                }
+                ComponentState::KeyValue(state) => {
+                    writeln!(
+                        s,
+                        "KeyValue {:?}={:?} {:?} background={}",
+                        state.key,
+                        state.value,
+                        state.semantic_color,
+                        DebugGradient(state.background),
+                    )?;
+                }
+                ComponentState::Separator(_) => s.push_str("Separator\n"),
+                ComponentState::Splits(state) => {
+                    writeln!(
+                        s,
+                        "Splits background={} hidden_before_last={} final_separator={}",
+                        DebugListGradient(state.background),
+                        state.hidden_segments_before_last_split,
+                        state.show_final_separator,
+                    )?;
+                    if let Some(labels) = &state.column_labels {
+                        write!(s, "  Labels")?;
+                        for label in labels {
+                            write!(s, " {label:?}")?;
+                        }
+                        s.push('\n');
+                    }
+                    for split in &state.splits {
+                        write!(s, "  {} {:?}", split.index, split.name)?;
+                        if split.is_current_split {
+                            s.push_str(" current");
+                        }
+                        if split.show_separator_after {
+                            s.push_str(" separator");
+                        }
+                        if let Some(blank_reason) = split.blank_reason {
+                            write!(s, " blank={blank_reason:?}")?;
+                        }
+                        for column in &split.columns {
+                            write!(
+                                s,
+                                " | {:?} {:?} {}",
+                                column.value,
+                                column.semantic_color,
+                                Hex(column.visual_color),
+                            )?;
+                        }
+                        s.push('\n');
+                    }
+                }
+                ComponentState::Text(state) => {
+                    match &state.text {
+                        TextState::Center(text) => write!(s, "Text {text:?}")?,
+                        TextState::Split(left, right) => write!(s, "Text {left:?} {right:?}")?,
+                    }
+                    writeln!(s, " background={}", DebugGradient(state.background))?;
+                }
+                ComponentState::Timer(state) => write_timer(s, "Timer", state)?,
+                ComponentState::Title(state) => {
+                    writeln!(
+                        s,
+                        "Title {:?} {:?} attempts={:?} finished={:?} background={}",
+                        state.line1.first(),
+                        state.line2.first(),
+                        state.attempts,
+                        state.finished_runs,
+                        DebugGradient(state.background),
+                    )?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn write_timer(s: &mut String, label: &str, state: &timer::State) -> fmt::Result {
+    writeln!(
+        s,
+        "{label} \"{}{}\" {:?} top={} bottom={} height={}",
+        state.time,
+        state.fraction,
+        state.semantic_color,
+        Hex(state.top_color),
+        Hex(state.bottom_color),
+        state.height,
+    )
+}
+
+struct Hex(Color);
+
+impl fmt::Display for Hex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [r, g, b, a] = self.0.to_rgba8();
+        write!(f, "#{r:02X}{g:02X}{b:02X}{a:02X}")
+    }
+}
+
+struct DebugGradient(Gradient);
+
+impl fmt::Display for DebugGradient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Gradient::Transparent => f.write_str("Transparent"),
+            Gradient::Plain(c) => write!(f, "Plain({})", Hex(c)),
+            Gradient::Vertical(a, b) => write!(f, "Vertical({},{})", Hex(a), Hex(b)),
+            Gradient::Horizontal(a, b) => write!(f, "Horizontal({},{})", Hex(a), Hex(b)),
+        }
+    }
+}
+
+struct DebugListGradient(ListGradient);
+
+impl fmt::Display for DebugListGradient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            ListGradient::Same(gradient) => fmt::Display::fmt(&DebugGradient(gradient), f),
+            ListGradient::Alternating(a, b) => write!(f, "Alternating({},{})", Hex(a), Hex(b)),
+        }
+    }
+}
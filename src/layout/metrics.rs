@@ -0,0 +1,14 @@
+//! The metrics of the layout, specified relative to the height of a component
+//! that shows a single line of text. The components use these to report the
+//! sizes of their rows and the renderer uses them to lay out the text, so they
+//! always agree with each other.
+
+pub const DEFAULT_COMPONENT_HEIGHT: f32 = 1.0;
+pub const DEFAULT_TEXT_ASCENT: f32 = 0.55;
+pub const DEFAULT_TEXT_DESCENT: f32 = 0.175;
+pub const DEFAULT_TEXT_ASCENT_DESCENT_DISTANCE: f32 = DEFAULT_TEXT_ASCENT + DEFAULT_TEXT_DESCENT;
+pub const BOTH_VERTICAL_PADDINGS: f32 =
+    DEFAULT_COMPONENT_HEIGHT - DEFAULT_TEXT_ASCENT_DESCENT_DISTANCE;
+pub const TWO_ROW_HEIGHT: f32 = 2.0 * DEFAULT_TEXT_ASCENT_DESCENT_DISTANCE + BOTH_VERTICAL_PADDINGS;
+pub const SUB_VALUE_TEXT_SCALE: f32 = 0.8;
+pub const SUB_VALUE_HEIGHT: f32 = SUB_VALUE_TEXT_SCALE * DEFAULT_TEXT_ASCENT_DESCENT_DISTANCE;
@@ -10,8 +10,12 @@ mod general_settings;
 mod layout_direction;
 mod layout_settings;
 mod layout_state;
+pub(crate) mod metrics;
 pub mod parser;
 
+#[cfg(test)]
+mod tests;
+
 pub use self::{
     component::Component, component_settings::ComponentSettings, component_state::ComponentState,
     editor::Editor, general_settings::GeneralSettings, layout_direction::LayoutDirection,
@@ -31,6 +35,10 @@ use crate::{
 pub struct Layout {
     /// All of the layout's components.
     pub components: Vec<Component>,
+    /// Whether the component at the same index is visible. Components past the
+    /// end of this list are visible, so components that get added directly to
+    /// the list of components start out visible.
+    visibility: Vec<bool>,
     settings: GeneralSettings,
 }
 
@@ -45,25 +53,29 @@ impl Layout {
     /// are provided by this and how they are configured may change in the
     /// future.
     pub fn default_layout() -> Self {
+        let components: Vec<Component> = vec![
+            title::Component::new().into(),
+            splits::Component::new().into(),
+            timer::Component::new().into(),
+            previous_segment::Component::new().into(),
+        ];
         Self {
-            components: vec![
-                title::Component::new().into(),
-                splits::Component::new().into(),
-                timer::Component::new().into(),
-                previous_segment::Component::new().into(),
-            ],
+            visibility: vec![true; components.len()],
+            components,
             settings: GeneralSettings::default(),
         }
     }
 
     /// Creates a new layout from the layout settings of the whole layout.
     pub fn from_settings(layout_settings: LayoutSettings) -> Self {
+        let components: Vec<Component> = layout_settings
+            .components
+            .into_iter()
+            .map(Into::into)
+            .collect();
         Self {
-            components: layout_settings
-                .components
-                .into_iter()
-                .map(Into::into)
-                .collect(),
+            visibility: vec![true; components.len()],
+            components,
             settings: layout_settings.general,
         }
     }
@@ -82,7 +94,66 @@ impl Layout {
 
     /// Adds a new component to the end of the layout.
     pub fn push<C: Into<Component>>(&mut self, component: C) {
+        self.sync_visibility();
         self.components.push(component.into());
+        self.visibility.push(true);
+    }
+
+    /// Inserts all the components of the other layout at the index specified,
+    /// preserving their settings and visibility. The general settings of the
+    /// other layout are discarded. If the index is out of bounds, the
+    /// components are appended to the end of the layout instead.
+    pub fn merge(&mut self, mut other: Layout, at: usize) {
+        other.sync_visibility();
+        self.sync_visibility();
+        let at = at.min(self.components.len());
+        self.components.splice(at..at, other.components);
+        self.visibility.splice(at..at, other.visibility);
+    }
+
+    /// Checks whether the component at the index provided is visible. Hidden
+    /// components stay part of the layout, but they are not part of the
+    /// layout's state and thus are not visualized.
+    pub fn is_component_visible(&self, index: usize) -> bool {
+        self.visibility.get(index).copied().unwrap_or(true)
+    }
+
+    /// Shows or hides the component at the index provided. Hidden components
+    /// keep their settings and stay part of the layout, but they are skipped
+    /// when calculating the layout's state. This allows temporarily hiding
+    /// components without having to remove them. The visibility of the
+    /// components is not part of the layout's settings and is therefore not
+    /// stored when the layout is saved. Indices that are out of bounds are
+    /// ignored.
+    pub fn set_component_visible(&mut self, index: usize, visible: bool) {
+        self.sync_visibility();
+        if let Some(entry) = self.visibility.get_mut(index) {
+            *entry = visible;
+        }
+    }
+
+    /// Brings the visibility of the components in line with the list of
+    /// components, which may have been modified directly.
+    fn sync_visibility(&mut self) {
+        self.visibility.resize(self.components.len(), true);
+    }
+
+    fn insert_component(&mut self, index: usize, component: Component, visible: bool) {
+        self.sync_visibility();
+        self.components.insert(index, component);
+        self.visibility.insert(index, visible);
+    }
+
+    fn remove_component(&mut self, index: usize) {
+        self.sync_visibility();
+        self.components.remove(index);
+        self.visibility.remove(index);
+    }
+
+    fn swap_components(&mut self, a: usize, b: usize) {
+        self.sync_visibility();
+        self.components.swap(a, b);
+        self.visibility.swap(a, b);
     }
 
     /// Updates the layout's state based on the timer provided. You can use this
@@ -97,10 +168,17 @@ impl Layout {
         image_cache: &mut ImageCache,
         timer: &Snapshot<'_>,
     ) {
-        let settings = &self.settings;
+        self.sync_visibility();
+        let visible_count = self.visibility.iter().filter(|&&visible| visible).count();
+        state.components.truncate(visible_count);
 
-        state.components.truncate(self.components.len());
-        let mut components = self.components.iter_mut();
+        let settings = &self.settings;
+        let mut components = self
+            .components
+            .iter_mut()
+            .zip(&self.visibility)
+            .filter(|&(_, &visible)| visible)
+            .map(|(component, _)| component);
         // First update all the states that we have.
         for (state, component) in state.components.iter_mut().zip(components.by_ref()) {
             component.update_state(state, image_cache, timer, settings);
@@ -113,6 +191,9 @@ impl Layout {
         state.timer_font.clone_from(&settings.timer_font);
         state.times_font.clone_from(&settings.times_font);
         state.text_font.clone_from(&settings.text_font);
+        state.letter_spacing = settings.letter_spacing;
+        state.monospace_text_digits = settings.monospace_text_digits;
+        state.gradient_color_space = settings.gradient_color_space;
 
         state.background = settings.background.cache(image_cache);
         state.thin_separators_color = settings.thin_separators_color;
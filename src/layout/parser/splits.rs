@@ -5,7 +5,7 @@ use super::{
 use crate::{
     component::splits::{
         self, ColumnKind, ColumnSettings, ColumnStartWith, ColumnUpdateTrigger, ColumnUpdateWith,
-        TimeColumn,
+        SeparatorMode, TimeColumn,
     },
     platform::prelude::*,
     util::xml::{helper::text_as_escaped_string_err, Reader},
@@ -31,7 +31,13 @@ pub fn settings(reader: &mut Reader<'_>, component: &mut Component) -> Result<()
                         text_parsed(reader, |v| settings.split_preview_count = v)
                     }
                     "ShowThinSeparators" => {
-                        parse_bool(reader, |b| settings.show_thin_separators = b)
+                        parse_bool(reader, |b| {
+                            settings.separator_mode = if b {
+                                SeparatorMode::All
+                            } else {
+                                SeparatorMode::None
+                            }
+                        })
                     }
                     "AlwaysShowLastSplit" => {
                         parse_bool(reader, |b| settings.always_show_last_split = b)
@@ -105,6 +111,10 @@ pub fn settings(reader: &mut Reader<'_>, component: &mut Component) -> Result<()
                                 splits::ColumnSettings {
                                     name: column_name,
                                     kind: ColumnKind::Time(column),
+                                    alignment: None,
+                                    group: None,
+                                    min_width: None,
+                                    max_width: None,
                                 },
                             );
                             Ok(())
@@ -139,7 +149,16 @@ pub fn settings(reader: &mut Reader<'_>, component: &mut Component) -> Result<()
                                         update_trigger: ColumnUpdateTrigger::OnEndingSegment,
                                         comparison_override: comparison_override.clone(),
                                         timing_method: None,
+                                        custom_timing_method: None,
+                                        show_segment_time_below: false,
+                                        show_comparison_ghost: false,
+                                        sign_style: None,
+                                        accuracy_override: None,
                                     }),
+                                    alignment: None,
+                                    group: None,
+                                    min_width: None,
+                                    max_width: None,
                                 });
                                 settings.columns.push(ColumnSettings {
                                     name: String::from("+/−"),
@@ -149,7 +168,16 @@ pub fn settings(reader: &mut Reader<'_>, component: &mut Component) -> Result<()
                                         update_trigger: ColumnUpdateTrigger::Contextual,
                                         comparison_override,
                                         timing_method: None,
+                                        custom_timing_method: None,
+                                        show_segment_time_below: false,
+                                        show_comparison_ghost: false,
+                                        sign_style: None,
+                                        accuracy_override: None,
                                     }),
+                                    alignment: None,
+                                    group: None,
+                                    min_width: None,
+                                    max_width: None,
                                 });
                             }
                         })
@@ -0,0 +1,172 @@
+use super::{ComponentState, Editor, Layout};
+use crate::{
+    component::{blank_space, separator},
+    settings::ImageCache,
+    util::tests_helper::create_timer,
+};
+
+fn names(layout: &Layout) -> Vec<String> {
+    layout
+        .components
+        .iter()
+        .map(|c| c.name().into_owned())
+        .collect()
+}
+
+#[test]
+fn merge_inserts_components_at_index() {
+    let mut other = Layout::new();
+    other.push(separator::Component::new());
+    other.push(blank_space::Component::new());
+
+    let mut layout = Layout::default_layout();
+    layout.merge(other, 1);
+
+    let names = names(&layout);
+    assert_eq!(names.len(), 6);
+    assert_eq!(names[..4], ["Title", "Separator", "Blank Space", "Splits"]);
+}
+
+#[test]
+fn merge_appends_when_out_of_bounds() {
+    let mut other = Layout::new();
+    other.push(separator::Component::new());
+    other.push(blank_space::Component::new());
+
+    let mut layout = Layout::default_layout();
+    layout.merge(other, 100);
+
+    assert_eq!(layout.components.len(), 6);
+    assert_eq!(names(&layout)[4..], ["Separator", "Blank Space"]);
+}
+
+#[test]
+fn hidden_components_are_skipped_in_the_state() {
+    let timer = create_timer(&["A", "B"]);
+    let mut image_cache = ImageCache::new();
+    let mut layout = Layout::default_layout();
+
+    let state = layout.state(&mut image_cache, &timer.snapshot());
+    assert_eq!(state.components.len(), 4);
+    assert!(matches!(state.components[1], ComponentState::Splits(_)));
+
+    layout.set_component_visible(1, false);
+    assert!(!layout.is_component_visible(1));
+
+    let state = layout.state(&mut image_cache, &timer.snapshot());
+    assert_eq!(state.components.len(), 3);
+    assert!(
+        !state
+            .components
+            .iter()
+            .any(|c| matches!(c, ComponentState::Splits(_)))
+    );
+    assert_eq!(names(&layout)[1], "Splits");
+
+    layout.set_component_visible(1, true);
+    let state = layout.state(&mut image_cache, &timer.snapshot());
+    assert_eq!(state.components.len(), 4);
+    assert!(matches!(state.components[1], ComponentState::Splits(_)));
+}
+
+#[test]
+fn visibility_follows_the_components_in_the_editor() {
+    let mut layout = Layout::default_layout();
+    layout.set_component_visible(1, false);
+
+    let mut editor = Editor::new(layout).unwrap();
+    editor.select(1);
+    editor.move_component_down();
+    editor.duplicate_component();
+    let layout = editor.close();
+
+    assert_eq!(
+        names(&layout),
+        ["Title", "Timer", "Splits", "Splits", "Previous Segment"]
+    );
+    let visible: Vec<_> = (0..5).map(|i| layout.is_component_visible(i)).collect();
+    assert_eq!(visible, [true, true, false, false, true]);
+}
+
+#[test]
+fn debug_string_of_default_layout_is_stable() {
+    let timer = create_timer(&["A", "B"]);
+
+    let dump = || {
+        let mut image_cache = ImageCache::new();
+        Layout::default_layout()
+            .state(&mut image_cache, &timer.snapshot())
+            .to_debug_string()
+    };
+
+    let first = dump();
+    assert_eq!(first, dump());
+    assert_eq!(
+        first,
+        concat!(
+            "Layout direction=Vertical text=#FFFFFFFF separators=#FFFFFF59 thin_separators=#FFFFFF17\n",
+            "Title Some(\"Untitled\") None attempts=Some(0) finished=None background=Vertical(#FFFFFF21,#FFFFFF00)\n",
+            "Splits background=Alternating(#00000000,#FFFFFF0A) hidden_before_last=0 final_separator=false\n",
+            "  0 \"A\" separator | \"—\" Default #FFFFFFFF | \"\" Default #FFFFFFFF\n",
+            "  1 \"B\" separator | \"—\" Default #FFFFFFFF | \"\" Default #FFFFFFFF\n",
+            "  18446744073709551614 \"\" separator blank=Padding\n",
+            "  18446744073709551612 \"\" separator blank=Padding\n",
+            "  18446744073709551610 \"\" separator blank=Padding\n",
+            "  18446744073709551608 \"\" separator blank=Padding\n",
+            "  18446744073709551606 \"\" separator blank=Padding\n",
+            "  18446744073709551604 \"\" separator blank=Padding\n",
+            "  18446744073709551602 \"\" separator blank=Padding\n",
+            "  18446744073709551600 \"\" separator blank=Padding\n",
+            "  18446744073709551598 \"\" separator blank=Padding\n",
+            "  18446744073709551596 \"\" separator blank=Padding\n",
+            "  18446744073709551594 \"\" separator blank=Padding\n",
+            "  18446744073709551592 \"\" separator blank=Padding\n",
+            "  18446744073709551590 \"\" separator blank=Padding\n",
+            "  18446744073709551588 \"\" separator blank=Padding\n",
+            "Timer \"0.00\" NotRunning top=#FFFFFFFF bottom=#898989FF height=60\n",
+            "KeyValue \"Previous Segment\"=\"—\" Default background=Vertical(#FFFFFF0F,#FFFFFF01)\n",
+        ),
+    );
+}
+
+#[test]
+fn editor_can_hide_the_selected_component() {
+    let mut editor = Editor::new(Layout::default_layout()).unwrap();
+    editor.select(2);
+    editor.set_component_visible(false);
+
+    let mut image_cache = ImageCache::new();
+    let state = editor.state(&mut image_cache);
+    assert_eq!(state.component_visibility, [true, true, false, true]);
+
+    editor.remove_component();
+    let state = editor.state(&mut image_cache);
+    assert_eq!(state.components, ["Title", "Splits", "Previous Segment"]);
+    assert_eq!(state.component_visibility, [true, true, true]);
+}
+
+#[test]
+fn merging_keeps_the_visibility_of_the_components() {
+    let mut layout = Layout::default_layout();
+    let mut other = Layout::new();
+    other.push(separator::Component::new());
+    other.push(blank_space::Component::new());
+    other.set_component_visible(0, false);
+
+    layout.merge(other, 1);
+    let visible: Vec<_> = (0..6).map(|i| layout.is_component_visible(i)).collect();
+    assert_eq!(visible, [true, false, true, true, true, true]);
+}
+
+#[test]
+fn components_added_directly_are_visible() {
+    let mut layout = Layout::default_layout();
+    layout.components.push(separator::Component::new().into());
+    assert!(layout.is_component_visible(4));
+
+    layout.set_component_visible(4, false);
+    let mut image_cache = ImageCache::new();
+    let timer = create_timer(&["A"]);
+    let state = layout.state(&mut image_cache, &timer.snapshot());
+    assert_eq!(state.components.len(), 4);
+}
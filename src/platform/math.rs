@@ -22,7 +22,7 @@ pub mod f32 {
     }
 }
 
-mod f64 {
+pub mod f64 {
     cfg_if::cfg_if! {
         if #[cfg(all(feature = "std"))] {
             #[inline(always)]
@@ -30,8 +30,20 @@ mod f64 {
             pub fn powf(x: f64, y: f64) -> f64 {
                 x.powf(y)
             }
+
+            #[inline(always)]
+            #[allow(clippy::missing_const_for_fn)] // Can't do this for the libm counterpart.
+            pub fn sqrt(x: f64) -> f64 {
+                x.sqrt()
+            }
+
+            #[inline(always)]
+            #[allow(clippy::missing_const_for_fn)] // Can't do this for the libm counterpart.
+            pub fn cbrt(x: f64) -> f64 {
+                x.cbrt()
+            }
         } else {
-            pub use libm::pow as powf;
+            pub use libm::{cbrt, pow as powf, sqrt};
         }
     }
 }
@@ -0,0 +1,397 @@
+//! Provides a renderer that emits the frame as a flat list of draw commands.
+//! This is useful for integrating livesplit-core into custom renderers, such
+//! as GPU based ones, that want to draw the individual paths, glyphs and images
+//! themselves rather than receiving a finished image.
+
+use alloc::rc::Rc;
+
+use crate::{
+    layout::LayoutState,
+    platform::prelude::*,
+    settings::{Font, GradientColorSpace, ImageCache},
+};
+
+use super::{
+    Background, Entity, FillShader, FontKind, ResourceAllocator, Rgba, SceneManager, Transform,
+    consts::SHADOW_OFFSET,
+    default_text_engine::{self, TextEngine},
+    gradient, resource,
+};
+
+/// A path consisting of a list of segments. Paths are shared between the
+/// commands of the same frame and across frames if they didn't change.
+pub type Path = Rc<[PathSegment]>;
+
+type CommandsFont = default_text_engine::Font;
+type CommandsLabel = default_text_engine::Label<Path>;
+
+/// A single segment of a [`Path`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum PathSegment {
+    /// Starts a new sub-path at the given point.
+    MoveTo([f32; 2]),
+    /// Draws a straight line to the given point.
+    LineTo([f32; 2]),
+    /// Draws a quadratic bézier curve with the given control point to the
+    /// given point.
+    QuadTo([f32; 2], [f32; 2]),
+    /// Draws a cubic bézier curve with the two given control points to the
+    /// given point.
+    CurveTo([f32; 2], [f32; 2], [f32; 2]),
+    /// Closes the current sub-path.
+    Close,
+}
+
+/// An image referenced by the draw commands. It stores the image in its
+/// original file format, so it still needs to be decoded to be drawn.
+#[derive(Debug)]
+pub struct ImageData {
+    /// The image in its original file format.
+    pub data: Box<[u8]>,
+    /// The width of the image in pixels.
+    pub width: u32,
+    /// The height of the image in pixels.
+    pub height: u32,
+}
+
+impl resource::Image for Rc<ImageData> {
+    fn aspect_ratio(&self) -> f32 {
+        self.width as f32 / self.height as f32
+    }
+}
+
+/// A single glyph of a text.
+#[derive(Clone, Debug)]
+pub struct Glyph {
+    /// The outline of the glyph.
+    pub path: Path,
+    /// The color of the glyph if it provides its own color, such as for color
+    /// emojis. Otherwise the shader of the text is to be used.
+    pub color: Option<Rgba>,
+    /// The transform that places the glyph's outline into the frame.
+    pub transform: Transform,
+}
+
+/// A single command for drawing a part of the frame. The commands are meant to
+/// be drawn in order. All the transforms map into the coordinate space
+/// defined by the dimensions the frame is rendered with, with `(0, 0)` being
+/// the top left corner. Gradients are supposed to be interpolated in the
+/// [`GradientColorSpace`] of the frame, which [`Renderer::gradient_stops`]
+/// helps with.
+#[derive(Clone, Debug)]
+pub enum DrawCommand {
+    /// Fills the whole frame with the shader.
+    Background(FillShader),
+    /// Fills the whole frame with the image. The transform maps the unit
+    /// square to the area the image is supposed to cover, which may be larger
+    /// than the frame.
+    BackgroundImage {
+        /// The image to draw.
+        image: Rc<ImageData>,
+        /// The brightness of the image in the range from `0` to `1`.
+        brightness: f32,
+        /// The opacity of the image in the range from `0` to `1`.
+        opacity: f32,
+        /// The blur to apply to the image in the range from `0` to `1`, as
+        /// described by [`BackgroundImage`](crate::settings::BackgroundImage).
+        blur: f32,
+        /// The transform that places the image into the frame.
+        transform: Transform,
+    },
+    /// Fills the inside of the path with the shader, using the non-zero fill
+    /// rule.
+    FillPath {
+        /// The path to fill.
+        path: Path,
+        /// The shader to fill the path with.
+        shader: FillShader,
+        /// The transform that places the path into the frame.
+        transform: Transform,
+    },
+    /// Draws the lines of the path with the given thickness and color.
+    StrokePath {
+        /// The path to draw.
+        path: Path,
+        /// The thickness of the lines before applying the transform.
+        stroke_width: f32,
+        /// The color of the lines.
+        color: Rgba,
+        /// The transform that places the path into the frame.
+        transform: Transform,
+    },
+    /// Draws an image. The transform maps the unit square to the area the
+    /// image is supposed to cover.
+    Image {
+        /// The image to draw.
+        image: Rc<ImageData>,
+        /// The transform that places the image into the frame.
+        transform: Transform,
+    },
+    /// Draws a run of glyphs. If there is a text shadow, the glyphs are
+    /// supposed to be drawn with the shadow color first, offset by the shadow
+    /// offset, before drawing them with the shader.
+    Text {
+        /// The glyphs of the text.
+        glyphs: Vec<Glyph>,
+        /// The shader to fill the glyphs with that don't have their own color.
+        shader: FillShader,
+        /// The color of the text shadow, if there is one.
+        shadow: Option<Rgba>,
+        /// The offset of the text shadow before applying the transform.
+        shadow_offset: [f32; 2],
+        /// The transform that places the text into the frame. The transforms
+        /// of the glyphs already include it.
+        transform: Transform,
+    },
+}
+
+/// The draw commands renderer turns layouts into flat lists of
+/// [`DrawCommands`](DrawCommand).
+pub struct Renderer {
+    allocator: CommandsAllocator,
+    scene_manager: SceneManager<Path, Rc<ImageData>, CommandsFont, CommandsLabel>,
+}
+
+impl Default for Renderer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Renderer {
+    /// Creates a new draw commands renderer.
+    pub fn new() -> Self {
+        let mut allocator = CommandsAllocator {
+            text_engine: TextEngine::new(),
+        };
+        let scene_manager = SceneManager::new(&mut allocator);
+        Self {
+            allocator,
+            scene_manager,
+        }
+    }
+
+    /// Renders the layout state with the chosen dimensions and returns the
+    /// commands for drawing the frame. The dimensions define the coordinate
+    /// space of the commands.
+    pub fn render(
+        &mut self,
+        layout_state: &LayoutState,
+        image_cache: &ImageCache,
+        [width, height]: [f32; 2],
+    ) -> Vec<DrawCommand> {
+        self.scene_manager.update_scene(
+            &mut self.allocator,
+            [width, height],
+            layout_state,
+            image_cache,
+        );
+
+        let scene = self.scene_manager.scene();
+        let mut commands = Vec::new();
+
+        if let Some(background) = scene.background() {
+            commands.push(match background {
+                Background::Shader(shader) => DrawCommand::Background(*shader),
+                Background::Image(image, transform) => DrawCommand::BackgroundImage {
+                    image: Rc::<ImageData>::clone(&image.image),
+                    brightness: image.brightness,
+                    opacity: image.opacity,
+                    blur: image.blur,
+                    transform: *transform,
+                },
+            });
+        }
+
+        for entity in scene.bottom_layer().iter().chain(scene.top_layer()) {
+            commands.push(match entity {
+                Entity::FillPath(path, shader, transform) => DrawCommand::FillPath {
+                    path: Path::clone(path),
+                    shader: *shader,
+                    transform: *transform,
+                },
+                Entity::StrokePath(path, stroke_width, color, transform) => {
+                    DrawCommand::StrokePath {
+                        path: Path::clone(path),
+                        stroke_width: *stroke_width,
+                        color: *color,
+                        transform: *transform,
+                    }
+                }
+                Entity::Image(image, transform) => DrawCommand::Image {
+                    image: Rc::<ImageData>::clone(image),
+                    transform: *transform,
+                },
+                Entity::Label(label, shader, shadow, transform) => {
+                    let label = label.read().unwrap();
+                    DrawCommand::Text {
+                        glyphs: label
+                            .glyphs()
+                            .iter()
+                            .map(|glyph| Glyph {
+                                path: Path::clone(&glyph.path),
+                                color: glyph.color,
+                                transform: transform
+                                    .pre_translate(glyph.x, glyph.y)
+                                    .pre_scale(glyph.scale, glyph.scale),
+                            })
+                            .collect(),
+                        shader: *shader,
+                        shadow: *shadow,
+                        shadow_offset: [SHADOW_OFFSET, SHADOW_OFFSET],
+                        transform: *transform,
+                    }
+                }
+            });
+        }
+
+        commands
+    }
+
+    /// Returns the color space that the gradients of the most recently
+    /// rendered frame are supposed to be interpolated in.
+    pub const fn gradient_color_space(&self) -> GradientColorSpace {
+        self.scene_manager.scene().gradient_color_space()
+    }
+
+    /// Calculates the stops of a gradient going from the start color to the
+    /// end color, such that linearly interpolating between the stops in sRGB
+    /// approximates interpolating in the [`GradientColorSpace`] of the most
+    /// recently rendered frame. Each stop consists of its offset (0 - 1) and
+    /// its color.
+    pub fn gradient_stops(&self, start: &Rgba, end: &Rgba) -> impl Iterator<Item = (f32, Rgba)> {
+        gradient::stops(start, end, self.gradient_color_space())
+    }
+}
+
+struct CommandsAllocator {
+    text_engine: TextEngine<Path>,
+}
+
+struct PathBuilder {
+    segments: Vec<PathSegment>,
+}
+
+impl super::PathBuilder for PathBuilder {
+    type Path = Path;
+
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.segments.push(PathSegment::MoveTo([x, y]));
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        self.segments.push(PathSegment::LineTo([x, y]));
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        self.segments.push(PathSegment::QuadTo([x1, y1], [x, y]));
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        self.segments
+            .push(PathSegment::CurveTo([x1, y1], [x2, y2], [x, y]));
+    }
+
+    fn close(&mut self) {
+        self.segments.push(PathSegment::Close);
+    }
+
+    fn finish(self) -> Self::Path {
+        self.segments.into()
+    }
+}
+
+impl ResourceAllocator for CommandsAllocator {
+    type PathBuilder = PathBuilder;
+    type Path = Path;
+    type Image = Rc<ImageData>;
+    type Font = CommandsFont;
+    type Label = CommandsLabel;
+
+    fn path_builder(&mut self) -> Self::PathBuilder {
+        PathBuilder {
+            segments: Vec::new(),
+        }
+    }
+
+    fn create_image(&mut self, _data: &[u8]) -> Option<Self::Image> {
+        #[cfg(feature = "image")]
+        {
+            let format = image::guess_format(_data).ok()?;
+            let (width, height) = crate::util::image::get_dimensions(format, _data)?;
+
+            Some(Rc::new(ImageData {
+                data: _data.into(),
+                width,
+                height,
+            }))
+        }
+        #[cfg(not(feature = "image"))]
+        {
+            None
+        }
+    }
+
+    fn create_font(&mut self, font: Option<&Font>, kind: FontKind) -> Self::Font {
+        self.text_engine.create_font(font, kind)
+    }
+
+    fn create_font_with_letter_spacing(
+        &mut self,
+        font: Option<&Font>,
+        kind: FontKind,
+        letter_spacing: f32,
+    ) -> Self::Font {
+        self.text_engine
+            .create_font_with_letter_spacing(font, kind, letter_spacing)
+    }
+
+    fn create_font_with_monospaced_digits(
+        &mut self,
+        font: Option<&Font>,
+        kind: FontKind,
+        letter_spacing: f32,
+        monospaced_digits: bool,
+    ) -> Self::Font {
+        self.text_engine.create_font_with_monospaced_digits(
+            font,
+            kind,
+            letter_spacing,
+            monospaced_digits,
+        )
+    }
+
+    fn create_label(
+        &mut self,
+        text: &str,
+        font: &mut Self::Font,
+        max_width: Option<f32>,
+    ) -> Self::Label {
+        self.text_engine.create_label(
+            || PathBuilder {
+                segments: Vec::new(),
+            },
+            text,
+            font,
+            max_width,
+        )
+    }
+
+    fn update_label(
+        &mut self,
+        label: &mut Self::Label,
+        text: &str,
+        font: &mut Self::Font,
+        max_width: Option<f32>,
+    ) {
+        self.text_engine.update_label(
+            || PathBuilder {
+                segments: Vec::new(),
+            },
+            label,
+            text,
+            font,
+            max_width,
+        )
+    }
+}
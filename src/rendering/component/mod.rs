@@ -112,17 +112,7 @@ pub fn height(component: &ComponentState) -> f32 {
         }
         ComponentState::Separator(_) => SEPARATOR_THICKNESS,
         ComponentState::Splits(state) => {
-            state.splits.len() as f32
-                * if state.display_two_rows {
-                    TWO_ROW_HEIGHT
-                } else {
-                    DEFAULT_COMPONENT_HEIGHT
-                }
-                + if state.column_labels.is_some() {
-                    DEFAULT_COMPONENT_HEIGHT
-                } else {
-                    0.0
-                }
+            state.splits.len() as f32 * state.row_height_units + state.header_height_units
         }
         ComponentState::Text(state) => {
             if state.display_two_rows {
@@ -1,20 +1,27 @@
 use crate::{
-    component::splits::State,
+    component::splits::{BlankReason, ColumnAlignment, SplitIndicator, State},
     layout::{LayoutDirection, LayoutState},
     platform::prelude::*,
     rendering::{
-        RenderContext,
+        FillShader, Pos, RenderContext,
         consts::{
-            BOTH_PADDINGS, DEFAULT_COMPONENT_HEIGHT, DEFAULT_TEXT_SIZE, PADDING, TEXT_ALIGN_BOTTOM,
-            TEXT_ALIGN_TOP, THIN_SEPARATOR_THICKNESS, TWO_ROW_HEIGHT, vertical_padding,
+            BOTH_PADDINGS, DEFAULT_TEXT_SIZE, PADDING, SUB_VALUE_HEIGHT, SUB_VALUE_TEXT_SIZE,
+            TEXT_ALIGN_BOTTOM, TEXT_ALIGN_CENTER, TEXT_ALIGN_TOP, THIN_SEPARATOR_THICKNESS,
+            TWO_ROW_HEIGHT, vertical_padding,
         },
         font::CachedLabel,
-        resource::ResourceAllocator,
+        resource::{PathBuilder, ResourceAllocator},
         scene::Layer,
         solid,
     },
     settings::{Gradient, ListGradient},
 };
+use core::iter;
+
+const INDICATOR_LEFT: f32 = 0.1;
+const INDICATOR_RIGHT: f32 = PADDING - 0.05;
+const INDICATOR_HALF_HEIGHT: f32 = 0.125;
+const GHOST_OPACITY: f32 = 0.5;
 
 pub struct Cache<L> {
     splits: Vec<SplitCache<L>>,
@@ -62,6 +69,7 @@ unsafe impl Sync for ShortLivedStr {}
 struct SplitCache<L> {
     name: CachedLabel<L>,
     columns: Vec<CachedLabel<L>>,
+    sub_values: Vec<CachedLabel<L>>,
 }
 
 impl<L> SplitCache<L> {
@@ -69,6 +77,7 @@ impl<L> SplitCache<L> {
         Self {
             name: CachedLabel::new(),
             columns: Vec::new(),
+            sub_values: Vec::new(),
         }
     }
 }
@@ -107,9 +116,11 @@ pub(in crate::rendering) fn render<A: ResourceAllocator>(
         for (column, longest_column_value) in
             split.columns.iter().zip(&mut cache.longest_column_values)
         {
-            let column_value = ShortLivedStr::new(column.value.as_str());
-            if column_value.char_count > longest_column_value.char_count {
-                *longest_column_value = column_value;
+            for value in iter::once(&column.value).chain(&column.sub_value) {
+                let column_value = ShortLivedStr::new(value.as_str());
+                if column_value.char_count > longest_column_value.char_count {
+                    *longest_column_value = column_value;
+                }
             }
         }
     }
@@ -143,6 +154,18 @@ pub(in crate::rendering) fn render<A: ResourceAllocator>(
         }
     }
 
+    for split in &component.splits {
+        for (column, (column_width, _)) in split.columns.iter().zip(&mut cache.column_width_labels)
+        {
+            if let Some(min_width) = column.min_width {
+                *column_width = column_width.max(min_width);
+            }
+            if let Some(max_width) = column.max_width {
+                *column_width = column_width.min(max_width);
+            }
+        }
+    }
+
     let split_background = match component.background {
         ListGradient::Same(gradient) => {
             context.render_rectangle([0.0, 0.0], [width, height], &gradient);
@@ -154,14 +177,25 @@ pub(in crate::rendering) fn render<A: ResourceAllocator>(
     let display_two_rows =
         component.display_two_rows || layout_state.direction == LayoutDirection::Horizontal;
 
-    let split_height = if display_two_rows {
+    let split_height = if layout_state.direction == LayoutDirection::Horizontal {
         TWO_ROW_HEIGHT
     } else {
-        DEFAULT_COMPONENT_HEIGHT
+        component.row_height_units
     };
 
     let vertical_padding = vertical_padding(split_height);
 
+    // There's no room for the sub values when the splits are laid out
+    // horizontally, as the rows have a fixed height then.
+    let show_sub_values =
+        component.has_sub_values && layout_state.direction == LayoutDirection::Vertical;
+    let sub_value_y = split_height + TEXT_ALIGN_BOTTOM;
+    let value_y = if show_sub_values {
+        sub_value_y - SUB_VALUE_HEIGHT
+    } else {
+        sub_value_y
+    };
+
     let (split_width, (delta_x, delta_y), separator_pos, split_background_bottom_right, icon_y) =
         if layout_state.direction == LayoutDirection::Horizontal {
             let split_width = width / component.splits.len() as f32;
@@ -192,11 +226,17 @@ pub(in crate::rendering) fn render<A: ResourceAllocator>(
                 .resize_with(column_labels.len(), CachedLabel::new);
 
             let mut right_x = width - PADDING;
-            for ((label, column_cache), (max_width, _)) in column_labels
+            for (i, ((label, column_cache), (max_width, _))) in column_labels
                 .iter()
                 .zip(&mut cache.column_labels)
                 .zip(&mut cache.column_width_labels)
+                .enumerate()
             {
+                let width_limit = component
+                    .splits
+                    .iter()
+                    .find_map(|split| split.columns.get(i))
+                    .and_then(|column| column.max_width);
                 let left_x = context.render_text_right_align(
                     label,
                     column_cache,
@@ -204,6 +244,7 @@ pub(in crate::rendering) fn render<A: ResourceAllocator>(
                     [right_x, TEXT_ALIGN_TOP],
                     DEFAULT_TEXT_SIZE,
                     text_color,
+                    width_limit,
                 );
                 let label_width = right_x - left_x;
                 if label_width > *max_width {
@@ -212,7 +253,7 @@ pub(in crate::rendering) fn render<A: ResourceAllocator>(
                 right_x -= *max_width + PADDING;
             }
 
-            context.translate(0.0, DEFAULT_COMPONENT_HEIGHT);
+            context.translate(0.0, component.header_height_units);
             context.render_rectangle(
                 [0.0, -THIN_SEPARATOR_THICKNESS],
                 [width, THIN_SEPARATOR_THICKNESS],
@@ -233,7 +274,7 @@ pub(in crate::rendering) fn render<A: ResourceAllocator>(
         .resize_with(component.splits.len(), SplitCache::new);
 
     for (i, (split, split_cache)) in component.splits.iter().zip(&mut cache.splits).enumerate() {
-        if component.show_thin_separators && i + 1 != component.splits.len() {
+        if split.show_separator_after && i + 1 != component.splits.len() {
             context.render_rectangle(
                 separator_pos,
                 [split_width, split_height],
@@ -246,6 +287,8 @@ pub(in crate::rendering) fn render<A: ResourceAllocator>(
                 [split_width, split_height],
                 &component.current_split_gradient,
             );
+        } else if let Some(background) = split.background {
+            context.render_background(split_background_bottom_right, &Gradient::Plain(background));
         } else if let Some((even, odd)) = &split_background {
             let color = if split.index % 2 == 0 { even } else { odd };
             context.render_background(split_background_bottom_right, color);
@@ -262,23 +305,73 @@ pub(in crate::rendering) fn render<A: ResourceAllocator>(
             split_cache
                 .columns
                 .resize_with(split.columns.len(), CachedLabel::new);
+            split_cache
+                .sub_values
+                .resize_with(split.columns.len(), CachedLabel::new);
 
-            for ((column, column_cache), (max_width, _)) in split
+            for (((column, column_cache), sub_value_cache), (max_width, _)) in split
                 .columns
                 .iter()
                 .zip(&mut split_cache.columns)
+                .zip(&mut split_cache.sub_values)
                 .zip(&cache.column_width_labels)
             {
+                let layer = Layer::from_updates_frequently(column.updates_frequently);
+
+                let width_limit = column.max_width.map(|_| *max_width);
+
                 if !column.value.is_empty() {
-                    left_x = context.render_numbers(
+                    let end_x = aligned_end_x(
+                        context,
+                        &column.value,
+                        column_cache,
+                        DEFAULT_TEXT_SIZE,
+                        column.alignment,
+                        right_x,
+                        *max_width,
+                        width_limit,
+                    );
+                    let mut color = column.visual_color;
+                    if column.is_ghost {
+                        color.alpha *= GHOST_OPACITY;
+                    }
+                    left_x = render_value(
+                        context,
                         &column.value,
                         column_cache,
-                        Layer::from_updates_frequently(column.updates_frequently),
-                        [right_x, split_height + TEXT_ALIGN_BOTTOM],
+                        layer,
+                        [end_x, value_y],
                         DEFAULT_TEXT_SIZE,
-                        solid(&column.visual_color),
+                        solid(&color),
+                        width_limit,
                     );
                 }
+
+                if show_sub_values {
+                    if let Some(sub_value) = &column.sub_value {
+                        let end_x = aligned_end_x(
+                            context,
+                            sub_value,
+                            sub_value_cache,
+                            SUB_VALUE_TEXT_SIZE,
+                            column.alignment,
+                            right_x,
+                            *max_width,
+                            width_limit,
+                        );
+                        left_x = left_x.min(render_value(
+                            context,
+                            sub_value,
+                            sub_value_cache,
+                            layer,
+                            [end_x, sub_value_y],
+                            SUB_VALUE_TEXT_SIZE,
+                            text_color,
+                            width_limit,
+                        ));
+                    }
+                }
+
                 right_x -= max_width + PADDING;
             }
 
@@ -286,8 +379,25 @@ pub(in crate::rendering) fn render<A: ResourceAllocator>(
                 left_x = split_width;
             }
 
+            if split.indicator == SplitIndicator::Arrow {
+                let center_y = TEXT_ALIGN_TOP - TEXT_ALIGN_CENTER;
+                let mut builder = context.handles.path_builder();
+                builder.move_to(INDICATOR_LEFT, center_y - INDICATOR_HALF_HEIGHT);
+                builder.line_to(INDICATOR_RIGHT, center_y);
+                builder.line_to(INDICATOR_LEFT, center_y + INDICATOR_HALF_HEIGHT);
+                builder.close();
+                let path = builder.finish();
+                context.fill_path(path, layout_state.text_color, Layer::Bottom);
+            }
+
+            let name = if split.blank_reason == Some(BlankReason::Gap) {
+                "…"
+            } else {
+                &split.name
+            };
+
             context.render_text_ellipsis(
-                &split.name,
+                name,
                 &mut split_cache.name,
                 [icon_right, TEXT_ALIGN_TOP],
                 DEFAULT_TEXT_SIZE,
@@ -315,3 +425,50 @@ pub(in crate::rendering) fn render<A: ResourceAllocator>(
 
     context.transform = transform;
 }
+
+fn aligned_end_x<A: ResourceAllocator>(
+    context: &mut RenderContext<'_, A>,
+    text: &str,
+    label: &mut CachedLabel<A::Label>,
+    scale: f32,
+    alignment: ColumnAlignment,
+    right_x: f32,
+    max_width: f32,
+    width_limit: Option<f32>,
+) -> f32 {
+    match alignment {
+        ColumnAlignment::End => right_x,
+        alignment => {
+            let width = match width_limit {
+                Some(width_limit) => {
+                    context.measure_numbers_ellipsis(text, label, scale, width_limit)
+                }
+                None => context.measure_numbers(text, label, scale),
+            };
+            let remaining = (max_width - width).max(0.0);
+            if alignment == ColumnAlignment::Start {
+                right_x - remaining
+            } else {
+                right_x - 0.5 * remaining
+            }
+        }
+    }
+}
+
+fn render_value<A: ResourceAllocator>(
+    context: &mut RenderContext<'_, A>,
+    text: &str,
+    label: &mut CachedLabel<A::Label>,
+    layer: Layer,
+    pos: Pos,
+    scale: f32,
+    shader: FillShader,
+    width_limit: Option<f32>,
+) -> f32 {
+    match width_limit {
+        Some(width_limit) => {
+            context.render_numbers_ellipsis(text, label, layer, pos, scale, shader, width_limit)
+        }
+        None => context.render_numbers(text, label, layer, pos, scale, shader),
+    }
+}
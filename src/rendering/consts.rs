@@ -1,14 +1,16 @@
+use crate::layout::metrics::{
+    BOTH_VERTICAL_PADDINGS, DEFAULT_TEXT_ASCENT, DEFAULT_TEXT_ASCENT_DESCENT_DISTANCE,
+    DEFAULT_TEXT_DESCENT, SUB_VALUE_TEXT_SCALE,
+};
+
+pub use crate::layout::metrics::{DEFAULT_COMPONENT_HEIGHT, SUB_VALUE_HEIGHT, TWO_ROW_HEIGHT};
+
 pub const PADDING: f32 = 0.35;
 pub const BOTH_PADDINGS: f32 = 2.0 * PADDING;
-const BOTH_VERTICAL_PADDINGS: f32 = DEFAULT_COMPONENT_HEIGHT - DEFAULT_TEXT_ASCENT_DESCENT_DISTANCE;
 const VERTICAL_PADDING: f32 = BOTH_VERTICAL_PADDINGS / 2.0;
 const ICON_MIN_VERTICAL_PADDING: f32 = 0.1;
-pub const DEFAULT_COMPONENT_HEIGHT: f32 = 1.0;
-pub const TWO_ROW_HEIGHT: f32 = 2.0 * DEFAULT_TEXT_ASCENT_DESCENT_DISTANCE + BOTH_VERTICAL_PADDINGS;
 pub const DEFAULT_TEXT_SIZE: f32 = 0.6;
-const DEFAULT_TEXT_ASCENT: f32 = 0.55;
-const DEFAULT_TEXT_DESCENT: f32 = 0.175;
-const DEFAULT_TEXT_ASCENT_DESCENT_DISTANCE: f32 = DEFAULT_TEXT_ASCENT + DEFAULT_TEXT_DESCENT;
+pub const SUB_VALUE_TEXT_SIZE: f32 = SUB_VALUE_TEXT_SCALE * DEFAULT_TEXT_SIZE;
 pub const TEXT_ALIGN_TOP: f32 = VERTICAL_PADDING + DEFAULT_TEXT_ASCENT;
 pub const TEXT_ALIGN_BOTTOM: f32 = -(VERTICAL_PADDING + DEFAULT_TEXT_DESCENT);
 pub const TEXT_ALIGN_CENTER: f32 = DEFAULT_TEXT_ASCENT - DEFAULT_TEXT_ASCENT_DESCENT_DISTANCE / 2.0;
@@ -65,6 +65,33 @@ impl<P: SharedOwnership> TextEngine<P> {
     /// Creates a new font. You can call this directly from a
     /// [`ResourceAllocator`](super::ResourceAllocator).
     pub fn create_font(&mut self, font: Option<&settings::Font>, kind: FontKind) -> Font {
+        self.create_font_with_letter_spacing(font, kind, 0.0)
+    }
+
+    /// Creates a new font with additional space after each glyph, specified
+    /// relative to the font size. You can call this directly from a
+    /// [`ResourceAllocator`](super::ResourceAllocator).
+    pub fn create_font_with_letter_spacing(
+        &mut self,
+        font: Option<&settings::Font>,
+        kind: FontKind,
+        letter_spacing: f32,
+    ) -> Font {
+        self.create_font_with_monospaced_digits(font, kind, letter_spacing, false)
+    }
+
+    /// Creates a new font with additional space after each glyph, specified
+    /// relative to the font size, and optionally with monospaced digits, even
+    /// if the kind of font isn't monospaced on its own. You can call this
+    /// directly from a [`ResourceAllocator`](super::ResourceAllocator).
+    pub fn create_font_with_monospaced_digits(
+        &mut self,
+        font: Option<&settings::Font>,
+        kind: FontKind,
+        letter_spacing: f32,
+        monospaced_digits: bool,
+    ) -> Font {
+        let is_monospaced = monospaced_digits || kind.is_monospaced();
         let fallback_family = &match kind {
             FontKind::Timer => "LiveSplit Timer",
             _ => "Fira Sans",
@@ -132,7 +159,7 @@ impl<P: SharedOwnership> TextEngine<P> {
             .style(style)
             .weight(weight);
 
-        if kind.is_monospaced() {
+        if is_monospaced {
             let mut features = FontFeatures::new();
             features.disable(FeatureTag::KERNING);
             features.disable(FeatureTag::STANDARD_LIGATURES);
@@ -143,7 +170,7 @@ impl<P: SharedOwnership> TextEngine<P> {
 
         let attrs_list = AttrsList::new(&attrs);
 
-        let monotonic = kind.is_monospaced().then(|| {
+        let monotonic = is_monospaced.then(|| {
             let mut digit_glyphs = [(ID::dummy(), 0); 10];
             let mut digit_width = 0.0;
             for (digit, glyph) in digit_glyphs.iter_mut().enumerate() {
@@ -170,6 +197,7 @@ impl<P: SharedOwnership> TextEngine<P> {
 
         Font {
             attrs_list,
+            letter_spacing,
             monotonic,
             ellipsis_font_id,
             ellipsis_glyph_id,
@@ -238,6 +266,7 @@ impl<P: SharedOwnership> TextEngine<P> {
             4,
         );
         let [mut x, mut y] = [0.0; 2];
+        let letter_spacing = font.letter_spacing;
 
         if let Some(monotonic) = &font.monotonic {
             for span in &shape_line.spans {
@@ -280,11 +309,11 @@ impl<P: SharedOwnership> TextEngine<P> {
                                     scale: cached_glyph.scale,
                                 }));
 
-                            x += x_advance;
+                            x += x_advance + letter_spacing;
                             y -= glyph.y_advance;
                         }
                     } else {
-                        x += word.width(1.0);
+                        x += word.width(1.0) + letter_spacing * word.glyphs.len() as f32;
                     }
                 }
             }
@@ -317,11 +346,11 @@ impl<P: SharedOwnership> TextEngine<P> {
                                     scale: cached_glyph.scale,
                                 }));
 
-                            glyph_x += glyph.x_advance;
+                            glyph_x += glyph.x_advance + letter_spacing;
                             glyph_y -= glyph.y_advance;
                         }
                     }
-                    x += word.width(1.0);
+                    x += word.width(1.0) + letter_spacing * word.glyphs.len() as f32;
                 }
             }
         }
@@ -404,6 +433,7 @@ struct MonotonicInfo {
 /// The font to use in the [`ResourceAllocator`](super::ResourceAllocator).
 pub struct Font {
     attrs_list: AttrsList,
+    letter_spacing: f32,
     monotonic: Option<MonotonicInfo>,
     ellipsis_font_id: ID,
     ellipsis_glyph_id: u16,
@@ -6,6 +6,8 @@ use crate::{layout::LayoutState, settings::Font};
 
 pub struct CachedFont<F> {
     setting: Option<Font>,
+    letter_spacing: f32,
+    monospaced_digits: bool,
     pub font: Handle<F>,
 }
 
@@ -13,6 +15,8 @@ impl<F> CachedFont<F> {
     const fn new(font: Handle<F>) -> Self {
         Self {
             setting: None,
+            letter_spacing: 0.0,
+            monospaced_digits: false,
             font,
         }
     }
@@ -22,10 +26,22 @@ impl<F> CachedFont<F> {
         allocator: &mut impl ResourceAllocator<Font = Handle<F>>,
         font_to_use: &Option<Font>,
         font_kind: FontKind,
+        letter_spacing: f32,
+        monospaced_digits: bool,
     ) {
-        if &self.setting != font_to_use {
-            self.font = allocator.create_font(font_to_use.as_ref(), font_kind);
+        if &self.setting != font_to_use
+            || self.letter_spacing != letter_spacing
+            || self.monospaced_digits != monospaced_digits
+        {
+            self.font = allocator.create_font_with_monospaced_digits(
+                font_to_use.as_ref(),
+                font_kind,
+                letter_spacing,
+                monospaced_digits,
+            );
             self.setting.clone_from(font_to_use);
+            self.letter_spacing = letter_spacing;
+            self.monospaced_digits = monospaced_digits;
         }
     }
 }
@@ -50,11 +66,27 @@ impl<F> FontCache<F> {
         allocator: &mut impl ResourceAllocator<Font = Handle<F>>,
         state: &LayoutState,
     ) {
-        self.timer
-            .maybe_reload(allocator, &state.timer_font, FontKind::Timer);
-        self.times
-            .maybe_reload(allocator, &state.times_font, FontKind::Times);
-        self.text
-            .maybe_reload(allocator, &state.text_font, FontKind::Text);
+        let letter_spacing = state.letter_spacing;
+        self.timer.maybe_reload(
+            allocator,
+            &state.timer_font,
+            FontKind::Timer,
+            letter_spacing,
+            false,
+        );
+        self.times.maybe_reload(
+            allocator,
+            &state.times_font,
+            FontKind::Times,
+            letter_spacing,
+            false,
+        );
+        self.text.maybe_reload(
+            allocator,
+            &state.text_font,
+            FontKind::Text,
+            letter_spacing,
+            state.monospace_text_digits,
+        );
     }
 }
@@ -0,0 +1,112 @@
+use super::Rgba;
+use crate::{
+    platform::math::f64::{cbrt, powf},
+    settings::GradientColorSpace,
+};
+
+/// The number of segments a gradient that isn't interpolated in sRGB gets
+/// split into. The renderers interpolate between the stops in sRGB, so the
+/// gradient is approximated piecewise.
+const SEGMENTS: u16 = 16;
+
+/// Calculates the stops of a gradient going from the start color to the end
+/// color, such that interpolating between the stops in sRGB approximates
+/// interpolating between the colors in the color space provided. Each stop
+/// consists of its offset (0 - 1) and its color.
+pub fn stops(
+    start: &Rgba,
+    end: &Rgba,
+    color_space: GradientColorSpace,
+) -> impl Iterator<Item = (f32, Rgba)> {
+    let (start, end) = (*start, *end);
+    let segments = match color_space {
+        GradientColorSpace::Srgb => 1,
+        GradientColorSpace::Linear | GradientColorSpace::Oklab => SEGMENTS,
+    };
+
+    (0..=segments).map(move |i| {
+        if i == 0 {
+            (0.0, start)
+        } else if i == segments {
+            (1.0, end)
+        } else {
+            let t = f32::from(i) / f32::from(segments);
+            (t, interpolate(&start, &end, t, color_space))
+        }
+    })
+}
+
+fn interpolate(start: &Rgba, end: &Rgba, t: f32, color_space: GradientColorSpace) -> Rgba {
+    let t = t as f64;
+    let lerp = |a: f64, b: f64| a + (b - a) * t;
+    if color_space == GradientColorSpace::Srgb {
+        return [0, 1, 2, 3].map(|i| lerp(start[i] as f64, end[i] as f64) as f32);
+    }
+
+    let alpha = lerp(start[3] as f64, end[3] as f64) as f32;
+
+    let [start, end] = [start, end].map(linear_rgb);
+    let [r, g, b] = match color_space {
+        GradientColorSpace::Oklab => {
+            let [start, end] = [start, end].map(linear_rgb_to_oklab);
+            oklab_to_linear_rgb([0, 1, 2].map(|i| lerp(start[i], end[i])))
+        }
+        GradientColorSpace::Srgb | GradientColorSpace::Linear => {
+            [0, 1, 2].map(|i| lerp(start[i], end[i]))
+        }
+    };
+
+    [
+        linear_to_srgb(r),
+        linear_to_srgb(g),
+        linear_to_srgb(b),
+        alpha,
+    ]
+}
+
+fn linear_rgb(&[r, g, b, _]: &Rgba) -> [f64; 3] {
+    [r, g, b].map(|c| srgb_to_linear(c as f64))
+}
+
+fn srgb_to_linear(c: f64) -> f64 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        powf((c + 0.055) / 1.055, 2.4)
+    }
+}
+
+fn linear_to_srgb(c: f64) -> f32 {
+    let c = if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * powf(c, 1.0 / 2.4) - 0.055
+    };
+    c.clamp(0.0, 1.0) as f32
+}
+
+fn linear_rgb_to_oklab([r, g, b]: [f64; 3]) -> [f64; 3] {
+    let l = cbrt(0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b);
+    let m = cbrt(0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b);
+    let s = cbrt(0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b);
+
+    [
+        0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+        1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+        0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s,
+    ]
+}
+
+fn oklab_to_linear_rgb([l, a, b]: [f64; 3]) -> [f64; 3] {
+    let l_ = l + 0.3963377774 * a + 0.2158037573 * b;
+    let m_ = l - 0.1055613458 * a - 0.0638541728 * b;
+    let s_ = l - 0.0894841775 * a - 1.2914855480 * b;
+
+    let (l, m, s) = (l_ * l_ * l_, m_ * m_ * m_, s_ * s_ * s_);
+
+    [
+        4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s,
+        -1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s,
+        -0.0041960863 * l - 0.7034186147 * m + 1.7076926193 * s,
+    ]
+}
@@ -74,10 +74,13 @@ mod component;
 mod consts;
 mod entity;
 mod font;
+mod gradient;
 mod icon;
 mod resource;
 mod scene;
 
+#[cfg(feature = "default-text-engine")]
+pub mod commands;
 #[cfg(feature = "default-text-engine")]
 pub mod default_text_engine;
 
@@ -124,7 +127,7 @@ pub type Rgba = [f32; 4];
 
 /// A transformation to apply to the entities in order to place them into the
 /// scene.
-#[derive(Copy, Clone, Pod, Zeroable)]
+#[derive(Copy, Clone, Debug, Pod, Zeroable)]
 #[repr(C)]
 pub struct Transform {
     /// Scale the x coordinate by this value.
@@ -138,7 +141,7 @@ pub struct Transform {
 }
 
 /// Specifies the colors to use when filling a path.
-#[derive(Copy, Clone, PartialEq)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub enum FillShader {
     /// Use a single color for the whole path.
     SolidColor(Rgba),
@@ -176,6 +179,7 @@ pub struct SceneManager<P, I, F, L> {
     components: Vec<component::Cache<L>>,
     next_id: usize,
     cached_size: Option<CachedSize>,
+    layout_transform: Transform,
     fonts: FontCache<F>,
     images: ImageCache<CachedImage<I>>,
 }
@@ -194,6 +198,7 @@ impl<P: SharedOwnership, I: SharedOwnership, F, L: SharedOwnership> SceneManager
             next_id: handles.into_next_id(),
             scene: Scene::new(rectangle),
             cached_size: None,
+            layout_transform: Transform::scale(1.0, 1.0),
             fonts,
             images: ImageCache::new(),
         }
@@ -204,6 +209,17 @@ impl<P: SharedOwnership, I: SharedOwnership, F, L: SharedOwnership> SceneManager
         &self.scene
     }
 
+    /// Returns the transform that maps the coordinates of the layout to the
+    /// pixel coordinates of the resolution the [`Scene`] was last updated
+    /// with. The layout coordinates are in the same units as the sizes of the
+    /// components, where the first component starts at the origin. A point in
+    /// layout coordinates is mapped to the pixel at `x + scale_x * point_x` and
+    /// `y + scale_y * point_y`. This can be used to map a position in the
+    /// rendered image, such as a click, back to the component at that position.
+    pub const fn layout_transform(&self) -> Transform {
+        self.layout_transform
+    }
+
     /// Updates the [`Scene`] by updating the [`Entities`](Entity) according to
     /// the [`LayoutState`] provided. The [`ResourceAllocator`] is used to
     /// allocate the resources necessary that the [`Entities`](Entity) use. A
@@ -238,6 +254,8 @@ impl<P: SharedOwnership, I: SharedOwnership, F, L: SharedOwnership> SceneManager
             }
         };
 
+        self.scene
+            .set_gradient_color_space(state.gradient_color_space);
         self.scene.recalculate_if_bottom_layer_changed();
 
         self.images.collect();
@@ -300,6 +318,7 @@ impl<P: SharedOwnership, I: SharedOwnership, F, L: SharedOwnership> SceneManager
         // scaling as the Component Coordinate Space. This also already is the
         // Component Coordinate Space for the component at (0, 0).
         context.scale(total_height.recip());
+        self.layout_transform = context.transform;
 
         // Calculate the width of the components in component space. In vertical
         // mode, all the components have the same width.
@@ -376,6 +395,7 @@ impl<P: SharedOwnership, I: SharedOwnership, F, L: SharedOwnership> SceneManager
         // components use the two row height as their height, we scale by the
         // reciprocal of that.
         context.scale(TWO_ROW_HEIGHT.recip());
+        self.layout_transform = context.transform;
 
         // We don't take the component width we calculate. Instead we use the
         // component width as a ratio of how much of the total actual width to
@@ -714,8 +734,14 @@ impl<A: ResourceAllocator> RenderContext<'_, A> {
         pos @ [x, _]: Pos,
         scale: f32,
         shader: FillShader,
+        max_width: Option<f32>,
     ) -> f32 {
-        let label = label.update(text, &mut self.handles, &mut self.fonts.text.font, None);
+        let label = label.update(
+            text,
+            &mut self.handles,
+            &mut self.fonts.text.font,
+            max_width.map(|max_width| max_width / scale),
+        );
         let width = label.width(scale);
 
         self.scene.layer_mut(layer).push(Entity::Label(
@@ -776,6 +802,34 @@ impl<A: ResourceAllocator> RenderContext<'_, A> {
         x - width
     }
 
+    fn render_numbers_ellipsis(
+        &mut self,
+        text: &str,
+        label: &mut CachedLabel<A::Label>,
+        layer: Layer,
+        pos @ [x, _]: Pos,
+        scale: f32,
+        shader: FillShader,
+        max_width: f32,
+    ) -> f32 {
+        let label = label.update(
+            text,
+            &mut self.handles,
+            &mut self.fonts.times.font,
+            Some(max_width / scale),
+        );
+        let width = label.width(scale);
+
+        self.scene.layer_mut(layer).push(Entity::Label(
+            label.share(),
+            shader,
+            self.state.text_shadow.as_ref().map(Color::to_array),
+            font::right_aligned(&self.transform, pos, scale, width),
+        ));
+
+        x - width
+    }
+
     fn render_timer(
         &mut self,
         text: &str,
@@ -808,6 +862,22 @@ impl<A: ResourceAllocator> RenderContext<'_, A> {
         label.width(scale)
     }
 
+    fn measure_numbers_ellipsis(
+        &mut self,
+        text: &str,
+        label: &mut CachedLabel<A::Label>,
+        scale: f32,
+        max_width: f32,
+    ) -> f32 {
+        let label = label.update(
+            text,
+            &mut self.handles,
+            &mut self.fonts.times.font,
+            Some(max_width / scale),
+        );
+        label.width(scale)
+    }
+
     fn decode_layout_background(
         &mut self,
         background: &LayoutBackground<ImageId>,
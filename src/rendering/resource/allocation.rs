@@ -101,6 +101,42 @@ pub trait ResourceAllocator {
     /// [`TIMER_FONT`](super::super::TIMER_FONT).
     fn create_font(&mut self, font: Option<&Font>, kind: FontKind) -> Self::Font;
 
+    /// Creates a font just like [`create_font`](Self::create_font), but with
+    /// additional letter spacing. The letter spacing is additional space to
+    /// add after each glyph, specified relative to the font size. It may be
+    /// negative in order to move the glyphs closer together. A letter spacing
+    /// of 0 means the glyphs are laid out as usual. By default the letter
+    /// spacing is ignored and the font is created with
+    /// [`create_font`](Self::create_font).
+    fn create_font_with_letter_spacing(
+        &mut self,
+        font: Option<&Font>,
+        kind: FontKind,
+        letter_spacing: f32,
+    ) -> Self::Font {
+        let _ = letter_spacing;
+        self.create_font(font, kind)
+    }
+
+    /// Creates a font just like
+    /// [`create_font_with_letter_spacing`](Self::create_font_with_letter_spacing),
+    /// but optionally with monospaced digits. If `monospaced_digits` is
+    /// `true`, the digits of the font are supposed to be monospaced, even if
+    /// the [`FontKind`] isn't meant to be monospaced on its own. This prevents
+    /// numbers in regular text from shifting around as they change. By default
+    /// this is ignored and the font is created with
+    /// [`create_font_with_letter_spacing`](Self::create_font_with_letter_spacing).
+    fn create_font_with_monospaced_digits(
+        &mut self,
+        font: Option<&Font>,
+        kind: FontKind,
+        letter_spacing: f32,
+        monospaced_digits: bool,
+    ) -> Self::Font {
+        let _ = monospaced_digits;
+        self.create_font_with_letter_spacing(font, kind, letter_spacing)
+    }
+
     /// Creates a new text label with the text and font provided. An optional
     /// maximum width is provided as well. If the width of the text measured at
     /// a size of 1 is greater than the maximum width, then it is expected to be
@@ -232,6 +268,25 @@ impl<A: ResourceAllocator> ResourceAllocator for &mut A {
         (*self).create_font(font, kind)
     }
 
+    fn create_font_with_letter_spacing(
+        &mut self,
+        font: Option<&Font>,
+        kind: FontKind,
+        letter_spacing: f32,
+    ) -> Self::Font {
+        (*self).create_font_with_letter_spacing(font, kind, letter_spacing)
+    }
+
+    fn create_font_with_monospaced_digits(
+        &mut self,
+        font: Option<&Font>,
+        kind: FontKind,
+        letter_spacing: f32,
+        monospaced_digits: bool,
+    ) -> Self::Font {
+        (*self).create_font_with_monospaced_digits(font, kind, letter_spacing, monospaced_digits)
+    }
+
     fn create_label(
         &mut self,
         text: &str,
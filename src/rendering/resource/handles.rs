@@ -92,6 +92,34 @@ impl<A: ResourceAllocator> ResourceAllocator for Handles<A> {
         self.next(font)
     }
 
+    fn create_font_with_letter_spacing(
+        &mut self,
+        font: Option<&Font>,
+        kind: super::FontKind,
+        letter_spacing: f32,
+    ) -> Self::Font {
+        let font = self
+            .allocator
+            .create_font_with_letter_spacing(font, kind, letter_spacing);
+        self.next(font)
+    }
+
+    fn create_font_with_monospaced_digits(
+        &mut self,
+        font: Option<&Font>,
+        kind: super::FontKind,
+        letter_spacing: f32,
+        monospaced_digits: bool,
+    ) -> Self::Font {
+        let font = self.allocator.create_font_with_monospaced_digits(
+            font,
+            kind,
+            letter_spacing,
+            monospaced_digits,
+        );
+        self.next(font)
+    }
+
     fn create_label(
         &mut self,
         text: &str,
@@ -3,7 +3,7 @@ use super::{
     resource::{Handle, SharedOwnership},
     Background,
 };
-use crate::platform::prelude::*;
+use crate::{platform::prelude::*, settings::GradientColorSpace};
 
 /// Describes a layer of a [`Scene`] to place an [`Entity`] on.
 #[derive(Copy, Clone, PartialEq, Eq)]
@@ -38,6 +38,8 @@ impl Layer {
 pub struct Scene<P, I, L> {
     rectangle: Handle<P>,
     background: Option<Background<I>>,
+    gradient_color_space: GradientColorSpace,
+    gradient_color_space_changed: bool,
     bottom_hash: u64,
     bottom_layer_changed: bool,
     bottom_layer: Vec<Entity<P, I, L>>,
@@ -51,6 +53,8 @@ impl<P: SharedOwnership, I: SharedOwnership, L: SharedOwnership> Scene<P, I, L>
         Self {
             rectangle,
             background: None,
+            gradient_color_space: GradientColorSpace::Srgb,
+            gradient_color_space_changed: false,
             bottom_hash: calculate_hash::<P, I, L>(&None, &[]),
             bottom_layer_changed: false,
             bottom_layer: Vec::new(),
@@ -65,6 +69,11 @@ impl<P: SharedOwnership, I: SharedOwnership, L: SharedOwnership> Scene<P, I, L>
         &self.background
     }
 
+    /// Get the color space that gradients are supposed to be interpolated in.
+    pub const fn gradient_color_space(&self) -> GradientColorSpace {
+        self.gradient_color_space
+    }
+
     /// Check if the scene's bottom [`Layer`] changed. Use this method to check
     /// if the bottom [`Layer`] needs to be rerendered. If the background of the
     /// bottom [`Layer`] changes this also returns `true`, so the background
@@ -95,6 +104,15 @@ impl<P: SharedOwnership, I: SharedOwnership, L: SharedOwnership> Scene<P, I, L>
         self.background = background;
     }
 
+    /// Set the color space that gradients are supposed to be interpolated in.
+    /// Changing it causes the bottom [`Layer`] to be considered changed.
+    pub fn set_gradient_color_space(&mut self, color_space: GradientColorSpace) {
+        if self.gradient_color_space != color_space {
+            self.gradient_color_space = color_space;
+            self.gradient_color_space_changed = true;
+        }
+    }
+
     /// Get a mutable reference to the scene's bottom [`Layer`].
     pub fn bottom_layer_mut(&mut self) -> &mut Vec<Entity<P, I, L>> {
         &mut self.bottom_layer
@@ -117,7 +135,8 @@ impl<P: SharedOwnership, I: SharedOwnership, L: SharedOwnership> Scene<P, I, L>
     /// doesn't need to be rerendered all the time.
     pub fn recalculate_if_bottom_layer_changed(&mut self) {
         let new_hash = calculate_hash(&self.background, &self.bottom_layer);
-        self.bottom_layer_changed = new_hash != self.bottom_hash;
+        self.bottom_layer_changed =
+            new_hash != self.bottom_hash || core::mem::take(&mut self.gradient_color_space_changed);
         self.bottom_hash = new_hash;
     }
 
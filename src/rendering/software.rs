@@ -2,21 +2,26 @@
 //! surprisingly fast and can be considered the default rendering backend.
 
 use super::{
-    FillShader, FontKind, Scene, SceneManager, SharedOwnership, Transform,
+    FillShader, FontKind, Rgba, Scene, SceneManager, SharedOwnership, Transform,
     consts::SHADOW_OFFSET,
     default_text_engine::{Font, Label, TextEngine},
     entity::Entity,
+    gradient,
     resource::{self, ResourceAllocator},
 };
 use crate::{
-    layout::LayoutState, platform::prelude::*, rendering::Background, settings,
-    settings::ImageCache,
+    layout::LayoutState,
+    platform::prelude::*,
+    rendering::Background,
+    settings,
+    settings::{GradientColorSpace, ImageCache},
 };
 use alloc::rc::Rc;
 use core::{mem, ops::Deref};
 use tiny_skia::{
     BlendMode, Color, FillRule, FilterQuality, GradientStop, LinearGradient, Paint, Path,
-    PathBuilder, Pattern, Pixmap, PixmapMut, Point, Rect, Shader, SpreadMode, Stroke,
+    PathBuilder, Pattern, Pixmap, PixmapMut, Point, PremultipliedColorU8, Rect, Shader, SpreadMode,
+    Stroke,
 };
 use tiny_skia_path::NormalizedF32;
 
@@ -80,6 +85,16 @@ fn convert_color(&[r, g, b, a]: &[f32; 4]) -> Color {
     Color::from_rgba(r, g, b, a).unwrap()
 }
 
+fn convert_gradient_stops(
+    start: &Rgba,
+    end: &Rgba,
+    color_space: GradientColorSpace,
+) -> Vec<GradientStop> {
+    gradient::stops(start, end, color_space)
+        .map(|(offset, color)| GradientStop::new(offset, convert_color(&color)))
+        .collect()
+}
+
 fn convert_transform(transform: &Transform) -> tiny_skia::Transform {
     tiny_skia::Transform::from_row(
         transform.scale_x,
@@ -146,6 +161,31 @@ impl ResourceAllocator for SkiaAllocator {
         self.text_engine.create_font(font, kind)
     }
 
+    fn create_font_with_letter_spacing(
+        &mut self,
+        font: Option<&settings::Font>,
+        kind: FontKind,
+        letter_spacing: f32,
+    ) -> Self::Font {
+        self.text_engine
+            .create_font_with_letter_spacing(font, kind, letter_spacing)
+    }
+
+    fn create_font_with_monospaced_digits(
+        &mut self,
+        font: Option<&settings::Font>,
+        kind: FontKind,
+        letter_spacing: f32,
+        monospaced_digits: bool,
+    ) -> Self::Font {
+        self.text_engine.create_font_with_monospaced_digits(
+            font,
+            kind,
+            letter_spacing,
+            monospaced_digits,
+        )
+    }
+
     fn create_label(
         &mut self,
         text: &str,
@@ -253,6 +293,12 @@ impl BorrowedRenderer {
         }
     }
 
+    /// Returns the transform that maps the coordinates of the layout to the
+    /// pixel coordinates of the image that was last rendered.
+    pub const fn layout_transform(&self) -> Transform {
+        self.scene_manager.layout_transform()
+    }
+
     /// Renders the layout state provided into the image buffer provided. The
     /// image has to be an array of `RGBA8` encoded pixels (red, green, blue,
     /// alpha with each channel being an u8). Some frameworks may over allocate
@@ -305,7 +351,12 @@ impl BorrowedRenderer {
                 height,
                 rectangle,
             );
-            render_layer(&mut background, scene.bottom_layer(), rectangle);
+            render_layer(
+                &mut background,
+                scene.bottom_layer(),
+                rectangle,
+                scene.gradient_color_space(),
+            );
         }
 
         let top_layer = scene.top_layer();
@@ -327,12 +378,34 @@ impl BorrowedRenderer {
                 .copy_from_slice(&background.data_mut()[min_y..max_y]);
         }
 
-        render_layer(&mut frame_buffer, top_layer, rectangle);
+        render_layer(
+            &mut frame_buffer,
+            top_layer,
+            rectangle,
+            scene.gradient_color_space(),
+        );
 
         new_resolution
     }
 }
 
+/// Describes how the software renderer rotates the final image. This is useful
+/// for displays that are mounted sideways or upside down.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Rotation {
+    /// The image is not rotated.
+    #[default]
+    None,
+    /// The image is rotated by 90° clockwise. This swaps the width and the
+    /// height of the image.
+    Cw90,
+    /// The image is rotated by 90° counterclockwise. This swaps the width and
+    /// the height of the image.
+    Ccw90,
+    /// The image is rotated by 180°.
+    Rot180,
+}
+
 /// The software renderer allows rendering layouts entirely on the CPU. This is
 /// surprisingly fast and can be considered the default renderer. There are two
 /// versions of the software renderer. This version of the software renderer
@@ -340,6 +413,10 @@ impl BorrowedRenderer {
 pub struct Renderer {
     renderer: BorrowedRenderer,
     frame_buffer: Pixmap,
+    rotation: Rotation,
+    rotated: Pixmap,
+    transparency_checkerboard: bool,
+    force_redraw: bool,
 }
 
 impl Default for Renderer {
@@ -354,7 +431,53 @@ impl Renderer {
         Self {
             renderer: BorrowedRenderer::new(),
             frame_buffer: Pixmap::new(1, 1).unwrap(),
+            rotation: Rotation::None,
+            rotated: Pixmap::new(1, 1).unwrap(),
+            transparency_checkerboard: false,
+            force_redraw: false,
+        }
+    }
+
+    /// Returns whether a checkerboard is drawn into fully transparent regions
+    /// of the image.
+    pub const fn transparency_checkerboard(&self) -> bool {
+        self.transparency_checkerboard
+    }
+
+    /// Sets whether a faint checkerboard is drawn into fully transparent
+    /// regions of the image. This is meant as a debugging aid for visualizing
+    /// which parts of the layout are transparent when compositing it on top of
+    /// something else. This is disabled by default.
+    pub const fn set_transparency_checkerboard(&mut self, enabled: bool) {
+        // The checkerboard is drawn into the frame buffer, so the parts of the
+        // frame that don't get redrawn would otherwise keep showing it.
+        if self.transparency_checkerboard && !enabled {
+            self.force_redraw = true;
         }
+        self.transparency_checkerboard = enabled;
+    }
+
+    /// Returns how the final image is rotated.
+    pub const fn rotation(&self) -> Rotation {
+        self.rotation
+    }
+
+    /// Sets how the final image is rotated. The resolution provided when
+    /// rendering is always the resolution of the layout before it is rotated.
+    /// For rotations by 90° the width and the height of the final image are
+    /// swapped. The image that was already rendered is rotated right away.
+    pub fn set_rotation(&mut self, rotation: Rotation) {
+        if rotation != self.rotation && rotation != Rotation::None {
+            rotate(&self.frame_buffer, &mut self.rotated, rotation);
+        }
+        self.rotation = rotation;
+    }
+
+    /// Returns the transform that maps the coordinates of the layout to the
+    /// pixel coordinates of the image that was last rendered. The rotation of
+    /// the image is not taken into account.
+    pub const fn layout_transform(&self) -> Transform {
+        self.renderer.layout_transform()
     }
 
     /// Renders the layout state provided with the chosen resolution. It may
@@ -371,48 +494,113 @@ impl Renderer {
             self.frame_buffer = Pixmap::new(width, height).unwrap();
         }
 
-        self.renderer.render(
+        let new_resolution = self.renderer.render(
             state,
             image_cache,
             self.frame_buffer.data_mut(),
             [width, height],
             width,
-            false,
-        )
+            self.force_redraw,
+        );
+        self.force_redraw = false;
+
+        if self.transparency_checkerboard {
+            draw_checkerboard(&mut self.frame_buffer);
+        }
+
+        if self.rotation != Rotation::None {
+            rotate(&self.frame_buffer, &mut self.rotated, self.rotation);
+        }
+
+        new_resolution
+    }
+
+    fn output(&self) -> &Pixmap {
+        if self.rotation == Rotation::None {
+            &self.frame_buffer
+        } else {
+            &self.rotated
+        }
     }
 
     /// Accesses the image as a byte slice of RGBA8 encoded pixels (red, green,
     /// blue, alpha with each channel being an u8).
     pub fn image_data(&self) -> &[u8] {
-        self.frame_buffer.data()
+        self.output().data()
     }
 
     /// Turns the whole renderer into the underlying image buffer of RGBA8
     /// encoded pixels (red, green, blue, alpha with each channel being an u8).
     pub fn into_image_data(self) -> Vec<u8> {
-        self.frame_buffer.take()
+        if self.rotation == Rotation::None {
+            self.frame_buffer.take()
+        } else {
+            self.rotated.take()
+        }
     }
 
     /// Accesses the image.
     #[cfg(feature = "image")]
     pub fn image(&self) -> ImageBuffer<image::Rgba<u8>, &[u8]> {
-        ImageBuffer::from_raw(
-            self.frame_buffer.width(),
-            self.frame_buffer.height(),
-            self.frame_buffer.data(),
-        )
-        .unwrap()
+        let output = self.output();
+        ImageBuffer::from_raw(output.width(), output.height(), output.data()).unwrap()
     }
 
     /// Turns the whole renderer into the underlying image.
     #[cfg(feature = "image")]
     pub fn into_image(self) -> RgbaImage {
-        RgbaImage::from_raw(
-            self.frame_buffer.width(),
-            self.frame_buffer.height(),
-            self.frame_buffer.take(),
-        )
-        .unwrap()
+        let output = if self.rotation == Rotation::None {
+            self.frame_buffer
+        } else {
+            self.rotated
+        };
+        RgbaImage::from_raw(output.width(), output.height(), output.take()).unwrap()
+    }
+}
+
+fn draw_checkerboard(pixmap: &mut Pixmap) {
+    const SQUARE_SIZE: usize = 8;
+    let light = PremultipliedColorU8::from_rgba(0x40, 0x40, 0x40, 0x40).unwrap();
+    let dark = PremultipliedColorU8::from_rgba(0x20, 0x20, 0x20, 0x40).unwrap();
+
+    let width = pixmap.width() as usize;
+    for (index, pixel) in pixmap.pixels_mut().iter_mut().enumerate() {
+        if pixel.alpha() == 0 {
+            let (x, y) = (index % width, index / width);
+            *pixel = if (x / SQUARE_SIZE + y / SQUARE_SIZE) % 2 == 0 {
+                light
+            } else {
+                dark
+            };
+        }
+    }
+}
+
+fn rotate(source: &Pixmap, target: &mut Pixmap, rotation: Rotation) {
+    let (width, height) = (source.width(), source.height());
+    let (target_width, target_height) = match rotation {
+        Rotation::Cw90 | Rotation::Ccw90 => (height, width),
+        Rotation::None | Rotation::Rot180 => (width, height),
+    };
+
+    if target.width() != target_width || target.height() != target_height {
+        *target = Pixmap::new(target_width, target_height).unwrap();
+    }
+
+    let source_pixels = source.pixels();
+    let target_pixels = target.pixels_mut();
+
+    for y in 0..height {
+        for x in 0..width {
+            let (target_x, target_y) = match rotation {
+                Rotation::None => (x, y),
+                Rotation::Cw90 => (height - 1 - y, x),
+                Rotation::Ccw90 => (y, width - 1 - x),
+                Rotation::Rot180 => (width - 1 - x, height - 1 - y),
+            };
+            target_pixels[(target_y * target_width + target_x) as usize] =
+                source_pixels[(y * width + x) as usize];
+        }
     }
 }
 
@@ -420,6 +608,7 @@ fn render_layer(
     canvas: &mut PixmapMut<'_>,
     layer: &[Entity<SkiaPath, SkiaImage, SkiaLabel>],
     rectangle: &Path,
+    color_space: GradientColorSpace,
 ) {
     for entity in layer {
         match entity {
@@ -427,6 +616,7 @@ fn render_layer(
                 if let Some(path) = path.as_deref() {
                     let paint = convert_shader(
                         shader,
+                        color_space,
                         path,
                         |path| {
                             let bounds = path.bounds();
@@ -492,6 +682,7 @@ fn render_layer(
 
                 let paint = convert_shader(
                     shader,
+                    color_space,
                     label,
                     |label| {
                         let (mut top, mut bottom) = (f32::INFINITY, f32::NEG_INFINITY);
@@ -586,6 +777,7 @@ fn render_layer(
 
 fn convert_shader<T>(
     shader: &FillShader,
+    color_space: GradientColorSpace,
     has_bounds: &T,
     calculate_top_bottom: impl FnOnce(&T) -> [f32; 2],
     calculate_left_right: impl FnOnce(&T) -> [f32; 2],
@@ -597,10 +789,7 @@ fn convert_shader<T>(
             LinearGradient::new(
                 Point::from_xy(0.0, bound_top),
                 Point::from_xy(0.0, bound_bottom),
-                vec![
-                    GradientStop::new(0.0, convert_color(top)),
-                    GradientStop::new(1.0, convert_color(bottom)),
-                ],
+                convert_gradient_stops(top, bottom, color_space),
                 SpreadMode::Pad,
                 tiny_skia::Transform::identity(),
             )
@@ -611,10 +800,7 @@ fn convert_shader<T>(
             LinearGradient::new(
                 Point::from_xy(bound_left, 0.0),
                 Point::from_xy(bound_right, 0.0),
-                vec![
-                    GradientStop::new(0.0, convert_color(left)),
-                    GradientStop::new(1.0, convert_color(right)),
-                ],
+                convert_gradient_stops(left, right, color_space),
                 SpreadMode::Pad,
                 tiny_skia::Transform::identity(),
             )
@@ -643,6 +829,8 @@ fn fill_background(
     #[cfg(feature = "image")]
     update_blurred_background_image(scene, blurred_background_image);
 
+    let color_space = scene.gradient_color_space();
+
     match scene.background() {
         Some(background) => match background {
             Background::Shader(shader) => match shader {
@@ -658,10 +846,7 @@ fn fill_background(
                             shader: LinearGradient::new(
                                 Point::from_xy(0.0, 0.0),
                                 Point::from_xy(0.0, height as _),
-                                vec![
-                                    GradientStop::new(0.0, convert_color(top)),
-                                    GradientStop::new(1.0, convert_color(bottom)),
-                                ],
+                                convert_gradient_stops(top, bottom, color_space),
                                 SpreadMode::Pad,
                                 tiny_skia::Transform::identity(),
                             )
@@ -680,10 +865,7 @@ fn fill_background(
                             shader: LinearGradient::new(
                                 Point::from_xy(0.0, 0.0),
                                 Point::from_xy(width as _, 0.0),
-                                vec![
-                                    GradientStop::new(0.0, convert_color(left)),
-                                    GradientStop::new(1.0, convert_color(right)),
-                                ],
+                                convert_gradient_stops(left, right, color_space),
                                 SpreadMode::Pad,
                                 tiny_skia::Transform::identity(),
                             )
@@ -12,8 +12,8 @@ use hashbrown::{HashSet, HashTable};
 use crate::{
     layout::LayoutState,
     platform::prelude::*,
-    settings::{BLUR_FACTOR, Font, ImageCache},
-    util::xml::{AttributeWriter, DisplayAlreadyEscaped, Text, Value, Writer},
+    settings::{BLUR_FACTOR, Font, GradientColorSpace, ImageCache},
+    util::xml::{AttributeWriter, DisplayAlreadyEscaped, Value, Writer},
 };
 
 use super::{
@@ -21,7 +21,7 @@ use super::{
     Transform,
     consts::SHADOW_OFFSET,
     default_text_engine::{self, TextEngine},
-    resource,
+    gradient, resource,
 };
 
 type SvgImage = Rc<Image>;
@@ -109,12 +109,15 @@ impl Renderer {
 
         let scene = self.scene_manager.scene();
         let defs = &mut *self.allocator.defs.borrow_mut();
+        let color_space = scene.gradient_color_space();
 
         defs.ptr_lookup.clear();
 
         if let Some(background) = scene.background() {
             match background {
-                Background::Shader(shader) => visit_shader(current_id, defs, writer, shader)?,
+                Background::Shader(shader) => {
+                    visit_shader(current_id, defs, writer, shader, color_space)?
+                }
                 Background::Image(image, transform) => {
                     visit_image(current_id, defs, writer, &image.image)?;
 
@@ -173,14 +176,14 @@ impl Renderer {
             match entity {
                 Entity::FillPath(path, shader, _) => {
                     visit_path(current_id, defs, writer, path)?;
-                    visit_shader(current_id, defs, writer, shader)?;
+                    visit_shader(current_id, defs, writer, shader, color_space)?;
                 }
                 Entity::StrokePath(path, _, _, _) => visit_path(current_id, defs, writer, path)?,
                 Entity::Image(image, _) => visit_image(current_id, defs, writer, image)?,
                 Entity::Label(label, shader, _, _) => {
                     for glyph in label.read().unwrap().glyphs() {
                         if glyph.color.is_none() {
-                            visit_shader(current_id, defs, writer, shader)?;
+                            visit_shader(current_id, defs, writer, shader, color_space)?;
                         }
 
                         visit_path(current_id, defs, writer, &glyph.path)?;
@@ -423,6 +426,7 @@ fn visit_shader<W: Write>(
     defs: &mut Defs,
     writer: &mut Writer<W>,
     shader: &FillShader,
+    color_space: GradientColorSpace,
 ) -> fmt::Result {
     let (vertical, start, end) = match shader {
         FillShader::SolidColor(_) => return Ok(()),
@@ -453,23 +457,20 @@ fn visit_shader<W: Write>(
                 ),
             ],
             |writer| {
-                writer.tag("stop", |mut writer| {
-                    let (start_rgb, start_a) = convert_color_or_transparent(start);
-                    writer.attribute("stop-color", start_rgb)?;
-                    if let Some(a) = start_a {
-                        writer.attribute("stop-opacity", DisplayAlreadyEscaped(a))?;
-                    }
-                    Ok(())
-                })?;
-                writer.tag("stop", |mut writer| {
-                    let (end_rgb, end_a) = convert_color_or_transparent(end);
-                    writer.attribute("offset", Text::new_escaped("1"))?;
-                    writer.attribute("stop-color", end_rgb)?;
-                    if let Some(a) = end_a {
-                        writer.attribute("stop-opacity", DisplayAlreadyEscaped(a))?;
-                    }
-                    Ok(())
-                })
+                for (offset, color) in gradient::stops(start, end, color_space) {
+                    writer.tag("stop", |mut writer| {
+                        let (rgb, a) = convert_color_or_transparent(&color);
+                        if offset != 0.0 {
+                            writer.attribute("offset", DisplayAlreadyEscaped(offset))?;
+                        }
+                        writer.attribute("stop-color", rgb)?;
+                        if let Some(a) = a {
+                            writer.attribute("stop-opacity", DisplayAlreadyEscaped(a))?;
+                        }
+                        Ok(())
+                    })?;
+                }
+                Ok(())
             },
         )?;
     }
@@ -777,6 +778,31 @@ impl ResourceAllocator for SvgAllocator {
         self.text_engine.create_font(font, kind)
     }
 
+    fn create_font_with_letter_spacing(
+        &mut self,
+        font: Option<&Font>,
+        kind: FontKind,
+        letter_spacing: f32,
+    ) -> Self::Font {
+        self.text_engine
+            .create_font_with_letter_spacing(font, kind, letter_spacing)
+    }
+
+    fn create_font_with_monospaced_digits(
+        &mut self,
+        font: Option<&Font>,
+        kind: FontKind,
+        letter_spacing: f32,
+        monospaced_digits: bool,
+    ) -> Self::Font {
+        self.text_engine.create_font_with_monospaced_digits(
+            font,
+            kind,
+            letter_spacing,
+            monospaced_digits,
+        )
+    }
+
     fn create_label(
         &mut self,
         text: &str,
@@ -19,6 +19,8 @@ extern "C" {
     pub fn set_font(this: &CanvasRenderingContext2d, value: &JsString);
     #[wasm_bindgen(structural, method, setter, js_class = "CanvasRenderingContext2D", js_name = fontKerning)]
     pub fn set_font_kerning(this: &CanvasRenderingContext2d, value: &JsString);
+    #[wasm_bindgen(structural, method, setter, js_class = "CanvasRenderingContext2D", js_name = letterSpacing)]
+    pub fn set_letter_spacing(this: &CanvasRenderingContext2d, value: &JsString);
     #[wasm_bindgen(structural, method, setter, js_class = "CanvasRenderingContext2D", js_name = shadowColor)]
     pub fn set_shadow_color(this: &CanvasRenderingContext2d, value: &JsString);
     #[wasm_bindgen(structural, method, setter, js_class = "CanvasRenderingContext2D", js_name = shadowBlur)]
@@ -14,12 +14,13 @@ use std::{
 };
 use wasm_bindgen::{JsCast, JsValue};
 use wasm_bindgen_futures::JsFuture;
-use web_sys::{Blob, HtmlCanvasElement, HtmlElement, ImageBitmap, Path2d, Window};
+use web_sys::{Blob, CanvasGradient, HtmlCanvasElement, HtmlElement, ImageBitmap, Path2d, Window};
 
 use crate::{
     layout::LayoutState,
     settings::{
-        BLUR_FACTOR, BackgroundImage, Font, FontStretch, FontStyle, FontWeight, ImageCache,
+        BLUR_FACTOR, BackgroundImage, Font, FontStretch, FontStyle, FontWeight, GradientColorSpace,
+        ImageCache,
     },
 };
 
@@ -27,7 +28,7 @@ use self::bindings::CanvasRenderingContext2d;
 
 use super::{
     Background, Entity, FillShader, FontKind, Handle, Label, PathBuilder, ResourceAllocator,
-    SceneManager, SharedOwnership, Transform, consts::SHADOW_OFFSET,
+    SceneManager, SharedOwnership, Transform, consts::SHADOW_OFFSET, gradient,
 };
 
 mod bindings;
@@ -172,6 +173,7 @@ impl Default for CanvasLabelInner {
                 descriptor: "".into(),
                 font_handling: FontHandling::Normal,
                 font_kerning: "".into(),
+                letter_spacing: "".into(),
                 top: 0.0,
                 bottom: 0.0,
             }),
@@ -204,6 +206,7 @@ impl Label for CanvasLabel {
 struct CanvasFont {
     descriptor: JsString,
     font_kerning: JsString,
+    letter_spacing: JsString,
     font_handling: FontHandling,
     top: f32,
     bottom: f32,
@@ -267,6 +270,25 @@ impl ResourceAllocator for CanvasAllocator {
     }
 
     fn create_font(&mut self, font: Option<&Font>, kind: FontKind) -> Self::Font {
+        self.create_font_with_letter_spacing(font, kind, 0.0)
+    }
+
+    fn create_font_with_letter_spacing(
+        &mut self,
+        font: Option<&Font>,
+        kind: FontKind,
+        letter_spacing: f32,
+    ) -> Self::Font {
+        self.create_font_with_monospaced_digits(font, kind, letter_spacing, false)
+    }
+
+    fn create_font_with_monospaced_digits(
+        &mut self,
+        font: Option<&Font>,
+        kind: FontKind,
+        letter_spacing: f32,
+        monospaced_digits: bool,
+    ) -> Self::Font {
         let mut descriptor = String::new();
         if let Some(font) = font {
             match font.style {
@@ -297,15 +319,17 @@ impl ResourceAllocator for CanvasAllocator {
             FontKind::Times => descriptor.push_str("\"fira\", monospace"),
         }
 
-        let is_monospaced = kind.is_monospaced();
+        let is_monospaced = monospaced_digits || kind.is_monospaced();
         let font_kerning = if is_monospaced {
             &self.cache.none
         } else {
             &self.cache.auto
         };
         let descriptor = JsString::from(descriptor);
+        let letter_spacing = JsString::from(format!("{}px", letter_spacing / FONT_SCALE_FACTOR));
         self.ctx_top.set_font(&descriptor);
         self.ctx_top.set_font_kerning(font_kerning);
+        self.ctx_top.set_letter_spacing(&letter_spacing);
 
         // FIXME: We query this to position a gradient from the top to the
         // bottom of the font. Is the ascent and descent what we want here?
@@ -350,6 +374,7 @@ impl ResourceAllocator for CanvasAllocator {
         Rc::new(CanvasFont {
             descriptor,
             font_kerning: font_kerning.clone(),
+            letter_spacing,
             font_handling,
             top,
             bottom,
@@ -488,6 +513,7 @@ pub struct Renderer {
 struct JsValueCache {
     str_buf: String,
     shaders: HashMap<HashShader, JsValue>,
+    gradient_color_space: GradientColorSpace,
     colors: HashMap<[u8; 16], JsString>,
     filters: HashMap<HashFilter, JsString>,
     digits: [JsString; 10],
@@ -518,6 +544,26 @@ impl JsValueCache {
             .or_insert_with(|| JsString::from(Self::raw_color(&mut self.str_buf, rgba)))
     }
 
+    fn set_gradient_color_space(&mut self, color_space: GradientColorSpace) {
+        if self.gradient_color_space != color_space {
+            // The cached gradients were created for the previous color space.
+            self.gradient_color_space = color_space;
+            self.shaders.clear();
+        }
+    }
+
+    fn gradient(
+        str_buf: &mut String,
+        canvas_gradient: &CanvasGradient,
+        start: &[f32; 4],
+        end: &[f32; 4],
+        color_space: GradientColorSpace,
+    ) {
+        for (offset, color) in gradient::stops(start, end, color_space) {
+            let _ = canvas_gradient.add_color_stop(offset, Self::raw_color(str_buf, &color));
+        }
+    }
+
     fn shader(
         &mut self,
         shader: &FillShader,
@@ -536,6 +582,7 @@ impl JsValueCache {
             }
         };
 
+        let color_space = self.gradient_color_space;
         self.shaders
             .entry(hash_shader)
             .or_insert_with(|| match shader {
@@ -543,15 +590,13 @@ impl JsValueCache {
                 FillShader::VerticalGradient(t, b) => {
                     let [min_y, max_y] = handle.bounds_y();
                     let gradient = ctx.create_linear_gradient(0.0, min_y as _, 0.0, max_y as _);
-                    let _ = gradient.add_color_stop(0.0, Self::raw_color(&mut self.str_buf, t));
-                    let _ = gradient.add_color_stop(1.0, Self::raw_color(&mut self.str_buf, b));
+                    Self::gradient(&mut self.str_buf, &gradient, t, b, color_space);
                     gradient.unchecked_into()
                 }
                 FillShader::HorizontalGradient(l, r) => {
                     let [min_x, max_x] = handle.bounds_x();
                     let gradient = ctx.create_linear_gradient(min_x as _, 0.0, max_x as _, 0.0);
-                    let _ = gradient.add_color_stop(0.0, Self::raw_color(&mut self.str_buf, l));
-                    let _ = gradient.add_color_stop(1.0, Self::raw_color(&mut self.str_buf, r));
+                    Self::gradient(&mut self.str_buf, &gradient, l, r, color_space);
                     gradient.unchecked_into()
                 }
             })
@@ -670,6 +715,7 @@ impl Renderer {
             cache: JsValueCache {
                 str_buf: String::new(),
                 shaders: HashMap::new(),
+                gradient_color_space: GradientColorSpace::Srgb,
                 colors: HashMap::new(),
                 filters: HashMap::new(),
                 digits: array::from_fn(|digit| JsString::from((digit as u8 + b'0') as char)),
@@ -738,6 +784,9 @@ impl Renderer {
         );
 
         let scene = self.manager.scene();
+        self.allocator
+            .cache
+            .set_gradient_color_space(scene.gradient_color_space());
 
         if scene.bottom_layer_changed() || self.allocator.force_redraw_all.take() {
             let ctx = &mut self.allocator.ctx_bottom;
@@ -935,6 +984,7 @@ fn render_layer(
 fn set_font(ctx: &CanvasRenderingContext2d, font: &CanvasFont) {
     ctx.set_font(&font.descriptor);
     ctx.set_font_kerning(&font.font_kerning);
+    ctx.set_letter_spacing(&font.letter_spacing);
 }
 
 fn set_transform(ctx: &CanvasRenderingContext2d, transform: &Transform) {
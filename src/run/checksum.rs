@@ -0,0 +1,137 @@
+//! Provides a checksum of the segment and time data of a [`Run`]. The checksum
+//! can be stored in splits files, so that corrupted splits files can be
+//! detected when they are loaded again.
+//!
+//! The times are hashed in the same textual representation that splits files
+//! store them in. This ensures that the checksum is not affected by the
+//! precision of the times being reduced when they are saved.
+
+use super::Run;
+use crate::{
+    Time,
+    timing::formatter::{Complete, TimeFormatter},
+};
+use core::fmt::{self, Write};
+
+/// Describes the result of verifying the checksum of a [`Run`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ChecksumVerification {
+    /// The Run doesn't have a checksum stored, so it can't be verified.
+    Missing,
+    /// The checksum stored matches the segment and time data of the Run.
+    Valid,
+    /// The checksum stored doesn't match the segment and time data of the Run.
+    /// This usually means that the splits file got corrupted or was modified
+    /// outside of a timer.
+    Mismatch {
+        /// The checksum that is stored in the Run.
+        stored: u32,
+        /// The checksum of the Run's current segment and time data.
+        actual: u32,
+    },
+}
+
+impl Run {
+    /// Calculates a CRC-32 checksum of the segment and time data of the Run.
+    /// This includes the names of the segments, the custom comparisons, the
+    /// best segment times and the segment histories.
+    pub fn calculate_checksum(&self) -> u32 {
+        let mut crc = Crc32::new();
+
+        for comparison in &self.custom_comparisons {
+            crc.field(comparison.as_bytes());
+        }
+
+        for segment in &self.segments {
+            crc.field(segment.name().as_bytes());
+            for comparison in &self.custom_comparisons {
+                crc.time(segment.comparison(comparison));
+            }
+            crc.time(segment.best_segment_time());
+            for &(index, time) in segment.segment_history() {
+                let _ = write!(crc, "{index}");
+                crc.field(&[]);
+                crc.time(time);
+            }
+        }
+
+        crc.finish()
+    }
+
+    /// Verifies that the checksum stored in the Run's metadata matches its
+    /// segment and time data. This is meant to be used right after loading a
+    /// splits file. A mismatch doesn't prevent the Run from being used, but the
+    /// user should be warned that their splits may have been corrupted.
+    pub fn verify_checksum(&self) -> ChecksumVerification {
+        let Some(stored) = self.metadata.checksum() else {
+            return ChecksumVerification::Missing;
+        };
+        let actual = self.calculate_checksum();
+        if stored == actual {
+            ChecksumVerification::Valid
+        } else {
+            ChecksumVerification::Mismatch { stored, actual }
+        }
+    }
+}
+
+const TABLE: [u32; 256] = {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut value = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            value = if value & 1 != 0 {
+                (value >> 1) ^ 0xEDB8_8320
+            } else {
+                value >> 1
+            };
+            bit += 1;
+        }
+        table[i] = value;
+        i += 1;
+    }
+    table
+};
+
+struct Crc32(u32);
+
+impl Crc32 {
+    const fn new() -> Self {
+        Self(!0)
+    }
+
+    fn update(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = TABLE[((self.0 ^ byte as u32) & 0xFF) as usize] ^ (self.0 >> 8);
+        }
+    }
+
+    /// Hashes the bytes followed by a separator, so that the boundaries
+    /// between the individual fields affect the checksum.
+    fn field(&mut self, bytes: &[u8]) {
+        self.update(bytes);
+        self.update(&[0]);
+    }
+
+    fn time(&mut self, time: Time) {
+        for time in [time.real_time, time.game_time] {
+            if let Some(time) = time {
+                let _ = write!(self, "{}", Complete.format(time));
+            }
+            self.field(&[]);
+        }
+    }
+
+    const fn finish(&self) -> u32 {
+        !self.0
+    }
+}
+
+impl Write for Crc32 {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.update(s.as_bytes());
+        Ok(())
+    }
+}
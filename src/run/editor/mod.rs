@@ -12,7 +12,10 @@ use crate::{
     timing::ParseError as ParseTimeSpanError,
     util::{PopulateString, caseless},
 };
-use core::{mem::swap, num::ParseIntError};
+use core::{
+    num::ParseIntError,
+    ops::{Deref, DerefMut},
+};
 use snafu::{OptionExt, ResultExt};
 
 pub mod cleaning;
@@ -76,6 +79,39 @@ pub struct Editor {
     selected_segments: Vec<usize>,
     previous_personal_best_time: Time,
     segment_times: Vec<Option<TimeSpan>>,
+    segment_list_pending: bool,
+}
+
+/// A transaction started by [`Editor::begin_edit`]. It wraps a transaction of
+/// the Run being edited, so fixing the Run is deferred until the transaction is
+/// dropped rather than happening after each individual edit. The transaction
+/// dereferences to the [`Editor`], so all the usual editing methods can be used
+/// through it.
+pub struct Transaction<'a> {
+    editor: &'a mut Editor,
+}
+
+impl Deref for Transaction<'_> {
+    type Target = Editor;
+
+    fn deref(&self) -> &Self::Target {
+        self.editor
+    }
+}
+
+impl DerefMut for Transaction<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.editor
+    }
+}
+
+impl Drop for Transaction<'_> {
+    fn drop(&mut self) {
+        self.editor.run.end_edit();
+        if !self.editor.run.is_editing() && self.editor.segment_list_pending {
+            self.editor.update_segment_list();
+        }
+    }
 }
 
 impl Editor {
@@ -96,6 +132,7 @@ impl Editor {
             selected_segments: vec![0],
             previous_personal_best_time: personal_best_time,
             segment_times: Vec::with_capacity(len),
+            segment_list_pending: false,
         };
 
         editor.update_segment_list();
@@ -116,6 +153,16 @@ impl Editor {
         &self.run
     }
 
+    /// Begins a transaction for applying a batch of edits. This works like
+    /// [`Run::begin_edit`], so the Run is only fixed once the transaction is
+    /// dropped rather than after each individual edit. Transactions can be
+    /// nested, in which case the Run is fixed when the outermost transaction is
+    /// dropped.
+    pub fn begin_edit(&mut self) -> Transaction<'_> {
+        self.run.start_edit();
+        Transaction { editor: self }
+    }
+
     /// Accesses the timing method that is currently selected for being
     /// modified.
     pub const fn selected_timing_method(&self) -> TimingMethod {
@@ -457,11 +504,31 @@ impl Editor {
 
     fn fix(&mut self) {
         self.run.fix_splits();
-        self.update_segment_list();
+        if self.run.is_editing() {
+            self.segment_list_pending = true;
+        } else {
+            self.update_segment_list();
+        }
         self.raise_run_edited();
     }
 
+    fn segment_time(&self, index: usize) -> Option<TimeSpan> {
+        if !self.segment_list_pending {
+            return self.segment_times[index];
+        }
+        let method = self.selected_method;
+        let segments = self.run.segments();
+        let split_time = segments[index].personal_best_split_time()[method];
+        let previous_time = segments[..index]
+            .iter()
+            .rev()
+            .find_map(|segment| segment.personal_best_split_time()[method])
+            .unwrap_or_default();
+        catch! { split_time? - previous_time }
+    }
+
     fn update_segment_list(&mut self) {
+        self.segment_list_pending = false;
         let method = self.selected_method;
         let mut previous_time = Some(TimeSpan::zero());
         self.segment_times.clear();
@@ -654,47 +721,7 @@ impl Editor {
     }
 
     fn switch_segments(&mut self, index: usize) {
-        let max_index = self.run.max_attempt_history_index().unwrap_or(0);
-        let min_index = self.run.min_segment_history_index().unwrap();
-
-        // Use split_at to prove that the 2 segments and the one before if it
-        // exists are distinct
-        let (a, b) = self.run.segments_mut().split_at_mut(index);
-        let previous = a.last();
-        let [first, second, ..] = b else {
-            panic!("There need to be at least 2 segments to switch them.");
-        };
-
-        for run_index in min_index..=max_index {
-            // Remove both segment history elements if one of them has a None
-            // time and the other has has a non None time
-            let first_history = first.segment_history().get(run_index);
-            let second_history = second.segment_history().get(run_index);
-            if let (Some(first_history), Some(second_history)) = (first_history, second_history) {
-                if first_history.real_time.is_some() != second_history.real_time.is_some()
-                    || first_history.game_time.is_some() != second_history.game_time.is_some()
-                {
-                    first.segment_history_mut().remove(run_index);
-                    second.segment_history_mut().remove(run_index);
-                }
-            }
-        }
-
-        for (comparison, first_time) in first.comparisons_mut().iter_mut() {
-            // Fix the comparison times based on the new positions of the two
-            // segments
-            let previous_time = previous
-                .map(|p| p.comparison(comparison))
-                .unwrap_or_else(Time::zero);
-
-            let second_time = second.comparison_mut(comparison);
-            let first_segment_time = *first_time - previous_time;
-            let second_segment_time = *second_time - *first_time;
-            *second_time = previous_time + second_segment_time;
-            *first_time = *second_time + first_segment_time;
-        }
-
-        swap(first, second);
+        self.run.switch_segments(index);
     }
 
     /// Checks if the currently selected segments can be moved up. If any one of
@@ -33,7 +33,7 @@ impl<T: Borrow<Editor>> SegmentRow<T> {
     /// Accesses the segment time of the segment for the active timing method.
     pub fn segment_time(&self) -> Option<TimeSpan> {
         let editor: &Editor = self.editor.borrow();
-        editor.segment_times[self.index]
+        editor.segment_time(self.index)
     }
 
     /// Accesses the best segment time of the segment for the active timing method.
@@ -107,6 +107,9 @@ impl<'a> SegmentRow<&'a mut Editor> {
 
     /// Sets the segment time of the segment for the active timing method.
     pub fn set_segment_time(&mut self, time: Option<TimeSpan>) {
+        if self.editor.segment_list_pending {
+            self.editor.update_segment_list();
+        }
         self.editor.segment_times[self.index] = time;
         self.editor.fix_splits_from_segments();
         self.editor.times_modified();
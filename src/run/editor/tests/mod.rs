@@ -8,6 +8,7 @@ mod comparison;
 mod custom_variables;
 mod dissociate_run;
 mod mark_as_modified;
+mod transaction;
 
 #[test]
 fn new_best_segment() {
@@ -0,0 +1,89 @@
+use super::super::Editor;
+use crate::{Run, Segment, TimeSpan};
+
+fn editor() -> Editor {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("A"));
+    run.push_segment(Segment::new("B"));
+    run.push_segment(Segment::new("C"));
+    Editor::new(run).unwrap()
+}
+
+fn edit(editor: &mut Editor) {
+    for (index, time) in ["1:00", "2:30", "4:00"].into_iter().enumerate() {
+        editor.select_only(index);
+        editor
+            .active_segment()
+            .parse_and_set_split_time(time)
+            .unwrap();
+    }
+}
+
+#[test]
+fn batched_edits_recompute_once() {
+    let mut individual = editor();
+    let generation = individual.run().generation();
+    edit(&mut individual);
+    assert_eq!(individual.run().generation(), generation + 3);
+
+    let mut batched = editor();
+    let generation = batched.run().generation();
+    {
+        let mut transaction = batched.begin_edit();
+        edit(&mut transaction);
+        assert_eq!(transaction.run().generation(), generation);
+    }
+    assert_eq!(batched.run().generation(), generation + 1);
+
+    assert_eq!(individual.close(), batched.close());
+}
+
+#[test]
+fn nested_transactions_recompute_when_the_outermost_one_is_dropped() {
+    let mut editor = editor();
+    let generation = editor.run().generation();
+    {
+        let mut outer = editor.begin_edit();
+        {
+            let mut inner = outer.begin_edit();
+            inner
+                .active_segment()
+                .parse_and_set_split_time("1:00")
+                .unwrap();
+        }
+        assert_eq!(outer.run().generation(), generation);
+    }
+    assert_eq!(editor.run().generation(), generation + 1);
+}
+
+#[test]
+fn transactions_without_edits_dont_recompute() {
+    let mut editor = editor();
+    let generation = editor.run().generation();
+    drop(editor.begin_edit());
+    assert_eq!(editor.run().generation(), generation);
+}
+
+#[test]
+fn segment_times_are_up_to_date_during_a_transaction() {
+    let mut editor = editor();
+    let mut transaction = editor.begin_edit();
+    edit(&mut transaction);
+
+    transaction.select_only(1);
+    assert_eq!(
+        transaction.active_segment().segment_time(),
+        Some(TimeSpan::from_seconds(90.0)),
+    );
+
+    transaction.insert_segment_above();
+    transaction.select_only(2);
+    transaction
+        .active_segment()
+        .parse_and_set_segment_time("1:00")
+        .unwrap();
+    assert_eq!(
+        transaction.active_segment().split_time(),
+        Some(TimeSpan::from_seconds(120.0)),
+    );
+}
@@ -0,0 +1,324 @@
+//! Provides a compact binary encoding of a [`Run`]'s history. This includes the
+//! Attempt History and the Segment Histories of all the segments, but nothing
+//! else. This allows backing up and synchronizing the history without
+//! transferring the entire splits file.
+//!
+//! The blob starts with a magic number and a version. All the integers are
+//! encoded as LEB128 variable length integers, with signed integers being
+//! zigzag encoded first. Optional values are tracked in a flags byte in front
+//! of each attempt and each segment time.
+
+use super::{Attempt, Run};
+use crate::{
+    AtomicDateTime, Time, TimeSpan,
+    platform::{DateTime, Duration, prelude::*},
+};
+use snafu::{OptionExt, ensure};
+
+const MAGIC: &[u8; 4] = b"LSHB";
+const VERSION: u8 = 1;
+
+const REAL_TIME: u8 = 1 << 0;
+const GAME_TIME: u8 = 1 << 1;
+const STARTED: u8 = 1 << 2;
+const STARTED_SYNCED: u8 = 1 << 3;
+const ENDED: u8 = 1 << 4;
+const ENDED_SYNCED: u8 = 1 << 5;
+const PAUSE_TIME: u8 = 1 << 6;
+
+/// The Error type for history blobs that could not be imported.
+#[derive(Debug, PartialEq, Eq, snafu::Snafu)]
+#[snafu(context(suffix(false)))]
+pub enum HistoryBlobError {
+    /// The data is not a history blob.
+    InvalidMagic,
+    /// The history blob was created by a newer version that is not supported.
+    UnsupportedVersion {
+        /// The version of the history blob.
+        version: u8,
+    },
+    /// The history blob ended unexpectedly.
+    UnexpectedEnd,
+    /// The history blob contains a value that is out of range.
+    InvalidValue,
+    /// The history blob doesn't have the same amount of segments as the run.
+    SegmentCountMismatch,
+    /// There is unexpected data after the end of the history blob.
+    TrailingData,
+}
+
+impl Run {
+    /// Encodes the Attempt History and the Segment Histories of all the
+    /// segments into a compact, versioned binary blob. It can be imported again
+    /// with [`import_history_blob`](Self::import_history_blob).
+    pub fn export_history_blob(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        buf.extend_from_slice(MAGIC);
+        buf.push(VERSION);
+
+        write_uint(&mut buf, self.attempt_history.len() as u64);
+        for attempt in &self.attempt_history {
+            let time = attempt.time();
+            let (started, ended, pause_time) =
+                (attempt.started(), attempt.ended(), attempt.pause_time());
+
+            let mut flags = time_flags(time);
+            if let Some(started) = started {
+                flags |= STARTED;
+                if started.synced_with_atomic_clock {
+                    flags |= STARTED_SYNCED;
+                }
+            }
+            if let Some(ended) = ended {
+                flags |= ENDED;
+                if ended.synced_with_atomic_clock {
+                    flags |= ENDED_SYNCED;
+                }
+            }
+            if pause_time.is_some() {
+                flags |= PAUSE_TIME;
+            }
+
+            write_int(&mut buf, attempt.index().into());
+            buf.push(flags);
+            write_time(&mut buf, time);
+            if let Some(started) = started {
+                write_date_time(&mut buf, started.time);
+            }
+            if let Some(ended) = ended {
+                write_date_time(&mut buf, ended.time);
+            }
+            if let Some(pause_time) = pause_time {
+                write_time_span(&mut buf, pause_time);
+            }
+        }
+
+        write_uint(&mut buf, self.segments.len() as u64);
+        for segment in &self.segments {
+            let history = segment.segment_history();
+            write_uint(&mut buf, history.iter().len() as u64);
+            // The indices are sorted, so we only store the difference to the
+            // previous index, which is usually tiny.
+            let mut previous_index = 0;
+            for &(index, time) in history {
+                write_int(&mut buf, i64::from(index) - i64::from(previous_index));
+                previous_index = index;
+                buf.push(time_flags(time));
+                write_time(&mut buf, time);
+            }
+        }
+
+        buf
+    }
+
+    /// Replaces the Attempt History and the Segment Histories of all the
+    /// segments with the history stored in a blob created by
+    /// [`export_history_blob`](Self::export_history_blob). The blob needs to
+    /// have been created from a Run with the same amount of segments. If the
+    /// blob can't be imported, the Run is left unchanged.
+    pub fn import_history_blob(&mut self, data: &[u8]) -> Result<(), HistoryBlobError> {
+        let mut reader = Reader(data);
+
+        ensure!(reader.bytes(MAGIC.len())? == MAGIC, InvalidMagic);
+        let version = reader.byte()?;
+        ensure!(version == VERSION, UnsupportedVersion { version });
+
+        let attempt_count = reader.uint()?;
+        let mut attempt_history = Vec::new();
+        for _ in 0..attempt_count {
+            let index = reader.i32()?;
+            let flags = reader.byte()?;
+            let time = reader.time(flags)?;
+            let started = if flags & STARTED != 0 {
+                Some(AtomicDateTime::new(
+                    reader.date_time()?,
+                    flags & STARTED_SYNCED != 0,
+                ))
+            } else {
+                None
+            };
+            let ended = if flags & ENDED != 0 {
+                Some(AtomicDateTime::new(
+                    reader.date_time()?,
+                    flags & ENDED_SYNCED != 0,
+                ))
+            } else {
+                None
+            };
+            let pause_time = if flags & PAUSE_TIME != 0 {
+                Some(reader.time_span()?)
+            } else {
+                None
+            };
+            attempt_history.push(Attempt::new(index, time, started, ended, pause_time));
+        }
+
+        let segment_count = reader.uint()?;
+        ensure!(
+            segment_count == self.segments.len() as u64,
+            SegmentCountMismatch
+        );
+
+        let mut segment_histories = Vec::with_capacity(self.segments.len());
+        for _ in 0..segment_count {
+            let entry_count = reader.uint()?;
+            let mut history = Vec::new();
+            let mut index = 0i32;
+            for _ in 0..entry_count {
+                let delta = reader.int()?;
+                index = i64::from(index)
+                    .checked_add(delta)
+                    .and_then(|i| i32::try_from(i).ok())
+                    .context(InvalidValue)?;
+                let flags = reader.byte()?;
+                history.push((index, reader.time(flags)?));
+            }
+            segment_histories.push(history);
+        }
+
+        ensure!(reader.0.is_empty(), TrailingData);
+
+        self.attempt_history = attempt_history;
+        for (segment, history) in self.segments.iter_mut().zip(segment_histories) {
+            let segment_history = segment.segment_history_mut();
+            segment_history.clear();
+            for (index, time) in history {
+                segment_history.insert(index, time);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn time_flags(time: Time) -> u8 {
+    let mut flags = 0;
+    if time.real_time.is_some() {
+        flags |= REAL_TIME;
+    }
+    if time.game_time.is_some() {
+        flags |= GAME_TIME;
+    }
+    flags
+}
+
+fn write_uint(buf: &mut Vec<u8>, mut value: u64) {
+    loop {
+        let byte = (value & 0x7F) as u8;
+        value >>= 7;
+        if value == 0 {
+            buf.push(byte);
+            return;
+        }
+        buf.push(byte | 0x80);
+    }
+}
+
+const fn zigzag(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+const fn unzigzag(value: u64) -> i64 {
+    (value >> 1) as i64 ^ -((value & 1) as i64)
+}
+
+fn write_int(buf: &mut Vec<u8>, value: i64) {
+    write_uint(buf, zigzag(value));
+}
+
+fn write_time_span(buf: &mut Vec<u8>, time_span: TimeSpan) {
+    let (seconds, nanoseconds) = time_span.to_seconds_and_subsec_nanoseconds();
+    write_int(buf, seconds);
+    write_int(buf, nanoseconds.into());
+}
+
+fn write_time(buf: &mut Vec<u8>, time: Time) {
+    if let Some(real_time) = time.real_time {
+        write_time_span(buf, real_time);
+    }
+    if let Some(game_time) = time.game_time {
+        write_time_span(buf, game_time);
+    }
+}
+
+fn write_date_time(buf: &mut Vec<u8>, date_time: DateTime) {
+    write_int(buf, date_time.unix_timestamp());
+    write_uint(buf, date_time.nanosecond().into());
+}
+
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn bytes(&mut self, len: usize) -> Result<&'a [u8], HistoryBlobError> {
+        ensure!(self.0.len() >= len, UnexpectedEnd);
+        let (bytes, rem) = self.0.split_at(len);
+        self.0 = rem;
+        Ok(bytes)
+    }
+
+    fn byte(&mut self) -> Result<u8, HistoryBlobError> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    fn uint(&mut self) -> Result<u64, HistoryBlobError> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.byte()?;
+            value |= u64::from(byte & 0x7F) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        InvalidValue.fail()
+    }
+
+    fn int(&mut self) -> Result<i64, HistoryBlobError> {
+        Ok(unzigzag(self.uint()?))
+    }
+
+    fn i32(&mut self) -> Result<i32, HistoryBlobError> {
+        i32::try_from(self.int()?).ok().context(InvalidValue)
+    }
+
+    fn time_span(&mut self) -> Result<TimeSpan, HistoryBlobError> {
+        let seconds = self.int()?;
+        let nanoseconds = self.i32()?;
+        // Both components always share the same sign, which ensures that the
+        // duration doesn't need to be normalized.
+        ensure!(
+            nanoseconds.unsigned_abs() < 1_000_000_000
+                && (seconds == 0 || nanoseconds == 0 || (seconds < 0) == (nanoseconds < 0)),
+            InvalidValue
+        );
+        Ok(Duration::new(seconds, nanoseconds).into())
+    }
+
+    fn time(&mut self, flags: u8) -> Result<Time, HistoryBlobError> {
+        let real_time = if flags & REAL_TIME != 0 {
+            Some(self.time_span()?)
+        } else {
+            None
+        };
+        let game_time = if flags & GAME_TIME != 0 {
+            Some(self.time_span()?)
+        } else {
+            None
+        };
+        Ok(Time {
+            real_time,
+            game_time,
+        })
+    }
+
+    fn date_time(&mut self) -> Result<DateTime, HistoryBlobError> {
+        let seconds = self.int()?;
+        let nanoseconds = u32::try_from(self.uint()?).ok().context(InvalidValue)?;
+        let date_time = DateTime::from_unix_timestamp(seconds)
+            .ok()
+            .context(InvalidValue)?;
+        date_time
+            .replace_nanosecond(nanoseconds)
+            .ok()
+            .context(InvalidValue)
+    }
+}
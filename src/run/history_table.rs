@@ -0,0 +1,54 @@
+//! Provides an export of the segment histories of a [`Run`] as a long-format
+//! table, where each row describes the segment time of a single segment in a
+//! single attempt. This is the format most statistics tools expect.
+
+use super::Run;
+use crate::{TimeSpan, TimingMethod, platform::prelude::*};
+
+impl Run {
+    /// Exports the segment histories as a long-format table. There is a row
+    /// for every segment of every attempt in the Attempt History, consisting
+    /// of the attempt's index, the segment's index, and the segment time for
+    /// the timing method provided. The segment time is [`None`] if the segment
+    /// was skipped or the attempt didn't reach the segment. The rows are
+    /// ordered by attempt first and by segment second.
+    pub fn history_table(&self, method: TimingMethod) -> Vec<(i32, usize, Option<TimeSpan>)> {
+        self.attempt_history
+            .iter()
+            .flat_map(|attempt| {
+                let attempt_index = attempt.index();
+                self.segments
+                    .iter()
+                    .enumerate()
+                    .map(move |(segment_index, segment)| {
+                        let time = segment.segment_history().get(attempt_index);
+                        (
+                            attempt_index,
+                            segment_index,
+                            time.and_then(|time| time[method]),
+                        )
+                    })
+            })
+            .collect()
+    }
+
+    /// Writes the table of [`history_table`](Self::history_table) as CSV. The
+    /// first line is a header naming the columns. The segment times are
+    /// written as seconds, with empty fields for the missing segment times.
+    #[cfg(feature = "std")]
+    pub fn write_history_csv<W: std::io::Write>(
+        &self,
+        mut writer: W,
+        method: TimingMethod,
+    ) -> std::io::Result<()> {
+        writeln!(writer, "attempt_id,segment_index,segment_time")?;
+        for (attempt_index, segment_index, time) in self.history_table(method) {
+            write!(writer, "{attempt_index},{segment_index},")?;
+            if let Some(time) = time {
+                write!(writer, "{}", time.total_seconds())?;
+            }
+            writeln!(writer)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,39 @@
+use crate::{AtomicDateTime, platform::prelude::*};
+use core::{
+    fmt::Write,
+    sync::atomic::{self, AtomicUsize},
+};
+use sha2::{Digest, Sha256};
+
+/// Generates a new identifier that is formatted like a version 4 UUID. There is
+/// no source of randomness available, so the current time, a counter and some
+/// memory addresses are hashed instead, which is unique enough for telling
+/// splits files apart.
+pub fn generate() -> String {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    let count = COUNTER.fetch_add(1, atomic::Ordering::Relaxed);
+    let now = AtomicDateTime::now().time.unix_timestamp_nanos();
+    let mut id = String::with_capacity(36);
+
+    let mut hasher = Sha256::new();
+    hasher.update(now.to_le_bytes());
+    hasher.update(count.to_le_bytes());
+    hasher.update((&raw const count as usize).to_le_bytes());
+    hasher.update((id.as_ptr() as usize).to_le_bytes());
+    let hash = hasher.finalize();
+
+    let mut bytes = [0; 16];
+    bytes.copy_from_slice(&hash[..16]);
+    bytes[6] = (bytes[6] & 0x0F) | 0x40;
+    bytes[8] = (bytes[8] & 0x3F) | 0x80;
+
+    for (i, byte) in bytes.into_iter().enumerate() {
+        if matches!(i, 4 | 6 | 8 | 10) {
+            id.push('-');
+        }
+        let _ = write!(id, "{byte:02x}");
+    }
+
+    id
+}
@@ -15,35 +15,46 @@
 //! ```
 
 mod attempt;
+mod checksum;
 mod comparisons;
 pub mod editor;
+mod history_blob;
+mod history_table;
+mod id;
 mod linked_layout;
 pub mod parser;
+mod prune_history;
 mod run_metadata;
 pub mod saver;
 mod segment;
 mod segment_history;
+mod transaction;
 
 #[cfg(test)]
 mod tests;
 
 pub use attempt::Attempt;
+pub use checksum::ChecksumVerification;
 pub use comparisons::Comparisons;
 pub use editor::{Editor, RenameError};
+pub use history_blob::HistoryBlobError;
 pub use linked_layout::LinkedLayout;
-pub use run_metadata::{CustomVariable, RunMetadata};
+pub use prune_history::PrunePolicy;
+pub use run_metadata::{CustomVariable, MetadataVariable, RunMetadata};
 pub use segment::Segment;
 pub use segment_history::SegmentHistory;
+pub use transaction::Transaction;
 
 use crate::{
     AtomicDateTime, Time, TimeSpan, TimingMethod,
     comparison::{ComparisonGenerator, RACE_COMPARISON_PREFIX, default_generators, personal_best},
     platform::prelude::*,
     settings::Image,
+    timing::formatter::Accuracy,
     util::{PopulateString, caseless::matches_ascii_key},
 };
 use alloc::borrow::Cow;
-use core::{cmp::max, fmt};
+use core::{cmp::max, fmt, mem::swap};
 use hashbrown::HashSet;
 
 /// A Run stores the split times for a specific game and category of a runner.
@@ -77,6 +88,22 @@ pub struct Run {
     comparison_generators: ComparisonGenerators,
     auto_splitter_settings: String,
     linked_layout: Option<LinkedLayout>,
+    time_precision_hint: Option<Accuracy>,
+    default_timing_method: TimingMethod,
+    id: RunId,
+    edit_state: transaction::EditState,
+}
+
+/// The unique identifier of a Run. It is not considered when comparing Runs,
+/// as the same splits are equal regardless of whether an identifier got
+/// generated for them yet.
+#[derive(Clone, Debug, Default)]
+struct RunId(Option<String>);
+
+impl PartialEq for RunId {
+    fn eq(&self, _: &RunId) -> bool {
+        true
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -100,6 +127,15 @@ pub enum AddComparisonError {
     DuplicateName,
 }
 
+/// Error type for setting the Personal Best from an attempt.
+#[derive(PartialEq, Eq, Debug, snafu::Snafu)]
+pub enum SetPersonalBestError {
+    /// There is no attempt with the provided ID in the attempt history.
+    AttemptNotFound,
+    /// The attempt was not completed, so it can't be the Personal Best.
+    AttemptNotCompleted,
+}
+
 /// Error type for copying a comparison.
 #[derive(PartialEq, Eq, Debug, snafu::Snafu)]
 pub enum CopyComparisonError {
@@ -112,6 +148,33 @@ pub enum CopyComparisonError {
     },
 }
 
+/// Error type for setting a goal comparison.
+#[derive(PartialEq, Eq, Debug, snafu::Snafu)]
+pub enum SetGoalComparisonError {
+    /// The amount of split times doesn't match the amount of segments.
+    SplitCountMismatch,
+    /// The comparison could not be added.
+    AddComparison {
+        /// The underlying error.
+        source: AddComparisonError,
+    },
+}
+
+/// A problem found when validating the comparisons of a run. The split time of
+/// a segment in a comparison is lower than the split time of an earlier segment,
+/// which means that the comparison's times decrease from one split to the next.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ComparisonWarning {
+    /// The name of the comparison the problem was found in.
+    pub comparison: String,
+    /// The index of the segment whose split time is lower than the one before.
+    pub segment_index: usize,
+    /// The most recent split time before the segment in the comparison.
+    pub previous_time: TimeSpan,
+    /// The split time of the segment in the comparison.
+    pub time: TimeSpan,
+}
+
 impl Run {
     /// Creates a new Run object with no segments.
     #[inline]
@@ -131,9 +194,44 @@ impl Run {
             comparison_generators: ComparisonGenerators(default_generators()),
             auto_splitter_settings: String::new(),
             linked_layout: None,
+            time_precision_hint: None,
+            default_timing_method: TimingMethod::RealTime,
+            id: RunId(Some(id::generate())),
+            edit_state: transaction::EditState::default(),
         }
     }
 
+    /// Accesses the unique identifier of this Run. A new identifier gets
+    /// generated when the Run is created. It is stored in the splits file, so
+    /// it stays the same across saving and loading the splits, even if the
+    /// file gets renamed. Splits files that don't store an identifier get a
+    /// newly generated one when they are loaded. This makes it suitable for
+    /// matching up splits files, for example when synchronizing them. The
+    /// identifier is not considered when comparing Runs.
+    #[inline]
+    pub fn id(&self) -> Option<&str> {
+        self.id.0.as_deref()
+    }
+
+    /// Accesses the unique identifier of this Run and generates a new one if
+    /// it doesn't have one yet. The identifier is derived from the current
+    /// time, a counter and some memory addresses rather than from a source of
+    /// randomness. Use [`set_id`](Self::set_id) to provide a random identifier
+    /// instead.
+    pub fn ensure_id(&mut self) -> &str {
+        self.id.0.get_or_insert_with(id::generate)
+    }
+
+    /// Sets the unique identifier of this Run. This is usually only done by
+    /// the parser of the splits file, in order to preserve the identifier that
+    /// got stored.
+    pub fn set_id<S>(&mut self, id: S)
+    where
+        S: PopulateString,
+    {
+        id.populate(self.id.0.get_or_insert_with(String::new));
+    }
+
     /// Accesses the name of the game this Run is for.
     #[inline]
     #[allow(clippy::missing_const_for_fn)] // FIXME: Can't reason about Deref
@@ -219,6 +317,48 @@ impl Run {
         &mut self.metadata
     }
 
+    /// Sets the metadata variable with the name specified to the value
+    /// specified. Metadata variables are the speedrun.com variables of the Run,
+    /// such as the platform, the region or whether the run is glitchless. If the
+    /// variable doesn't exist yet, it is being inserted.
+    pub fn set_metadata_variable<N, V>(&mut self, name: N, value: V)
+    where
+        N: PopulateString,
+        V: PopulateString,
+    {
+        self.metadata.set_speedrun_com_variable(name, value);
+    }
+
+    /// Cleans up the metadata of the Run. This is useful for runs that got
+    /// imported from messy splits files. See
+    /// [`RunMetadata::canonicalize`] for more information.
+    pub fn canonicalize_metadata(&mut self) {
+        self.metadata.canonicalize();
+    }
+
+    /// Accesses the value of the metadata variable with the name specified if
+    /// there is one.
+    pub fn metadata_variable(&self, name: &str) -> Option<&str> {
+        self.metadata.speedrun_com_variable(name)
+    }
+
+    /// Removes the metadata variable with the name specified. Nothing happens
+    /// if the variable does not exist.
+    pub fn remove_metadata_variable(&mut self, name: &str) {
+        self.metadata.remove_speedrun_com_variable(name);
+    }
+
+    /// Returns an iterator iterating over all the metadata variables of the
+    /// Run in the order they were inserted.
+    pub fn metadata_variables(&self) -> impl Iterator<Item = MetadataVariable<'_>> {
+        self.metadata
+            .speedrun_com_variables()
+            .map(|(name, value)| MetadataVariable {
+                name,
+                value: value.as_str(),
+            })
+    }
+
     /// Sets the time an attempt of this Run should start at.
     #[inline]
     pub const fn set_offset(&mut self, offset: TimeSpan) {
@@ -257,6 +397,27 @@ impl Run {
         self.segments.push(segment);
     }
 
+    /// Removes all the segments at the end of the Run that have no name, icon,
+    /// notes or variables and don't store any times. Editors sometimes leave such blank segments
+    /// behind. The first segment is always kept, so the Run can still be used
+    /// by a [`Timer`](crate::Timer). If any segments got removed, the
+    /// comparisons are regenerated. Returns the number of segments that got
+    /// removed.
+    pub fn trim_trailing_empty_segments(&mut self) -> usize {
+        let kept = self
+            .segments
+            .iter()
+            .rposition(|segment| !segment.is_empty())
+            .map_or(1, |index| index + 1);
+
+        let removed = self.segments.len().saturating_sub(kept);
+        if removed != 0 {
+            self.segments.truncate(kept);
+            self.regenerate_comparisons();
+        }
+        removed
+    }
+
     /// Accesses a certain segment of this Run.
     ///
     /// # Panics
@@ -277,6 +438,91 @@ impl Run {
         &mut self.segments[index]
     }
 
+    /// Moves the segment at the index `from` to the index `to`, shifting the
+    /// segments in between. The segment keeps its Segment History, Best
+    /// Segment Time and icon, while the comparison times of all the affected
+    /// segments are recalculated so that each segment keeps its own segment
+    /// time in every comparison.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either index is out of bounds.
+    pub fn move_segment(&mut self, from: usize, to: usize) {
+        assert!(from < self.len() && to < self.len());
+
+        if from < to {
+            for index in from..to {
+                self.switch_segments(index);
+            }
+        } else {
+            for index in (to..from).rev() {
+                self.switch_segments(index);
+            }
+        }
+
+        self.fix_splits();
+    }
+
+    /// Replaces the split times of the Personal Best for the [`TimingMethod`]
+    /// specified with the split times of the attempt with the given ID. The
+    /// split times are reconstructed from the segment times recorded in the
+    /// Segment History. The Best Segment Times are not modified. Segments that
+    /// were skipped in the attempt don't have a split time in the Personal
+    /// Best. The attempt needs to be completed for the [`TimingMethod`]
+    /// specified. If the final time of the Personal Best changes, the
+    /// speedrun.com Run ID is cleared.
+    pub fn set_pb_from_attempt(
+        &mut self,
+        attempt_id: i32,
+        method: TimingMethod,
+    ) -> Result<(), SetPersonalBestError> {
+        let attempt = self
+            .attempt_history
+            .iter()
+            .find(|attempt| attempt.index() == attempt_id)
+            .ok_or(SetPersonalBestError::AttemptNotFound)?;
+
+        if attempt.time()[method].is_none()
+            || self
+                .segments
+                .last()
+                .and_then(|segment| segment.segment_history().get(attempt_id))
+                .is_none_or(|time| time[method].is_none())
+        {
+            return Err(SetPersonalBestError::AttemptNotCompleted);
+        }
+
+        let previous_final_time = self
+            .segments
+            .last()
+            .and_then(|segment| segment.personal_best_split_time()[method]);
+
+        let mut split_time = TimeSpan::zero();
+        for segment in &mut self.segments {
+            let segment_time = segment
+                .segment_history()
+                .get(attempt_id)
+                .and_then(|time| time[method]);
+            segment.personal_best_split_time_mut()[method] = segment_time.map(|segment_time| {
+                split_time += segment_time;
+                split_time
+            });
+        }
+
+        if self
+            .segments
+            .last()
+            .and_then(|segment| segment.personal_best_split_time()[method])
+            != previous_final_time
+        {
+            self.clear_run_id();
+        }
+
+        self.regenerate_comparisons();
+
+        Ok(())
+    }
+
     /// Accesses the history of all the runs that have been attempted. This does
     /// not store the actual segment times, just the overall attempt
     /// information. Information about the individual segments is stored within
@@ -394,6 +640,40 @@ impl Run {
         self.linked_layout = linked_layout;
     }
 
+    /// Accesses the precision the times of this `Run` were stored with, if the
+    /// `Run` was imported from a format that stores its times with a lower
+    /// precision than milliseconds. This can be used to avoid visualizing the
+    /// times with more precision than they actually have.
+    #[inline]
+    pub const fn time_precision_hint(&self) -> Option<Accuracy> {
+        self.time_precision_hint
+    }
+
+    /// Sets the precision the times of this `Run` were stored with. Specify
+    /// [`None`] if the times are not known to be stored with a lower precision
+    /// than milliseconds.
+    #[inline]
+    pub const fn set_time_precision_hint(&mut self, precision: Option<Accuracy>) {
+        self.time_precision_hint = precision;
+    }
+
+    /// Accesses the timing method this `Run` is supposed to be timed with by
+    /// default. A [`Timer`](crate::Timer) that gets created for this `Run`
+    /// starts out with this timing method. This is [`TimingMethod::RealTime`]
+    /// by default.
+    #[inline]
+    pub const fn default_timing_method(&self) -> TimingMethod {
+        self.default_timing_method
+    }
+
+    /// Sets the timing method this `Run` is supposed to be timed with by
+    /// default. Runs of games with loading times that get removed for example
+    /// may want to default to [`TimingMethod::GameTime`].
+    #[inline]
+    pub const fn set_default_timing_method(&mut self, method: TimingMethod) {
+        self.default_timing_method = method;
+    }
+
     /// Returns the amount of segments stored in this Run.
     #[inline]
     pub fn len(&self) -> usize {
@@ -465,6 +745,17 @@ impl Run {
         self.attempt_history.push(attempt);
     }
 
+    /// Removes the Attempt with the History Index provided from the Attempt
+    /// History, along with the segment times it stored in the Segment
+    /// Histories.
+    pub(crate) fn remove_attempt(&mut self, index: i32) {
+        self.attempt_history
+            .retain(|attempt| attempt.index() != index);
+        for segment in &mut self.segments {
+            segment.segment_history_mut().remove(index);
+        }
+    }
+
     /// Clears the speedrun.com Run ID of this Run, as the current Run does not
     /// reflect the run on speedrun.com anymore. This may be the case if a new
     /// Personal Best is achieved for example.
@@ -485,14 +776,125 @@ impl Run {
         Ok(())
     }
 
+    /// Sets the split times of a goal comparison, such as the world record or
+    /// a target time, with the name provided. The comparison is stored as a
+    /// custom comparison, so it is saved with the splits and can be selected
+    /// like any other comparison. If a custom comparison with that name
+    /// already exists, its split times are replaced. There needs to be exactly
+    /// one split time per segment. The Personal Best can't be replaced this
+    /// way.
+    pub fn set_goal_comparison<S>(
+        &mut self,
+        comparison: S,
+        split_times: &[Time],
+    ) -> Result<(), SetGoalComparisonError>
+    where
+        S: PopulateString,
+    {
+        if split_times.len() != self.segments.len() {
+            return Err(SetGoalComparisonError::SplitCountMismatch);
+        }
+
+        let name = comparison.as_str();
+        if name == personal_best::NAME {
+            return Err(SetGoalComparisonError::AddComparison {
+                source: AddComparisonError::DuplicateName,
+            });
+        }
+
+        let name = if self.custom_comparisons.iter().any(|c| c == name) {
+            name
+        } else {
+            self.add_custom_comparison(comparison)
+                .map_err(|source| SetGoalComparisonError::AddComparison { source })?;
+            self.custom_comparisons.last().unwrap()
+        };
+
+        for (segment, &time) in self.segments.iter_mut().zip(split_times) {
+            *segment.comparison_mut(name) = time;
+        }
+
+        Ok(())
+    }
+
+    /// Shifts all the split times of the comparison provided by the same
+    /// amount of time for the timing method specified. Split times that would
+    /// become negative are clamped to zero. Segments without a split time in
+    /// the comparison are left empty. Nothing else, such as the best segment
+    /// times or the segment history, is recalculated.
+    pub fn shift_comparison(&mut self, comparison: &str, delta: TimeSpan, method: TimingMethod) {
+        for segment in &mut self.segments {
+            if let Some(time) = segment.comparison(comparison)[method] {
+                segment.comparison_mut(comparison)[method] =
+                    Some(max(time + delta, TimeSpan::zero()));
+            }
+        }
+    }
+
     /// Recalculates all the comparison times the Comparison Generators provide.
-    #[inline]
+    /// While a transaction is active, this is deferred until the transaction
+    /// ends.
     pub fn regenerate_comparisons(&mut self) {
+        if self.edit_state.depth != 0 {
+            self.edit_state.regenerate_pending = true;
+        } else {
+            self.regenerate_comparisons_now();
+            self.edit_state.generation += 1;
+        }
+    }
+
+    fn regenerate_comparisons_now(&mut self) {
         for generator in &mut self.comparison_generators.0 {
             generator.generate(&mut self.segments, &self.attempt_history);
         }
     }
 
+    /// Begins a transaction for applying a batch of edits. Fixing the splits
+    /// and regenerating the comparisons is deferred while the transaction is
+    /// active, so they only happen once when it is dropped rather than after
+    /// each individual edit. Transactions can be nested, in which case the
+    /// recomputation happens when the outermost transaction is dropped.
+    pub fn begin_edit(&mut self) -> Transaction<'_> {
+        Transaction::new(self)
+    }
+
+    /// Returns whether a transaction started by [`begin_edit`](Self::begin_edit)
+    /// is currently active.
+    pub const fn is_editing(&self) -> bool {
+        self.edit_state.depth != 0
+    }
+
+    /// Returns the generation of the Run. The generation is increased every
+    /// time the splits are fixed or the comparisons are regenerated. A
+    /// transaction increases it at most once, when it ends. Other modifications
+    /// of the Run don't change the generation.
+    #[inline]
+    pub const fn generation(&self) -> u64 {
+        self.edit_state.generation
+    }
+
+    const fn start_edit(&mut self) {
+        self.edit_state.depth += 1;
+    }
+
+    fn end_edit(&mut self) {
+        self.edit_state.depth -= 1;
+        if self.edit_state.depth != 0 {
+            return;
+        }
+        let fix = core::mem::take(&mut self.edit_state.fix_pending);
+        let regenerate = core::mem::take(&mut self.edit_state.regenerate_pending);
+        if fix {
+            self.fix_splits_now();
+        }
+        if regenerate {
+            self.regenerate_comparisons_now();
+        }
+        if fix || regenerate {
+            self.edit_state.generation += 1;
+        }
+    }
+
     /// Returns a file name (without the extension) suitable for this Run that
     /// is built the following way:
     ///
@@ -564,8 +966,18 @@ impl Run {
 
     /// Applies some fixing algorithms on the Run. This includes fixing the
     /// comparison times and history, removing duplicates in the segment
-    /// histories and removing empty times.
+    /// histories and removing empty times. While a transaction is active, this
+    /// is deferred until the transaction ends.
     pub fn fix_splits(&mut self) {
+        if self.edit_state.depth != 0 {
+            self.edit_state.fix_pending = true;
+        } else {
+            self.fix_splits_now();
+            self.edit_state.generation += 1;
+        }
+    }
+
+    fn fix_splits_now(&mut self) {
         for method in TimingMethod::all() {
             self.fix_comparison_times_and_history(method);
         }
@@ -786,6 +1198,32 @@ impl Run {
         }
     }
 
+    /// Checks all the comparisons for split times that decrease from one split
+    /// to the next for the timing method provided. Each segment whose split
+    /// time is lower than the most recent split time before it is reported.
+    /// Segments without a split time in the comparison are skipped. This
+    /// doesn't modify the run in any way.
+    pub fn validate_comparisons(&self, method: TimingMethod) -> Vec<ComparisonWarning> {
+        let mut warnings = Vec::new();
+        for comparison in self.comparisons() {
+            let mut previous_time = TimeSpan::zero();
+            for (segment_index, segment) in self.segments.iter().enumerate() {
+                if let Some(time) = segment.comparison(comparison)[method] {
+                    if time < previous_time {
+                        warnings.push(ComparisonWarning {
+                            comparison: comparison.into(),
+                            segment_index,
+                            previous_time,
+                            time,
+                        });
+                    }
+                    previous_time = time;
+                }
+            }
+        }
+        warnings
+    }
+
     /// Checks a given name against the current comparisons in the Run to
     /// ensure that it is valid for use.
     pub fn validate_comparison_name(&self, new: &str) -> Result<(), AddComparisonError> {
@@ -798,6 +1236,52 @@ impl Run {
         }
     }
 
+    /// Swaps the segment at the index provided with the one following it,
+    /// keeping the segment times of both segments in all the comparisons.
+    pub(crate) fn switch_segments(&mut self, index: usize) {
+        let max_index = self.max_attempt_history_index().unwrap_or(0);
+        let min_index = self.min_segment_history_index().unwrap();
+
+        // Use split_at to prove that the 2 segments and the one before if it
+        // exists are distinct
+        let (a, b) = self.segments.split_at_mut(index);
+        let previous = a.last();
+        let [first, second, ..] = b else {
+            panic!("There need to be at least 2 segments to switch them.");
+        };
+
+        for run_index in min_index..=max_index {
+            // Remove both segment history elements if one of them has a None
+            // time and the other has has a non None time
+            let first_history = first.segment_history().get(run_index);
+            let second_history = second.segment_history().get(run_index);
+            if let (Some(first_history), Some(second_history)) = (first_history, second_history) {
+                if first_history.real_time.is_some() != second_history.real_time.is_some()
+                    || first_history.game_time.is_some() != second_history.game_time.is_some()
+                {
+                    first.segment_history_mut().remove(run_index);
+                    second.segment_history_mut().remove(run_index);
+                }
+            }
+        }
+
+        for (comparison, first_time) in first.comparisons_mut().iter_mut() {
+            // Fix the comparison times based on the new positions of the two
+            // segments
+            let previous_time = previous
+                .map(|p| p.comparison(comparison))
+                .unwrap_or_else(Time::zero);
+
+            let second_time = second.comparison_mut(comparison);
+            let first_segment_time = *first_time - previous_time;
+            let second_segment_time = *second_time - *first_time;
+            *second_time = previous_time + second_segment_time;
+            *first_time = *second_time + first_segment_time;
+        }
+
+        swap(first, second);
+    }
+
     fn reattach_unattached_segment_history_elements(&mut self) {
         let max_id = self.max_attempt_history_index().unwrap_or_default();
         let mut min_id = self.min_segment_history_index().unwrap_or_default();
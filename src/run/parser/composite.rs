@@ -146,6 +146,10 @@ pub fn parse<'source>(
             return Ok(parsed(run, TimerKind::Flitter));
         }
 
+        if let Ok(run) = flitter::parse_toml(source) {
+            return Ok(parsed(run, TimerKind::Flitter));
+        }
+
         if let Ok(run) = source_live_timer::parse(source) {
             return Ok(parsed(run, TimerKind::SourceLiveTimer));
         }
@@ -1,4 +1,5 @@
-//! Provides the parser for Flitter splits files.
+//! Provides the parser for Flitter splits files. Both the JSON based format
+//! and the TOML based format are supported.
 
 use crate::{
     platform::prelude::*,
@@ -9,7 +10,7 @@ use alloc::borrow::Cow;
 use core::result::Result as StdResult;
 use serde_derive::Deserialize;
 use serde_json::Error as JsonError;
-use snafu::ensure;
+use snafu::{OptionExt, ensure};
 
 /// The Error type for splits files that couldn't be parsed by the Flitter
 /// Parser.
@@ -34,6 +35,10 @@ pub enum Error {
     PersonalBestCountMismatch,
     /// The last split of the personal best can't be null.
     LastSplitNull,
+    /// A line of the TOML file could not be parsed.
+    InvalidToml,
+    /// Failed to parse the amount of attempts.
+    Attempts,
 }
 
 /// The Result type for the Flitter Parser.
@@ -132,3 +137,137 @@ pub fn parse(source: &str) -> Result<Run> {
 
     Ok(run)
 }
+
+/// Attempts to parse a Flitter splits file that is stored in the TOML format.
+/// Each segment is stored in its own `[[splits]]` table, consisting of its
+/// `title`, the split time of the personal best (`pb`) and its best segment
+/// time (`gold`). Any other tables are skipped.
+pub fn parse_toml(source: &str) -> Result<Run> {
+    let mut run = Run::new();
+    let mut table = TomlTable::Root;
+
+    for line in source.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if line.starts_with('[') {
+            let header = strip_toml_comment(line);
+            ensure!(header.len() > 2 && header.ends_with(']'), InvalidToml);
+            table = if header == "[[splits]]" {
+                run.push_segment(Segment::new(""));
+                TomlTable::Splits
+            } else {
+                TomlTable::Other
+            };
+            continue;
+        }
+
+        if table == TomlTable::Other {
+            continue;
+        }
+
+        let (key, value) = line.split_once('=').context(InvalidToml)?;
+        let key = key.trim();
+        let value = value.trim();
+
+        if table == TomlTable::Splits {
+            let segment = run.segments_mut().last_mut().unwrap();
+            match key {
+                "title" => segment.set_name(parse_toml_string(value).context(InvalidToml)?),
+                "pb" => {
+                    let time = parse_toml_string(value).context(InvalidToml)?;
+                    if let Some(time) = parse_time(&time) {
+                        segment.set_personal_best_split_time(time);
+                    }
+                }
+                "gold" => {
+                    let time = parse_toml_string(value).context(InvalidToml)?;
+                    if let Some(time) = parse_time(&time) {
+                        segment.set_best_segment_time(time);
+                    }
+                }
+                _ => {}
+            }
+        } else {
+            match key {
+                "title" => run.set_game_name(parse_toml_string(value).context(InvalidToml)?),
+                "category" => {
+                    run.set_category_name(parse_toml_string(value).context(InvalidToml)?)
+                }
+                "attempts" => run.set_attempt_count(
+                    strip_toml_comment(value)
+                        .parse()
+                        .ok()
+                        .context(Attempts)?,
+                ),
+                _ => {}
+            }
+        }
+    }
+
+    ensure!(!run.is_empty(), SplitNamesEmpty);
+
+    Ok(run)
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum TomlTable {
+    Root,
+    Splits,
+    Other,
+}
+
+fn strip_toml_comment(value: &str) -> &str {
+    match value.split_once('#') {
+        Some((value, _)) => value.trim_end(),
+        None => value,
+    }
+}
+
+fn parse_toml_string(value: &str) -> Option<Cow<'_, str>> {
+    if let Some(rest) = value.strip_prefix('\'') {
+        // Literal strings don't support any escape sequences.
+        let (string, trailing) = rest.split_once('\'')?;
+        return strip_toml_comment(trailing)
+            .trim()
+            .is_empty()
+            .then_some(Cow::Borrowed(string));
+    }
+
+    let rest = value.strip_prefix('"')?;
+    let mut unescaped: Option<String> = None;
+    let mut chars = rest.char_indices();
+
+    while let Some((index, c)) = chars.next() {
+        match c {
+            '"' => {
+                if !strip_toml_comment(&rest[index + 1..]).trim().is_empty() {
+                    return None;
+                }
+                return Some(match unescaped {
+                    Some(unescaped) => Cow::Owned(unescaped),
+                    None => Cow::Borrowed(&rest[..index]),
+                });
+            }
+            '\\' => {
+                let unescaped = unescaped.get_or_insert_with(|| rest[..index].to_owned());
+                unescaped.push(match chars.next()?.1 {
+                    '"' => '"',
+                    '\\' => '\\',
+                    'n' => '\n',
+                    't' => '\t',
+                    _ => return None,
+                });
+            }
+            c => {
+                if let Some(unescaped) = &mut unescaped {
+                    unescaped.push(c);
+                }
+            }
+        }
+    }
+
+    None
+}
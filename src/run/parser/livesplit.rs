@@ -15,7 +15,7 @@ use crate::{
             Reader,
         },
     },
-    AtomicDateTime, DateTime, Run, RunMetadata, Segment, Time, TimeSpan,
+    AtomicDateTime, DateTime, Run, Segment, Time, TimeSpan, TimingMethod,
 };
 use alloc::borrow::Cow;
 use core::{mem::MaybeUninit, str};
@@ -228,42 +228,51 @@ fn parse_bool(value: &str) -> Result<bool> {
     }
 }
 
-fn parse_metadata(
-    version: Version,
-    reader: &mut Reader<'_>,
-    metadata: &mut RunMetadata,
-) -> Result<()> {
+fn parse_metadata(version: Version, reader: &mut Reader<'_>, run: &mut Run) -> Result<()> {
     if version >= Version(1, 6, 0, 0) {
         parse_children(reader, |reader, tag, attributes| match tag.name() {
+            "Id" => text(reader, |t| {
+                // An empty identifier is treated like a missing one, so the run
+                // keeps the identifier that got generated for it.
+                if !t.is_empty() {
+                    run.set_id(t);
+                }
+            }),
             "Run" => {
-                type_hint(attribute(attributes, "id", |t| metadata.set_run_id(t)))?;
+                type_hint(attribute(attributes, "id", |t| {
+                    run.metadata_mut().set_run_id(t)
+                }))?;
                 end_tag(reader)
             }
             "Platform" => {
                 type_hint(attribute_escaped_err(attributes, "usesEmulator", |t| {
-                    metadata.set_emulator_usage(parse_bool(t)?);
+                    run.metadata_mut().set_emulator_usage(parse_bool(t)?);
                     Ok(())
                 }))?;
-                text(reader, |t| metadata.set_platform_name(t))
+                text(reader, |t| run.metadata_mut().set_platform_name(t))
             }
-            "Region" => text(reader, |t| metadata.set_region_name(t)),
+            "Region" => text(reader, |t| run.metadata_mut().set_region_name(t)),
             "Variables" | "SpeedrunComVariables" => {
                 parse_children(reader, |reader, _, attributes| {
                     let mut name = Cow::Borrowed("");
                     let mut value = Cow::Borrowed("");
                     type_hint(attribute(attributes, "name", |t| name = t))?;
                     type_hint(text(reader, |t| value = t))?;
-                    metadata.set_speedrun_com_variable(name, value);
+                    run.metadata_mut().set_speedrun_com_variable(name, value);
                     Ok(())
                 })
             }
             "CustomVariables" => parse_children(reader, |reader, _, attributes| {
                 let mut name = Cow::Borrowed("");
                 type_hint(attribute(attributes, "name", |t| name = t))?;
-                let var = metadata.custom_variable_mut(name).permanent();
+                let var = run.metadata_mut().custom_variable_mut(name).permanent();
                 type_hint(text(reader, |t| var.set_value(t)))?;
                 Ok(())
             }),
+            "Checksum" => text(reader, |t| {
+                run.metadata_mut()
+                    .set_checksum(u32::from_str_radix(&t, 16).ok());
+            }),
             _ => end_tag(reader),
         })
     } else {
@@ -281,6 +290,7 @@ fn parse_segment(
 
     parse_children(reader, |reader, tag, _| match tag.name() {
         "Name" => text(reader, |t| segment.set_name(t)),
+        "Notes" => text(reader, |t| segment.set_notes(t)),
         "Icon" => image(reader, image_buf, |i| {
             segment.set_icon(Image::new(i.into(), Image::ICON))
         }),
@@ -465,7 +475,7 @@ pub fn parse(source: &str) -> Result<Run> {
             }
             "AttemptHistory" => parse_attempt_history(version, reader, &mut run),
             "RunHistory" => parse_run_history(version, reader, &mut run),
-            "Metadata" => parse_metadata(version, reader, run.metadata_mut()),
+            "Metadata" => parse_metadata(version, reader, &mut run),
             "Segments" => {
                 required_flags |= 1 << 5;
                 parse_children(reader, |reader, tag, _| {
@@ -482,6 +492,13 @@ pub fn parse(source: &str) -> Result<Run> {
                 let settings = run.auto_splitter_settings_mut();
                 reencode_children(reader, settings).map_err(Into::into)
             }
+            "DefaultTimingMethod" => text(reader, |t| {
+                run.set_default_timing_method(if t == "GameTime" {
+                    TimingMethod::GameTime
+                } else {
+                    TimingMethod::RealTime
+                });
+            }),
             "LayoutPath" => text(reader, |t| {
                 run.set_linked_layout(if t == "?default" {
                     Some(LinkedLayout::Default)
@@ -1,6 +1,9 @@
 //! Provides the parser for SplitterZ splits files.
 
-use crate::{timing, RealTime, Run, Segment, TimeSpan};
+use crate::{
+    RealTime, Run, Segment, TimeSpan,
+    timing::{self, formatter::Accuracy},
+};
 use alloc::borrow::Cow;
 use core::{num::ParseIntError, result::Result as StdResult};
 use snafu::ResultExt;
@@ -57,6 +60,8 @@ fn unescape(text: &str) -> Cow<'_, str> {
 /// should set this to `true`.
 pub fn parse(source: &str, #[allow(unused)] load_icons: bool) -> Result<Run> {
     let mut run = Run::new();
+    // SplitterZ stores all of its times with hundredths of a second.
+    run.set_time_precision_hint(Some(Accuracy::Hundredths));
 
     #[cfg(feature = "std")]
     let mut icon_buf = Vec::new();
@@ -0,0 +1,102 @@
+//! Provides the means to prune the history of a [`Run`] in order to limit the
+//! size of splits files with a large amount of attempts.
+
+use super::Run;
+use crate::{DateTime, TimingMethod, platform::prelude::*};
+use hashbrown::HashSet;
+
+/// Describes which attempts to keep when pruning the history of a [`Run`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PrunePolicy {
+    /// Keeps the given amount of the most recent attempts.
+    KeepLast(usize),
+    /// Keeps the attempts that started on or after the given date. If it is
+    /// not known when an attempt started, the time it ended is used instead.
+    /// Attempts without either are removed.
+    KeepSince(DateTime),
+    /// Keeps only the attempts that achieved one of the current best segment
+    /// times or the Personal Best, for any of the timing methods.
+    KeepRecords,
+}
+
+impl Run {
+    /// Prunes the Attempt History and the Segment Histories according to the
+    /// policy provided. All the attempts that don't match the policy are
+    /// removed, including their segment times in the Segment Histories. The
+    /// Best Segment Times, the Personal Best, and the other comparisons are
+    /// stored separately from the history, so they are retained. Segment times
+    /// in the Segment Histories that don't belong to any attempt, such as
+    /// imported ones, are retained as well. Returns the amount of attempts
+    /// that were removed.
+    pub fn prune_history(&mut self, policy: PrunePolicy) -> usize {
+        let keep: HashSet<i32> = match policy {
+            PrunePolicy::KeepLast(count) => {
+                let mut indices: Vec<i32> =
+                    self.attempt_history.iter().map(|a| a.index()).collect();
+                indices.sort_unstable();
+                indices.iter().rev().take(count).copied().collect()
+            }
+            PrunePolicy::KeepSince(since) => self
+                .attempt_history
+                .iter()
+                .filter(|attempt| {
+                    attempt
+                        .started()
+                        .or(attempt.ended())
+                        .is_some_and(|date| date.time >= since)
+                })
+                .map(|attempt| attempt.index())
+                .collect(),
+            PrunePolicy::KeepRecords => self.record_attempts(),
+        };
+
+        let before = self.attempt_history.len();
+        self.attempt_history
+            .retain(|attempt| keep.contains(&attempt.index()));
+        let removed = before - self.attempt_history.len();
+
+        if removed != 0 {
+            for segment in &mut self.segments {
+                segment
+                    .segment_history_mut()
+                    .retain(|&(index, _)| index <= 0 || keep.contains(&index));
+            }
+            self.mark_as_modified();
+        }
+
+        removed
+    }
+
+    fn record_attempts(&self) -> HashSet<i32> {
+        let mut records = HashSet::new();
+
+        for method in TimingMethod::all() {
+            for segment in &self.segments {
+                if let Some(best_segment) = segment.best_segment_time()[method] {
+                    records.extend(
+                        segment
+                            .segment_history()
+                            .iter_actual_runs()
+                            .filter(|(_, time)| time[method] == Some(best_segment))
+                            .map(|&(index, _)| index),
+                    );
+                }
+            }
+
+            let personal_best = self
+                .segments
+                .last()
+                .and_then(|segment| segment.personal_best_split_time()[method]);
+            if let Some(personal_best) = personal_best {
+                records.extend(
+                    self.attempt_history
+                        .iter()
+                        .filter(|attempt| attempt.time()[method] == Some(personal_best))
+                        .map(|attempt| attempt.index()),
+                );
+            }
+        }
+
+        records
+    }
+}
@@ -47,6 +47,17 @@ impl CustomVariable {
     }
 }
 
+/// A metadata variable is a speedrun.com variable of a run, such as the
+/// platform, the region or whether the run is glitchless. It is a key value
+/// pair that is borrowed from the [`RunMetadata`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MetadataVariable<'a> {
+    /// The name of the variable.
+    pub name: &'a str,
+    /// The value of the variable.
+    pub value: &'a str,
+}
+
 /// The `RunMetadata` struct stores optional information about a run, like the
 /// platform and region of the game.
 #[derive(Default, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -77,6 +88,7 @@ pub struct RunMetadata {
     /// the runner. Additionally auto splitters or other sources may provide
     /// temporary custom variables that are not stored in the splits files.
     pub custom_variables: Map<CustomVariable>,
+    checksum: Option<u32>,
 }
 
 impl RunMetadata {
@@ -182,6 +194,12 @@ impl RunMetadata {
         self.speedrun_com_variables.iter()
     }
 
+    /// Accesses the value of the speedrun.com variable with the name specified
+    /// if there is one.
+    pub fn speedrun_com_variable(&self, name: &str) -> Option<&str> {
+        self.speedrun_com_variables.get(name).map(String::as_str)
+    }
+
     /// Accesses the custom variable with the name specified if there is one.
     pub fn custom_variable(&self, name: &str) -> Option<&CustomVariable> {
         self.custom_variables.get(name)
@@ -214,6 +232,37 @@ impl RunMetadata {
         self.custom_variables.iter()
     }
 
+    /// Accesses the checksum of the segment and time data that was stored in
+    /// the splits file the run was loaded from, if there is one. Use
+    /// [`Run::verify_checksum`](super::Run::verify_checksum) to check it
+    /// against the run's actual data.
+    #[inline]
+    pub const fn checksum(&self) -> Option<u32> {
+        self.checksum
+    }
+
+    /// Sets the checksum of the segment and time data. This is usually set by
+    /// the parser of the splits file.
+    #[inline]
+    pub const fn set_checksum(&mut self, checksum: Option<u32>) {
+        self.checksum = checksum;
+    }
+
+    /// Cleans up the names of the speedrun.com variables and the custom
+    /// variables. The whitespace around the names gets trimmed and variables
+    /// whose names only differ in their capitalization get merged into a
+    /// single variable, keeping the most recent value. A merged custom
+    /// variable is permanent if any of the merged variables is permanent.
+    pub fn canonicalize(&mut self) {
+        self.speedrun_com_variables
+            .canonicalize_keys(|value, newer| *value = newer);
+        self.custom_variables.canonicalize_keys(|variable, newer| {
+            let is_permanent = variable.is_permanent || newer.is_permanent;
+            *variable = newer;
+            variable.is_permanent = is_permanent;
+        });
+    }
+
     /// Resets all the Metadata Information.
     pub fn clear(&mut self) {
         self.run_id.clear();
@@ -222,5 +271,6 @@ impl RunMetadata {
         self.uses_emulator = false;
         self.speedrun_com_variables.clear();
         self.custom_variables.clear();
+        self.checksum = None;
     }
 }
@@ -30,7 +30,7 @@ use crate::{
     settings::Image,
     timing::formatter::{Complete, TimeFormatter},
     util::xml::{AttributeWriter, DisplayAlreadyEscaped, Text, Writer, NO_ATTRIBUTES},
-    DateTime, Run, Time, Timer, TimerPhase,
+    DateTime, Run, Time, Timer, TimerPhase, TimingMethod,
 };
 use alloc::borrow::Cow;
 use core::{fmt, mem::MaybeUninit};
@@ -38,6 +38,18 @@ use time::UtcOffset;
 
 const LSS_IMAGE_HEADER: &[u8; 156] = include_bytes!("lss_image_header.bin");
 
+/// Specifies the format the icons are stored in when saving a splits file.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum IconFormat {
+    /// The icons are stored exactly as they are.
+    #[default]
+    Original,
+    /// The icons are converted to PNG.
+    Png,
+    /// The icons are converted to WebP with lossless compression.
+    WebpLossless,
+}
+
 const fn bool(value: bool) -> Text<'static> {
     Text::new_escaped(if value { "True" } else { "False" })
 }
@@ -66,6 +78,7 @@ fn image<W: fmt::Write>(
     writer: &mut Writer<W>,
     tag: &str,
     image: &Image,
+    icon_format: IconFormat,
     base64_buf: &mut Vec<MaybeUninit<u8>>,
     image_buf: &mut Cow<'_, [u8]>,
 ) -> fmt::Result {
@@ -75,6 +88,9 @@ fn image<W: fmt::Write>(
             return Ok(());
         }
 
+        let converted = convert_icon(image_data, icon_format);
+        let image_data = converted.as_deref().unwrap_or(image_data);
+
         let len = image_data.len();
         let image_buf = image_buf.to_mut();
         image_buf.truncate(LSS_IMAGE_HEADER.len());
@@ -96,6 +112,62 @@ fn image<W: fmt::Write>(
     })
 }
 
+/// Converts the icon to the format provided. [`None`] is returned if the icon
+/// is supposed to be stored as is, it already is in that format, or it can't be
+/// converted. Animated GIFs are never converted, as they would lose their
+/// animation.
+#[cfg(all(feature = "std", feature = "more-image-formats"))]
+fn convert_icon(data: &[u8], icon_format: IconFormat) -> Option<Vec<u8>> {
+    use crate::util::image::create_reencoder;
+    use image::{
+        ExtendedColorType, ImageEncoder, ImageFormat, codecs::webp::WebPEncoder, guess_format,
+        load_from_memory_with_format,
+    };
+
+    let target_format = match icon_format {
+        IconFormat::Original => return None,
+        IconFormat::Png => ImageFormat::Png,
+        IconFormat::WebpLossless => ImageFormat::WebP,
+    };
+
+    let format = guess_format(data).ok()?;
+    if format == target_format || format == ImageFormat::Gif {
+        return None;
+    }
+
+    let image = load_from_memory_with_format(data, format).ok()?;
+    let mut buf = Vec::new();
+
+    if target_format == ImageFormat::Png {
+        create_reencoder(&mut buf)
+            .write_image(
+                image.as_bytes(),
+                image.width(),
+                image.height(),
+                image.color().into(),
+            )
+            .ok()?;
+    } else {
+        // The WebP encoder only supports 8-bit color channels.
+        let image = image.to_rgba8();
+        WebPEncoder::new_lossless(&mut buf)
+            .write_image(
+                &image,
+                image.width(),
+                image.height(),
+                ExtendedColorType::Rgba8,
+            )
+            .ok()?;
+    }
+
+    Some(buf)
+}
+
+#[cfg(not(all(feature = "std", feature = "more-image-formats")))]
+const fn convert_icon(_: &[u8], _: IconFormat) -> Option<Vec<u8>> {
+    None
+}
+
 fn date<W: fmt::Write>(
     writer: &mut AttributeWriter<'_, W>,
     key: &str,
@@ -169,13 +241,59 @@ pub fn save_timer<W: fmt::Write>(timer: &Timer, writer: W) -> fmt::Result {
 /// function if the Run is in use by a timer in order to properly save the
 /// current attempt as well.
 pub fn save_run<W: fmt::Write>(run: &Run, writer: W) -> fmt::Result {
+    save(run, writer, false, IconFormat::Original)
+}
+
+/// Saves a Run as a LiveSplit splits file (*.lss) with all of its icons
+/// converted to the format provided. Icons that can't be converted are stored
+/// as they are. Converting icons requires the `std` and `more-image-formats`
+/// features. Otherwise all the icons are stored as they are.
+pub fn save_run_with_icon_format<W: fmt::Write>(
+    run: &Run,
+    writer: W,
+    icon_format: IconFormat,
+) -> fmt::Result {
+    save(run, writer, false, icon_format)
+}
+
+/// Saves a Run as a LiveSplit splits file (*.lss) and additionally stores a
+/// checksum of its segment and time data in the metadata. When the splits file
+/// is loaded again, [`Run::verify_checksum`] can be used to detect whether it
+/// got corrupted.
+pub fn save_run_with_checksum<W: fmt::Write>(run: &Run, writer: W) -> fmt::Result {
+    save(run, writer, true, IconFormat::Original)
+}
+
+/// Saves a Run as a LiveSplit splits file (*.lss) after removing the segments
+/// at the end of the Run that have no name and don't store any times. The Run
+/// provided is left untouched. See [`Run::trim_trailing_empty_segments`] for
+/// details.
+pub fn save_run_trimmed<W: fmt::Write>(run: &Run, writer: W) -> fmt::Result {
+    let mut run = run.clone();
+    run.trim_trailing_empty_segments();
+    save(&run, writer, false, IconFormat::Original)
+}
+
+fn save<W: fmt::Write>(
+    run: &Run,
+    writer: W,
+    write_checksum: bool,
+    icon_format: IconFormat,
+) -> fmt::Result {
     let writer = &mut Writer::new_with_default_header(writer)?;
 
     let base64_buf = &mut Vec::new();
     let image_buf = &mut Cow::Borrowed(&LSS_IMAGE_HEADER[..]);
 
     writer.tag_with_content("Run", [("version", Text::new_escaped("1.8.0"))], |writer| {
-        image(writer, "GameIcon", run.game_icon(), base64_buf, image_buf)?;
+        image(
+            writer,
+            "GameIcon",
+            run.game_icon(),
+            icon_format,
+            base64_buf,
+            image_buf,
+        )?;
         writer.tag_with_text_content("GameName", NO_ATTRIBUTES, run.game_name())?;
         writer.tag_with_text_content("CategoryName", NO_ATTRIBUTES, run.category_name())?;
         writer.tag_with_text_content("LevelName", NO_ATTRIBUTES, run.level_name())?;
@@ -183,6 +301,9 @@ pub fn save_run<W: fmt::Write>(run: &Run, writer: W) -> fmt::Result {
         writer.tag_with_content("Metadata", NO_ATTRIBUTES, |writer| {
             let metadata = run.metadata();
             writer.empty_tag("Run", [("id", metadata.run_id())])?;
+            if let Some(id) = run.id() {
+                writer.tag_with_text_content("Id", NO_ATTRIBUTES, id)?;
+            }
             writer.tag_with_text_content(
                 "Platform",
                 [("usesEmulator", bool(metadata.uses_emulator()))],
@@ -206,7 +327,15 @@ pub fn save_run<W: fmt::Write>(run: &Run, writer: W) -> fmt::Result {
                 |writer, (name, var)| {
                     writer.tag_with_text_content("Variable", [("name", name)], var.value.as_str())
                 },
-            )
+            )?;
+            if write_checksum {
+                writer.tag_with_text_content(
+                    "Checksum",
+                    NO_ATTRIBUTES,
+                    DisplayAlreadyEscaped(format_args!("{:08X}", run.calculate_checksum())),
+                )?;
+            }
+            Ok(())
         })?;
 
         writer.tag_with_text_content(
@@ -219,6 +348,10 @@ pub fn save_run<W: fmt::Write>(run: &Run, writer: W) -> fmt::Result {
             },
         )?;
 
+        if run.default_timing_method() == TimingMethod::GameTime {
+            writer.tag_with_text_content("DefaultTimingMethod", NO_ATTRIBUTES, "GameTime")?;
+        }
+
         writer.tag_with_text_content(
             "Offset",
             NO_ATTRIBUTES,
@@ -275,7 +408,14 @@ pub fn save_run<W: fmt::Write>(run: &Run, writer: W) -> fmt::Result {
         scoped_iter(writer, "Segments", run.segments(), |writer, segment| {
             writer.tag_with_content("Segment", NO_ATTRIBUTES, |writer| {
                 writer.tag_with_text_content("Name", NO_ATTRIBUTES, segment.name())?;
-                image(writer, "Icon", segment.icon(), base64_buf, image_buf)?;
+                image(
+                    writer,
+                    "Icon",
+                    segment.icon(),
+                    icon_format,
+                    base64_buf,
+                    image_buf,
+                )?;
 
                 scoped_iter(
                     writer,
@@ -303,7 +443,13 @@ pub fn save_run<W: fmt::Write>(run: &Run, writer: W) -> fmt::Result {
                             time(tag, history_time)
                         })
                     },
-                )
+                )?;
+
+                if !segment.notes().is_empty() {
+                    writer.tag_with_text_content("Notes", NO_ATTRIBUTES, segment.notes())?;
+                }
+
+                Ok(())
             })
         })?;
 
@@ -24,11 +24,13 @@ use crate::{
 pub struct Segment {
     name: String,
     icon: Image,
+    notes: String,
     best_segment_time: Time,
     split_time: Time,
     segment_history: SegmentHistory,
     comparisons: Comparisons,
     variables: HashMap<String, String>,
+    custom_split_times: HashMap<String, TimeSpan>,
 }
 
 impl Segment {
@@ -71,6 +73,24 @@ impl Segment {
         self.icon = image;
     }
 
+    /// Accesses the notes of the segment. These are meant for describing what
+    /// to do during the segment, such as the route to take. The notes are empty
+    /// if there are none.
+    #[inline]
+    #[allow(clippy::missing_const_for_fn)] // FIXME: Can't reason about Deref
+    pub fn notes(&self) -> &str {
+        &self.notes
+    }
+
+    /// Sets the notes of the segment.
+    #[inline]
+    pub fn set_notes<S>(&mut self, notes: S)
+    where
+        S: PopulateString,
+    {
+        notes.populate(&mut self.notes);
+    }
+
     /// Grants mutable access to the comparison times stored in the Segment.
     /// This includes both the custom comparisons and the generated ones.
     #[inline]
@@ -197,10 +217,51 @@ impl Segment {
         self.variables.clear();
     }
 
+    /// Accesses the split time of the current attempt for the custom timing
+    /// method specified. Custom timing methods are registered on the
+    /// [`Timer`](crate::Timer). Their split times are only kept for the current
+    /// attempt. They are neither stored in the splits files nor recorded in the
+    /// Segment History or the Attempt History, so they are gone once the
+    /// attempt is reset or the splits are reloaded.
+    pub fn custom_split_time(&self, name: &str) -> Option<TimeSpan> {
+        self.custom_split_times.get(name).copied()
+    }
+
+    /// Accesses the split times of the current attempt for all the custom
+    /// timing methods. Just like [`custom_split_time`](Self::custom_split_time),
+    /// these only exist for the current attempt.
+    pub const fn custom_split_times(&self) -> &HashMap<String, TimeSpan> {
+        &self.custom_split_times
+    }
+
+    /// Grants mutable access to the split times of the current attempt for all
+    /// the custom timing methods.
+    pub(crate) const fn custom_split_times_mut(&mut self) -> &mut HashMap<String, TimeSpan> {
+        &mut self.custom_split_times
+    }
+
     /// Clears all the information the segment stores when it has been splitted,
     /// such as the split's time and variables.
     pub fn clear_split_info(&mut self) {
         self.clear_variables();
         self.clear_split_time();
+        self.custom_split_times.clear();
+    }
+
+    /// Checks whether the segment has no name, icon, notes or variables and
+    /// doesn't store any times, neither in its comparisons, its Best Segment
+    /// Time, its Segment History nor as the split time of the current attempt.
+    pub(super) fn is_empty(&self) -> bool {
+        let is_empty = |time: Time| time.real_time.is_none() && time.game_time.is_none();
+
+        self.name.is_empty()
+            && self.icon.is_empty()
+            && self.notes.is_empty()
+            && self.variables.is_empty()
+            && is_empty(self.best_segment_time)
+            && is_empty(self.split_time)
+            && self.segment_history.iter().all(|&(_, time)| is_empty(time))
+            && self.comparisons.iter().all(|&(_, time)| is_empty(time))
+            && self.custom_split_times.is_empty()
     }
 }
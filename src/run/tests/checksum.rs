@@ -0,0 +1,51 @@
+use crate::{
+    run::{ChecksumVerification, parser, saver},
+    util::tests_helper::{create_timer, run_with_splits},
+};
+
+fn saved_run(with_checksum: bool) -> String {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[10.0, 17.5]);
+    run_with_splits(&mut timer, &[11.0, 19.0]);
+    let run = timer.into_run(true);
+
+    let mut buf = String::new();
+    if with_checksum {
+        saver::livesplit::save_run_with_checksum(&run, &mut buf).unwrap();
+    } else {
+        saver::livesplit::save_run(&run, &mut buf).unwrap();
+    }
+    buf
+}
+
+#[test]
+fn round_trip_keeps_the_checksum_valid() {
+    let run = parser::livesplit::parse(&saved_run(true)).unwrap();
+    assert!(run.metadata().checksum().is_some());
+    assert_eq!(run.verify_checksum(), ChecksumVerification::Valid);
+}
+
+#[test]
+fn tampering_with_a_time_causes_a_mismatch() {
+    // The second segment's best segment time is 7.5 seconds.
+    let saved = saved_run(true);
+    assert!(saved.contains("00:00:07.5"));
+    let tampered = saved.replacen("00:00:07.5", "00:00:06.5", 1);
+
+    let run = parser::livesplit::parse(&tampered).unwrap();
+    let stored = run.metadata().checksum().unwrap();
+    assert_eq!(
+        run.verify_checksum(),
+        ChecksumVerification::Mismatch {
+            stored,
+            actual: run.calculate_checksum(),
+        }
+    );
+    assert_ne!(stored, run.calculate_checksum());
+}
+
+#[test]
+fn missing_checksum_is_reported() {
+    let run = parser::livesplit::parse(&saved_run(false)).unwrap();
+    assert_eq!(run.verify_checksum(), ChecksumVerification::Missing);
+}
@@ -1,4 +1,11 @@
-use crate::run::{AddComparisonError, Run};
+use crate::{
+    comparison::{best_segments, personal_best},
+    run::{
+        parser, saver, AddComparisonError, ComparisonWarning, Run, Segment, SetGoalComparisonError,
+    },
+    util::tests_helper::{create_run, create_timer, run_with_splits},
+    Time, TimeSpan, Timer, TimingMethod,
+};
 
 #[test]
 fn adding_a_new_comparison_works() {
@@ -13,3 +20,118 @@ fn adding_a_duplicate_fails() {
     let c = run.add_custom_comparison("Best Segments");
     assert_eq!(c, Err(AddComparisonError::DuplicateName));
 }
+
+#[test]
+fn validating_comparisons_reports_decreasing_times() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("A"));
+    run.push_segment(Segment::new("B"));
+    run.push_segment(Segment::new("C"));
+    run.push_segment(Segment::new("D"));
+    run.add_custom_comparison("Imported").unwrap();
+
+    let method = TimingMethod::RealTime;
+    assert!(run.validate_comparisons(method).is_empty());
+
+    let times = [Some(10.0), None, Some(8.0), Some(12.0)];
+    for (segment, time) in run.segments_mut().iter_mut().zip(times) {
+        *segment.comparison_mut("Imported") =
+            Time::new().with_real_time(time.map(TimeSpan::from_seconds));
+    }
+
+    assert_eq!(
+        run.validate_comparisons(method),
+        [ComparisonWarning {
+            comparison: "Imported".into(),
+            segment_index: 2,
+            previous_time: TimeSpan::from_seconds(10.0),
+            time: TimeSpan::from_seconds(8.0),
+        }]
+    );
+    assert!(run.validate_comparisons(TimingMethod::GameTime).is_empty());
+}
+
+#[test]
+fn shifting_a_comparison_moves_all_its_split_times() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[1.0, 5.0, 9.0]);
+    let mut run = timer.into_run(true);
+
+    let method = TimingMethod::GameTime;
+    let split_times = |run: &Run| {
+        run.segments()
+            .iter()
+            .map(|s| s.comparison(personal_best::NAME)[method])
+            .collect::<Vec<_>>()
+    };
+    let before = split_times(&run);
+
+    run.shift_comparison(personal_best::NAME, TimeSpan::from_seconds(2.0), method);
+    let after = split_times(&run);
+    for (before, after) in before.iter().zip(&after) {
+        assert_eq!(*after, Some(before.unwrap() + TimeSpan::from_seconds(2.0)));
+    }
+
+    run.shift_comparison(personal_best::NAME, TimeSpan::from_seconds(-5.0), method);
+    assert_eq!(
+        split_times(&run),
+        [
+            Some(TimeSpan::zero()),
+            Some(TimeSpan::from_seconds(2.0)),
+            Some(TimeSpan::from_seconds(6.0)),
+        ]
+    );
+}
+
+#[test]
+fn goal_comparisons_survive_a_round_trip() {
+    let mut run = create_run(&["A", "B", "C"]);
+    let time = |seconds| Time::new().with_real_time(Some(TimeSpan::from_seconds(seconds)));
+    let split_times = [time(10.0), Time::new(), time(30.0)];
+
+    assert_eq!(
+        run.set_goal_comparison("World Record", &split_times[..2]),
+        Err(SetGoalComparisonError::SplitCountMismatch)
+    );
+    assert_eq!(
+        run.set_goal_comparison(personal_best::NAME, &split_times),
+        Err(SetGoalComparisonError::AddComparison {
+            source: AddComparisonError::DuplicateName
+        })
+    );
+    assert_eq!(
+        run.set_goal_comparison(best_segments::NAME, &split_times),
+        Err(SetGoalComparisonError::AddComparison {
+            source: AddComparisonError::DuplicateName
+        })
+    );
+
+    run.set_goal_comparison("World Record", &[time(5.0), time(6.0), time(7.0)])
+        .unwrap();
+    run.set_goal_comparison("World Record", &split_times)
+        .unwrap();
+    assert_eq!(
+        run.custom_comparisons()
+            .iter()
+            .filter(|c| *c == "World Record")
+            .count(),
+        1
+    );
+
+    let mut buf = String::new();
+    saver::livesplit::save_run(&run, &mut buf).unwrap();
+    let parsed = parser::livesplit::parse(&buf).unwrap();
+    for (segment, &time) in parsed.segments().iter().zip(&split_times) {
+        assert_eq!(segment.comparison("World Record"), time);
+    }
+
+    let mut timer = Timer::new(parsed).unwrap();
+    timer.set_current_comparison("World Record").unwrap();
+    assert_eq!(timer.current_comparison(), "World Record");
+    assert_eq!(
+        timer
+            .snapshot()
+            .comparison_final_time("World Record", TimingMethod::RealTime),
+        Some(TimeSpan::from_seconds(30.0))
+    );
+}
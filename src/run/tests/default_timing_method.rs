@@ -0,0 +1,50 @@
+use crate::{
+    Timer, TimingMethod,
+    run::{parser, saver},
+    util::tests_helper::create_run,
+};
+
+#[test]
+fn game_time_default_survives_a_round_trip() {
+    let mut run = create_run(&["A", "B"]);
+    run.set_default_timing_method(TimingMethod::GameTime);
+
+    let mut buf = String::new();
+    saver::livesplit::save_run(&run, &mut buf).unwrap();
+    let parsed = parser::livesplit::parse(&buf).unwrap();
+
+    assert_eq!(parsed.default_timing_method(), TimingMethod::GameTime);
+
+    let timer = Timer::new(parsed).unwrap();
+    assert_eq!(timer.current_timing_method(), TimingMethod::GameTime);
+}
+
+#[test]
+fn real_time_is_the_default() {
+    let run = create_run(&["A"]);
+    assert_eq!(run.default_timing_method(), TimingMethod::RealTime);
+
+    let mut buf = String::new();
+    saver::livesplit::save_run(&run, &mut buf).unwrap();
+    assert!(!buf.contains("<DefaultTimingMethod"));
+
+    let parsed = parser::livesplit::parse(&buf).unwrap();
+    assert_eq!(parsed.default_timing_method(), TimingMethod::RealTime);
+
+    let timer = Timer::new(parsed).unwrap();
+    assert_eq!(timer.current_timing_method(), TimingMethod::RealTime);
+}
+
+#[test]
+fn replacing_the_run_switches_to_its_default() {
+    let mut timer = Timer::new(create_run(&["A"])).unwrap();
+    timer.set_current_timing_method(TimingMethod::GameTime);
+
+    let mut run = create_run(&["A", "B"]);
+    run.set_default_timing_method(TimingMethod::GameTime);
+    timer.set_run(run).unwrap();
+    assert_eq!(timer.current_timing_method(), TimingMethod::GameTime);
+
+    timer.replace_run(create_run(&["A"]), false).unwrap();
+    assert_eq!(timer.current_timing_method(), TimingMethod::RealTime);
+}
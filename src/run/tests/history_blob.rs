@@ -0,0 +1,78 @@
+use crate::{
+    run::HistoryBlobError,
+    util::tests_helper::{create_timer, run_with_splits, run_with_splits_opt, span},
+    AtomicDateTime, Segment, Time,
+};
+
+fn run_with_history() -> crate::Run {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[3.0, 6.5, 9.25]);
+    run_with_splits_opt(&mut timer, &[Some(2.5), None, Some(8.0)]);
+    run_with_splits_opt(&mut timer, &[Some(2.75)]);
+    let mut run = timer.into_run(true);
+
+    run.add_attempt(
+        Time::new().with_real_time(Some(span(-1.5))),
+        Some(AtomicDateTime::now()),
+        Some(AtomicDateTime::new(AtomicDateTime::now().time, true)),
+        Some(span(123.456789)),
+    );
+    run.segment_mut(1)
+        .segment_history_mut()
+        .insert(-3, Time::new().with_game_time(Some(span(4.0))));
+
+    run
+}
+
+#[test]
+fn round_trips_losslessly() {
+    let run = run_with_history();
+    let blob = run.export_history_blob();
+
+    let mut imported = run.clone();
+    imported.clear_history();
+    assert_ne!(imported, run);
+
+    imported.import_history_blob(&blob).unwrap();
+    assert_eq!(imported, run);
+    assert_eq!(imported.export_history_blob(), blob);
+}
+
+#[test]
+fn rejects_mismatching_segment_count() {
+    let run = run_with_history();
+    let blob = run.export_history_blob();
+
+    let mut other = run.clone();
+    other.push_segment(Segment::new("D"));
+    let before = other.clone();
+
+    assert_eq!(
+        other.import_history_blob(&blob),
+        Err(HistoryBlobError::SegmentCountMismatch),
+    );
+    assert_eq!(other, before);
+}
+
+#[test]
+fn rejects_invalid_data() {
+    let run = run_with_history();
+    let mut blob = run.export_history_blob();
+    let mut other = run.clone();
+
+    assert_eq!(
+        other.import_history_blob(&blob[..blob.len() - 1]),
+        Err(HistoryBlobError::UnexpectedEnd),
+    );
+
+    assert_eq!(
+        other.import_history_blob(b"nope"),
+        Err(HistoryBlobError::InvalidMagic),
+    );
+
+    blob[4] = 2;
+    assert_eq!(
+        other.import_history_blob(&blob),
+        Err(HistoryBlobError::UnsupportedVersion { version: 2 }),
+    );
+}
@@ -0,0 +1,51 @@
+use crate::{
+    TimingMethod,
+    util::tests_helper::{create_timer, run_with_splits, run_with_splits_opt, span},
+};
+
+fn run() -> crate::Run {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[3.0, 6.5, 9.25]);
+    run_with_splits_opt(&mut timer, &[Some(2.5), None, Some(8.0)]);
+    run_with_splits_opt(&mut timer, &[Some(2.75)]);
+    timer.into_run(true)
+}
+
+#[test]
+fn has_a_row_for_every_segment_of_every_attempt() {
+    let run = run();
+    let table = run.history_table(TimingMethod::GameTime);
+
+    assert_eq!(table.len(), run.attempt_history().len() * run.len());
+    assert_eq!(table.len(), 9);
+
+    assert_eq!(table[1], (1, 1, Some(span(3.5))));
+    // The skipped segment has no segment time, while the segment after it
+    // spans both segments.
+    assert_eq!(table[4], (2, 1, None));
+    assert_eq!(table[5], (2, 2, Some(span(5.5))));
+    // The attempt was reset before reaching the last segments.
+    assert_eq!(table[6], (3, 0, Some(span(2.75))));
+    assert_eq!(table[8], (3, 2, None));
+
+    let real_time = run.history_table(TimingMethod::RealTime);
+    assert_eq!(real_time.len(), 9);
+    assert_eq!(real_time[5].0, 2);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn writes_csv() {
+    let mut buf = Vec::new();
+    run()
+        .write_history_csv(&mut buf, TimingMethod::GameTime)
+        .unwrap();
+    let csv = String::from_utf8(buf).unwrap();
+    let lines: Vec<_> = csv.lines().collect();
+
+    assert_eq!(lines.len(), 10);
+    assert_eq!(lines[0], "attempt_id,segment_index,segment_time");
+    assert_eq!(lines[1], "1,0,3");
+    assert_eq!(lines[5], "2,1,");
+    assert_eq!(lines[6], "2,2,5.5");
+}
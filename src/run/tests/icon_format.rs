@@ -0,0 +1,57 @@
+use crate::{
+    run::{
+        parser,
+        saver::livesplit::{self, IconFormat},
+    },
+    settings::Image,
+    util::tests_helper::create_run,
+};
+use image::{ImageFormat, Rgba, RgbaImage};
+use std::io::Cursor;
+
+const PNG_MAGIC: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+fn encoded_icon(format: ImageFormat) -> Vec<u8> {
+    let image = RgbaImage::from_fn(4, 4, |x, y| Rgba([x as u8 * 60, y as u8 * 60, 128, 255]));
+    let mut buf = Cursor::new(Vec::new());
+    image.write_to(&mut buf, format).unwrap();
+    buf.into_inner()
+}
+
+fn saved_icon(icon: &[u8], icon_format: IconFormat) -> Vec<u8> {
+    let mut run = create_run(&["A"]);
+    run.segment_mut(0)
+        .set_icon(Image::new(icon.into(), Image::ICON));
+
+    let mut buf = String::new();
+    livesplit::save_run_with_icon_format(&run, &mut buf, icon_format).unwrap();
+    parser::livesplit::parse(&buf)
+        .unwrap()
+        .segment(0)
+        .icon()
+        .data()
+        .to_vec()
+}
+
+#[test]
+fn original_format_keeps_the_icon_as_is() {
+    let icon = encoded_icon(ImageFormat::WebP);
+    assert_eq!(saved_icon(&icon, IconFormat::Original), icon);
+}
+
+#[test]
+fn icons_get_converted_to_png() {
+    let icon = encoded_icon(ImageFormat::WebP);
+    assert!(!icon.starts_with(PNG_MAGIC));
+    assert!(saved_icon(&icon, IconFormat::Png).starts_with(PNG_MAGIC));
+}
+
+#[test]
+fn icons_get_converted_to_lossless_webp() {
+    let icon = encoded_icon(ImageFormat::Png);
+    let saved = saved_icon(&icon, IconFormat::WebpLossless);
+    assert_eq!(&saved[..4], b"RIFF");
+    assert_eq!(&saved[8..12], b"WEBP");
+    // Lossless WebP images use the VP8L chunk.
+    assert_eq!(&saved[12..16], b"VP8L");
+}
@@ -0,0 +1,73 @@
+use crate::{
+    Run,
+    run::{parser, saver},
+    util::tests_helper::create_run,
+};
+
+fn is_uuid_like(id: &str) -> bool {
+    id.len() == 36
+        && id.char_indices().all(|(i, c)| match i {
+            8 | 13 | 18 | 23 => c == '-',
+            _ => c.is_ascii_hexdigit(),
+        })
+}
+
+#[test]
+fn new_runs_get_unique_ids() {
+    let a = Run::new();
+    let b = Run::new();
+
+    assert!(is_uuid_like(a.id().unwrap()));
+    assert!(is_uuid_like(b.id().unwrap()));
+    assert_ne!(a.id(), b.id());
+    assert_eq!(a.clone().id(), a.id());
+}
+
+#[test]
+fn ensure_id_keeps_the_existing_id() {
+    let mut run = Run::new();
+    let id = run.id().unwrap().to_owned();
+
+    assert_eq!(run.ensure_id(), id);
+    assert_eq!(run.id(), Some(&*id));
+}
+
+#[test]
+fn ids_are_not_compared() {
+    let a = Run::new();
+    let mut b = a.clone();
+    b.set_id("00000000-0000-4000-8000-000000000000");
+
+    assert_ne!(a.id(), b.id());
+    assert!(a == b);
+}
+
+#[test]
+fn id_survives_a_round_trip() {
+    let run = create_run(&["A", "B"]);
+
+    let mut buf = String::new();
+    saver::livesplit::save_run(&run, &mut buf).unwrap();
+    let parsed = parser::livesplit::parse(&buf).unwrap();
+    assert_eq!(parsed.id(), run.id());
+}
+
+#[test]
+fn legacy_files_without_an_id_get_one() {
+    let run = create_run(&["A", "B"]);
+
+    let mut buf = String::new();
+    saver::livesplit::save_run(&run, &mut buf).unwrap();
+    let id_tag = format!("<Id>{}</Id>", run.id().unwrap());
+    assert!(buf.contains(&id_tag));
+    let buf = buf.replace(&id_tag, "");
+
+    let parsed = parser::livesplit::parse(&buf).unwrap();
+    let id = parsed.id().unwrap();
+    assert!(is_uuid_like(id));
+    assert_ne!(Some(id), run.id());
+
+    let mut resaved = String::new();
+    saver::livesplit::save_run(&parsed, &mut resaved).unwrap();
+    assert_eq!(parser::livesplit::parse(&resaved).unwrap().id(), Some(id));
+}
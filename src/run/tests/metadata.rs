@@ -1,4 +1,4 @@
-use super::super::RunMetadata;
+use super::super::{MetadataVariable, Run, RunMetadata};
 
 #[test]
 fn removing_shifts_the_speedrun_com_variables() {
@@ -21,3 +21,78 @@ fn removing_shifts_the_speedrun_com_variables() {
         ["A", "C", "D"]
     );
 }
+
+#[test]
+fn metadata_variables_can_be_read_back_by_name() {
+    let mut run = Run::new();
+    run.set_metadata_variable("Platform", "N64");
+    run.set_metadata_variable("Glitchless", "Yes");
+    run.set_metadata_variable("Region", "NTSC-U");
+    run.set_metadata_variable("Glitchless", "No");
+
+    assert_eq!(run.metadata_variable("Platform"), Some("N64"));
+    assert_eq!(run.metadata_variable("Glitchless"), Some("No"));
+    assert_eq!(run.metadata_variable("Region"), Some("NTSC-U"));
+    assert_eq!(run.metadata_variable("Category"), None);
+
+    assert_eq!(
+        run.metadata_variables().collect::<Vec<_>>(),
+        [
+            MetadataVariable {
+                name: "Platform",
+                value: "N64",
+            },
+            MetadataVariable {
+                name: "Glitchless",
+                value: "No",
+            },
+            MetadataVariable {
+                name: "Region",
+                value: "NTSC-U",
+            },
+        ]
+    );
+
+    run.remove_metadata_variable("Platform");
+    assert_eq!(run.metadata_variable("Platform"), None);
+    assert_eq!(run.metadata_variables().count(), 2);
+}
+
+#[test]
+fn canonicalizing_merges_differently_cased_variables() {
+    let mut run = Run::new();
+    run.set_metadata_variable("Glitchless", "Yes");
+    run.set_metadata_variable("Region", "NTSC-U");
+    run.set_metadata_variable(" glitchless", "No");
+    run.set_metadata_variable("GLITCHLESS ", "Maybe");
+    run.metadata_mut()
+        .custom_variable_mut("Controller")
+        .permanent()
+        .set_value("Keyboard");
+    run.metadata_mut()
+        .custom_variable_mut("controller ")
+        .set_value("Gamepad");
+
+    run.canonicalize_metadata();
+
+    assert_eq!(
+        run.metadata_variables().collect::<Vec<_>>(),
+        [
+            MetadataVariable {
+                name: "GLITCHLESS",
+                value: "Maybe",
+            },
+            MetadataVariable {
+                name: "Region",
+                value: "NTSC-U",
+            },
+        ]
+    );
+
+    let custom_variables = run.metadata().custom_variables().collect::<Vec<_>>();
+    assert_eq!(custom_variables.len(), 1);
+    let (name, variable) = custom_variables[0];
+    assert_eq!(name, "controller");
+    assert_eq!(variable.value, "Gamepad");
+    assert!(variable.is_permanent);
+}
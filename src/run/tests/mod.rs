@@ -1,6 +1,19 @@
+mod checksum;
 mod comparison;
+mod default_timing_method;
 mod empty_run;
 mod extended_category_name;
 mod fixing;
+mod history_blob;
+mod history_table;
+#[cfg(all(feature = "std", feature = "more-image-formats"))]
+mod icon_format;
+mod id;
 mod linked_layout;
 mod metadata;
+mod move_segment;
+mod prune_history;
+mod segment_notes;
+mod set_pb_from_attempt;
+mod transaction;
+mod trim_trailing_empty_segments;
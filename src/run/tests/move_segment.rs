@@ -0,0 +1,57 @@
+use crate::{
+    util::tests_helper::{create_timer, run_with_splits},
+    TimeSpan, TimingMethod,
+};
+
+#[test]
+fn moving_a_middle_segment_to_the_front_keeps_its_times() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[10.0, 25.0, 35.0]);
+    run_with_splits(&mut timer, &[12.0, 20.0, 32.0]);
+    // The Personal Best segments are 12, 8 and 12, while the Best Segments
+    // are 10, 8 and 10.
+    let mut run = timer.into_run(true);
+    let history = run.segment(1).segment_history().clone();
+
+    run.move_segment(1, 0);
+
+    let names = run.segments().iter().map(|s| s.name()).collect::<Vec<_>>();
+    assert_eq!(names, ["B", "A", "C"]);
+
+    let moved = run.segment(0);
+    assert_eq!(
+        moved.best_segment_time().game_time,
+        Some(TimeSpan::from_seconds(8.0)),
+    );
+    assert_eq!(moved.segment_history(), &history);
+    assert_eq!(
+        run.segment(1).best_segment_time().game_time,
+        Some(TimeSpan::from_seconds(10.0)),
+    );
+
+    let pb = run
+        .segments()
+        .iter()
+        .map(|s| s.personal_best_split_time()[TimingMethod::GameTime])
+        .collect::<Vec<_>>();
+    assert_eq!(
+        pb,
+        [8.0, 20.0, 32.0].map(|t| Some(TimeSpan::from_seconds(t))),
+    );
+}
+
+#[test]
+fn moving_a_segment_back_and_forth_restores_the_run() {
+    let mut timer = create_timer(&["A", "B", "C", "D"]);
+    run_with_splits(&mut timer, &[10.0, 25.0, 35.0, 50.0]);
+    run_with_splits(&mut timer, &[12.0, 20.0, 32.0, 45.0]);
+    let mut run = timer.into_run(true);
+    let original = run.clone();
+
+    run.move_segment(0, 3);
+    let names = run.segments().iter().map(|s| s.name()).collect::<Vec<_>>();
+    assert_eq!(names, ["B", "C", "D", "A"]);
+
+    run.move_segment(3, 0);
+    assert_eq!(run, original);
+}
@@ -0,0 +1,123 @@
+use crate::{
+    AtomicDateTime, DateTime, Run, Segment, Time, TimeSpan, TimingMethod,
+    comparison::personal_best, run::PrunePolicy,
+};
+
+const DAY: i64 = 24 * 60 * 60;
+
+fn date(day: i64) -> DateTime {
+    DateTime::from_unix_timestamp(day * DAY).unwrap()
+}
+
+fn time(seconds: f64) -> Time {
+    Time::new().with_real_time(Some(TimeSpan::from_seconds(seconds)))
+}
+
+/// Attempt 2 holds the best segment of A, while attempt 3 holds the best
+/// segment of B and the Personal Best. Attempt 5 got reset in the first
+/// segment.
+fn run() -> Run {
+    let mut run = Run::new();
+
+    let mut a = Segment::new("A");
+    for (index, seconds) in [(1, 10.0), (2, 9.0), (3, 11.0), (4, 12.0), (5, 13.0)] {
+        a.segment_history_mut().insert(index, time(seconds));
+    }
+    a.segment_history_mut().insert(-1, time(15.0));
+    *a.best_segment_time_mut() = time(9.0);
+    *a.comparison_mut(personal_best::NAME) = time(11.0);
+    run.push_segment(a);
+
+    let mut b = Segment::new("B");
+    for (index, seconds) in [(1, 10.0), (2, 12.0), (3, 8.0), (4, 13.0)] {
+        b.segment_history_mut().insert(index, time(seconds));
+    }
+    *b.best_segment_time_mut() = time(8.0);
+    *b.comparison_mut(personal_best::NAME) = time(19.0);
+    run.push_segment(b);
+
+    for (index, final_time) in [
+        (1, Some(20.0)),
+        (2, Some(21.0)),
+        (3, Some(19.0)),
+        (4, Some(25.0)),
+        (5, None),
+    ] {
+        let final_time = final_time.map_or_else(Time::new, time);
+        let started = AtomicDateTime::new(date(index.into()), false);
+        run.add_attempt_with_index(final_time, index, Some(started), None, None);
+    }
+
+    run
+}
+
+fn attempts(run: &Run) -> Vec<i32> {
+    run.attempt_history().iter().map(|a| a.index()).collect()
+}
+
+fn history(run: &Run, segment: usize) -> Vec<i32> {
+    run.segment(segment)
+        .segment_history()
+        .iter()
+        .map(|&(index, _)| index)
+        .collect()
+}
+
+#[track_caller]
+fn assert_records_retained(run: &Run) {
+    let method = TimingMethod::RealTime;
+    assert_eq!(
+        run.segment(0).best_segment_time()[method],
+        Some(TimeSpan::from_seconds(9.0))
+    );
+    assert_eq!(
+        run.segment(1).best_segment_time()[method],
+        Some(TimeSpan::from_seconds(8.0))
+    );
+    assert_eq!(
+        run.segment(1).personal_best_split_time()[method],
+        Some(TimeSpan::from_seconds(19.0))
+    );
+}
+
+#[test]
+fn keeps_the_last_attempts() {
+    let mut run = run();
+    assert_eq!(run.prune_history(PrunePolicy::KeepLast(2)), 3);
+
+    assert_eq!(attempts(&run), [4, 5]);
+    assert_eq!(history(&run, 0), [-1, 4, 5]);
+    assert_eq!(history(&run, 1), [4]);
+    assert_records_retained(&run);
+}
+
+#[test]
+fn keeps_the_attempts_since_a_date() {
+    let mut run = run();
+    assert_eq!(run.prune_history(PrunePolicy::KeepSince(date(3))), 2);
+
+    assert_eq!(attempts(&run), [3, 4, 5]);
+    assert_eq!(history(&run, 0), [-1, 3, 4, 5]);
+    assert_eq!(history(&run, 1), [3, 4]);
+    assert_records_retained(&run);
+}
+
+#[test]
+fn keeps_the_attempts_that_set_records() {
+    let mut run = run();
+    assert_eq!(run.prune_history(PrunePolicy::KeepRecords), 3);
+
+    assert_eq!(attempts(&run), [2, 3]);
+    assert_eq!(history(&run, 0), [-1, 2, 3]);
+    assert_eq!(history(&run, 1), [2, 3]);
+    assert_records_retained(&run);
+}
+
+#[test]
+fn nothing_changes_if_all_attempts_are_kept() {
+    let mut run = run();
+    assert_eq!(run.prune_history(PrunePolicy::KeepLast(10)), 0);
+
+    assert_eq!(attempts(&run), [1, 2, 3, 4, 5]);
+    assert!(!run.has_been_modified());
+}
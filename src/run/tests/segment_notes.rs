@@ -0,0 +1,29 @@
+use crate::{
+    run::{parser, saver},
+    util::tests_helper::create_run,
+};
+
+#[test]
+fn notes_survive_a_round_trip() {
+    let mut run = create_run(&["A", "B", "C"]);
+    run.segment_mut(0).set_notes("Kill the boss, then menu");
+    run.segment_mut(2).set_notes("Grab the <key> & \"run\"");
+
+    let mut buf = String::new();
+    saver::livesplit::save_run(&run, &mut buf).unwrap();
+    let parsed = parser::livesplit::parse(&buf).unwrap();
+
+    assert_eq!(parsed.segment(0).notes(), "Kill the boss, then menu");
+    assert_eq!(parsed.segment(1).notes(), "");
+    assert_eq!(parsed.segment(2).notes(), "Grab the <key> & \"run\"");
+}
+
+#[test]
+fn empty_notes_are_not_saved() {
+    let run = create_run(&["A"]);
+
+    let mut buf = String::new();
+    saver::livesplit::save_run(&run, &mut buf).unwrap();
+
+    assert!(!buf.contains("<Notes"));
+}
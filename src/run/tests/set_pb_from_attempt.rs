@@ -0,0 +1,79 @@
+use crate::{
+    run::SetPersonalBestError,
+    util::tests_helper::{create_timer, run_with_splits, run_with_splits_opt, span, start_run},
+    TimingMethod,
+};
+
+#[test]
+fn replaces_the_personal_best_with_the_attempt() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[10.0, 25.0, 35.0]);
+    run_with_splits(&mut timer, &[12.0, 26.0, 40.0]);
+    run_with_splits_opt(&mut timer, &[Some(8.0), None, Some(33.0)]);
+    let mut run = timer.into_run(true);
+
+    let attempt_ids = run
+        .attempt_history()
+        .iter()
+        .map(|attempt| attempt.index())
+        .collect::<Vec<_>>();
+    let best_segments = run
+        .segments()
+        .iter()
+        .map(|segment| segment.best_segment_time())
+        .collect::<Vec<_>>();
+
+    run.set_pb_from_attempt(attempt_ids[1], TimingMethod::GameTime)
+        .unwrap();
+
+    let split_times = run
+        .segments()
+        .iter()
+        .map(|segment| segment.personal_best_split_time().game_time)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        split_times,
+        [Some(span(12.0)), Some(span(26.0)), Some(span(40.0))]
+    );
+
+    run.set_pb_from_attempt(attempt_ids[2], TimingMethod::GameTime)
+        .unwrap();
+
+    let split_times = run
+        .segments()
+        .iter()
+        .map(|segment| segment.personal_best_split_time().game_time)
+        .collect::<Vec<_>>();
+    assert_eq!(split_times, [Some(span(8.0)), None, Some(span(33.0))]);
+
+    let new_best_segments = run
+        .segments()
+        .iter()
+        .map(|segment| segment.best_segment_time())
+        .collect::<Vec<_>>();
+    assert_eq!(new_best_segments, best_segments);
+}
+
+#[test]
+fn fails_for_unknown_or_unfinished_attempts() {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[10.0, 20.0]);
+    start_run(&mut timer);
+    timer.split().unwrap();
+    timer.reset(true).unwrap();
+    let mut run = timer.into_run(true);
+
+    let unfinished = run.attempt_history()[1].index();
+    assert_eq!(
+        run.set_pb_from_attempt(unfinished, TimingMethod::GameTime),
+        Err(SetPersonalBestError::AttemptNotCompleted),
+    );
+    assert_eq!(
+        run.set_pb_from_attempt(unfinished + 1, TimingMethod::GameTime),
+        Err(SetPersonalBestError::AttemptNotFound),
+    );
+    assert_eq!(
+        run.segment(1).personal_best_split_time().game_time,
+        Some(span(20.0)),
+    );
+}
@@ -0,0 +1,66 @@
+use crate::{
+    Run,
+    util::tests_helper::{create_timer, run_with_splits},
+};
+
+fn run() -> Run {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[10.0, 25.0, 35.0]);
+    run_with_splits(&mut timer, &[12.0, 20.0, 32.0]);
+    timer.into_run(true)
+}
+
+#[test]
+fn batched_edits_recompute_once() {
+    let mut individual = run();
+    let generation = individual.generation();
+    individual.move_segment(2, 0);
+    individual.move_segment(1, 2);
+    individual.regenerate_comparisons();
+    assert_eq!(individual.generation(), generation + 3);
+
+    let mut batched = run();
+    let generation = batched.generation();
+    {
+        let mut transaction = batched.begin_edit();
+        transaction.move_segment(2, 0);
+        transaction.move_segment(1, 2);
+        transaction.regenerate_comparisons();
+        assert!(transaction.is_editing());
+        assert_eq!(transaction.generation(), generation);
+    }
+    assert!(!batched.is_editing());
+    assert_eq!(batched.generation(), generation + 1);
+
+    assert_eq!(individual, batched);
+}
+
+#[test]
+fn nested_transactions_recompute_when_the_outermost_one_is_dropped() {
+    let mut run = run();
+    let generation = run.generation();
+    {
+        let mut outer = run.begin_edit();
+        outer.begin_edit().fix_splits();
+        assert!(outer.is_editing());
+        assert_eq!(outer.generation(), generation);
+    }
+    assert_eq!(run.generation(), generation + 1);
+}
+
+#[test]
+fn transactions_without_edits_dont_recompute() {
+    let mut run = run();
+    let generation = run.generation();
+    drop(run.begin_edit());
+    assert_eq!(run.generation(), generation);
+}
+
+#[test]
+fn clones_are_not_part_of_the_transaction() {
+    let mut run = run();
+    let transaction = run.begin_edit();
+    let clone = Run::clone(&transaction);
+    assert!(!clone.is_editing());
+    assert_eq!(clone, *transaction);
+}
@@ -0,0 +1,74 @@
+use crate::{
+    comparison::personal_best,
+    settings::Image,
+    util::tests_helper::{create_timer, run_with_splits},
+    Segment, TimeSpan, TimingMethod,
+};
+
+#[test]
+fn removes_blank_trailing_segments() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[10.0, 25.0, 35.0]);
+    let mut run = timer.into_run(true);
+
+    run.push_segment(Segment::new(""));
+    run.push_segment(Segment::new(""));
+    assert_eq!(run.len(), 5);
+
+    assert_eq!(run.trim_trailing_empty_segments(), 2);
+
+    let names = run.segments().iter().map(|s| s.name()).collect::<Vec<_>>();
+    assert_eq!(names, ["A", "B", "C"]);
+    assert_eq!(
+        run.segment(2)
+            .comparison_timing_method(personal_best::NAME, TimingMethod::GameTime),
+        Some(TimeSpan::from_seconds(35.0)),
+    );
+
+    assert_eq!(run.trim_trailing_empty_segments(), 0);
+    assert_eq!(run.len(), 3);
+}
+
+#[test]
+fn keeps_placeholders_with_times() {
+    let mut timer = create_timer(&["A", ""]);
+    run_with_splits(&mut timer, &[10.0, 25.0]);
+    let mut run = timer.into_run(true);
+
+    // The unnamed segment stores times, so it's an intentional placeholder.
+    assert_eq!(run.trim_trailing_empty_segments(), 0);
+    assert_eq!(run.len(), 2);
+}
+
+#[test]
+fn keeps_placeholders_with_icons_notes_or_variables() {
+    let mut run = create_timer(&["A"]).into_run(true);
+
+    let mut segment = Segment::new("");
+    segment.set_icon(Image::new([1].into(), Image::ICON));
+    run.push_segment(segment);
+
+    let mut segment = Segment::new("");
+    segment.set_notes("Grab the key");
+    run.push_segment(segment);
+
+    let mut segment = Segment::new("");
+    segment.variables_mut().insert("Coins".into(), "3".into());
+    run.push_segment(segment);
+
+    run.push_segment(Segment::new(""));
+
+    assert_eq!(run.trim_trailing_empty_segments(), 1);
+    assert_eq!(run.len(), 4);
+}
+
+#[test]
+fn keeps_the_first_segment() {
+    let mut timer = create_timer(&[""]);
+    let mut run = timer.clone().into_run(true);
+    run.push_segment(Segment::new(""));
+
+    assert_eq!(run.trim_trailing_empty_segments(), 1);
+    assert_eq!(run.len(), 1);
+    assert!(timer.set_run(run).is_ok());
+}
@@ -0,0 +1,66 @@
+use super::Run;
+use core::ops::{Deref, DerefMut};
+
+/// Keeps track of the transactions that are currently active on a Run and the
+/// recomputations they deferred. It is not considered when comparing Runs, as
+/// it doesn't describe the splits themselves.
+#[derive(Debug, Default)]
+pub(super) struct EditState {
+    pub(super) depth: usize,
+    pub(super) fix_pending: bool,
+    pub(super) regenerate_pending: bool,
+    pub(super) generation: u64,
+}
+
+impl Clone for EditState {
+    fn clone(&self) -> Self {
+        // A clone is not part of the transactions of the original, so it
+        // starts out without any.
+        Self {
+            generation: self.generation,
+            ..Self::default()
+        }
+    }
+}
+
+impl PartialEq for EditState {
+    fn eq(&self, _: &EditState) -> bool {
+        true
+    }
+}
+
+/// A transaction started by [`Run::begin_edit`]. While the transaction is
+/// active, fixing the splits and regenerating the comparisons is deferred.
+/// Instead the Run is recomputed once when the transaction is dropped. The
+/// transaction dereferences to the [`Run`], so all the usual methods can be
+/// used through it.
+pub struct Transaction<'a> {
+    run: &'a mut Run,
+}
+
+impl<'a> Transaction<'a> {
+    pub(super) fn new(run: &'a mut Run) -> Self {
+        run.start_edit();
+        Self { run }
+    }
+}
+
+impl Deref for Transaction<'_> {
+    type Target = Run;
+
+    fn deref(&self) -> &Self::Target {
+        self.run
+    }
+}
+
+impl DerefMut for Transaction<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.run
+    }
+}
+
+impl Drop for Transaction<'_> {
+    fn drop(&mut self) {
+        self.run.end_edit();
+    }
+}
@@ -16,6 +16,23 @@ pub enum Gradient {
     Horizontal(Color, Color),
 }
 
+/// Describes the color space that the colors of a [`Gradient`] are
+/// interpolated in.
+#[derive(Debug, Copy, Clone, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum GradientColorSpace {
+    /// Interpolate the gamma encoded sRGB components of the colors. This is
+    /// how gradients are usually interpolated, but the colors in the middle
+    /// of the gradient may appear darker and muddier than expected.
+    #[default]
+    Srgb,
+    /// Interpolate the linear RGB components of the colors, which keeps the
+    /// brightness in the middle of the gradient more consistent.
+    Linear,
+    /// Interpolate the colors in the perceptually uniform Oklab color space,
+    /// which results in the smoothest looking transitions.
+    Oklab,
+}
+
 /// Describes an extended form of a gradient, specifically made for use with
 /// lists. It allows specifying different coloration for the rows in a list.
 #[derive(Debug, Copy, Clone, Serialize, Deserialize, PartialEq)]
@@ -17,7 +17,7 @@ pub use self::{
     color::Color,
     field::Field,
     font::{Font, Stretch as FontStretch, Style as FontStyle, Weight as FontWeight},
-    gradient::{Gradient, ListGradient},
+    gradient::{Gradient, GradientColorSpace, ListGradient},
     image::{HasImageId, Image, ImageCache, ImageId},
     layout_background::{BackgroundImage, LayoutBackground, BLUR_FACTOR},
     semantic_color::SemanticColor,
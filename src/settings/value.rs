@@ -1,13 +1,20 @@
 use crate::{
     TimingMethod,
     component::{
-        splits::{ColumnStartWith, ColumnUpdateTrigger, ColumnUpdateWith},
+        splits::{
+            ColumnAlignment, ColumnStartWith, ColumnUpdateTrigger, ColumnUpdateWith, SeparatorMode,
+            CurrentSplitAnchor, MedalBands, MedalColors, SignStyle,
+            SkippedSegmentDelta,
+        },
         timer::DeltaGradient,
     },
     hotkey::Hotkey,
     layout::LayoutDirection,
     platform::prelude::*,
-    settings::{Alignment, Color, Font, Gradient, ImageId, LayoutBackground, ListGradient},
+    settings::{
+        Alignment, Color, Font, Gradient, GradientColorSpace, ImageId, LayoutBackground,
+        ListGradient,
+    },
     timing::formatter::{Accuracy, DigitsFormat},
 };
 use core::result::Result as StdResult;
@@ -32,13 +39,20 @@ pub enum Value {
     UInt(u64),
     /// An integer.
     Int(i64),
+    /// A floating point number.
+    Float(f64),
     /// A string.
     String(String),
     /// An optional string.
     OptionalString(Option<String>),
+    /// An optional floating point number.
+    OptionalFloat(Option<f64>),
     /// An accuracy, describing how many digits to show for the fractional part
     /// of a time.
     Accuracy(Accuracy),
+    /// An optional accuracy, describing how many digits to show for the
+    /// fractional part of a time. [`None`] if the default accuracy is used.
+    OptionalAccuracy(Option<Accuracy>),
     /// A digits format, describing how many digits to show for the main part of
     /// a time.
     DigitsFormat(DigitsFormat),
@@ -52,6 +66,8 @@ pub enum Value {
     Gradient(Gradient),
     /// A gradient designed for use with lists.
     ListGradient(ListGradient),
+    /// A value describing the color space that gradients are interpolated in.
+    GradientColorSpace(GradientColorSpace),
     /// An alignment for the Title Component's title.
     Alignment(Alignment),
     /// A column kind.
@@ -64,6 +80,28 @@ pub enum Value {
     ColumnUpdateWith(ColumnUpdateWith),
     /// A value describing when to update a column of the Splits Component.
     ColumnUpdateTrigger(ColumnUpdateTrigger),
+    /// A value describing how the values of a column of the Splits Component
+    /// are aligned. [`None`] if the alignment is based on the kind of the
+    /// column.
+    OptionalColumnAlignment(Option<ColumnAlignment>),
+    /// A value describing how the signs of the deltas in a column of the
+    /// Splits Component are shown. [`None`] if the default style is used.
+    OptionalSignStyle(Option<SignStyle>),
+    /// A value describing between which rows of the Splits Component thin
+    /// separators are shown.
+    SeparatorMode(SeparatorMode),
+    /// A value describing how columns of the Splits Component display the
+    /// segment delta of a segment that follows a skipped segment.
+    SkippedSegmentDelta(SkippedSegmentDelta),
+    /// A value describing where the Splits Component keeps the current
+    /// segment within the scrolling window of segments.
+    CurrentSplitAnchor(CurrentSplitAnchor),
+    /// An optional value describing the time bands that determine which medal
+    /// each segment of the Splits Component is awarded.
+    OptionalMedalBands(Option<MedalBands>),
+    /// An optional value describing the colors that the backgrounds of the
+    /// segments of the Splits Component are tinted with based on their medals.
+    OptionalMedalColors(Option<MedalColors>),
     /// A value describing what hotkey to press to trigger a certain action.
     Hotkey(Option<Hotkey>),
     /// A value describing the direction of a layout.
@@ -96,6 +134,12 @@ impl From<i64> for Value {
     }
 }
 
+impl From<f64> for Value {
+    fn from(x: f64) -> Self {
+        Value::Float(x)
+    }
+}
+
 impl From<String> for Value {
     fn from(x: String) -> Self {
         Value::String(x)
@@ -108,12 +152,24 @@ impl From<Option<String>> for Value {
     }
 }
 
+impl From<Option<f64>> for Value {
+    fn from(x: Option<f64>) -> Self {
+        Value::OptionalFloat(x)
+    }
+}
+
 impl From<Accuracy> for Value {
     fn from(x: Accuracy) -> Self {
         Value::Accuracy(x)
     }
 }
 
+impl From<Option<Accuracy>> for Value {
+    fn from(x: Option<Accuracy>) -> Self {
+        Value::OptionalAccuracy(x)
+    }
+}
+
 impl From<DigitsFormat> for Value {
     fn from(x: DigitsFormat) -> Self {
         Value::DigitsFormat(x)
@@ -150,6 +206,12 @@ impl From<ListGradient> for Value {
     }
 }
 
+impl From<GradientColorSpace> for Value {
+    fn from(x: GradientColorSpace) -> Self {
+        Value::GradientColorSpace(x)
+    }
+}
+
 impl From<Alignment> for Value {
     fn from(x: Alignment) -> Self {
         Value::Alignment(x)
@@ -174,6 +236,48 @@ impl From<ColumnUpdateTrigger> for Value {
     }
 }
 
+impl From<Option<ColumnAlignment>> for Value {
+    fn from(x: Option<ColumnAlignment>) -> Self {
+        Value::OptionalColumnAlignment(x)
+    }
+}
+
+impl From<Option<SignStyle>> for Value {
+    fn from(x: Option<SignStyle>) -> Self {
+        Value::OptionalSignStyle(x)
+    }
+}
+
+impl From<SeparatorMode> for Value {
+    fn from(x: SeparatorMode) -> Self {
+        Value::SeparatorMode(x)
+    }
+}
+
+impl From<SkippedSegmentDelta> for Value {
+    fn from(x: SkippedSegmentDelta) -> Self {
+        Value::SkippedSegmentDelta(x)
+    }
+}
+
+impl From<Option<MedalBands>> for Value {
+    fn from(x: Option<MedalBands>) -> Self {
+        Value::OptionalMedalBands(x)
+    }
+}
+
+impl From<Option<MedalColors>> for Value {
+    fn from(x: Option<MedalColors>) -> Self {
+        Value::OptionalMedalColors(x)
+    }
+}
+
+impl From<CurrentSplitAnchor> for Value {
+    fn from(x: CurrentSplitAnchor) -> Self {
+        Value::CurrentSplitAnchor(x)
+    }
+}
+
 impl From<Option<Hotkey>> for Value {
     fn from(x: Option<Hotkey>) -> Self {
         Value::Hotkey(x)
@@ -245,6 +349,14 @@ impl Value {
         }
     }
 
+    /// Tries to convert the value into a floating point number.
+    pub fn into_float(self) -> Result<f64> {
+        match self {
+            Value::Float(v) => Ok(v),
+            _ => Err(Error::WrongType),
+        }
+    }
+
     /// Tries to convert the value into a string.
     pub fn into_string(self) -> Result<String> {
         match self {
@@ -261,6 +373,14 @@ impl Value {
         }
     }
 
+    /// Tries to convert the value into an optional floating point number.
+    pub fn into_optional_float(self) -> Result<Option<f64>> {
+        match self {
+            Value::OptionalFloat(v) => Ok(v),
+            _ => Err(Error::WrongType),
+        }
+    }
+
     /// Tries to convert the value into an accuracy.
     pub fn into_accuracy(self) -> Result<Accuracy> {
         match self {
@@ -269,6 +389,14 @@ impl Value {
         }
     }
 
+    /// Tries to convert the value into an optional accuracy.
+    pub fn into_optional_accuracy(self) -> Result<Option<Accuracy>> {
+        match self {
+            Value::OptionalAccuracy(v) => Ok(v),
+            _ => Err(Error::WrongType),
+        }
+    }
+
     /// Tries to convert the value into a digits format.
     pub fn into_digits_format(self) -> Result<DigitsFormat> {
         match self {
@@ -320,6 +448,14 @@ impl Value {
         }
     }
 
+    /// Tries to convert the value into a gradient color space.
+    pub fn into_gradient_color_space(self) -> Result<GradientColorSpace> {
+        match self {
+            Value::GradientColorSpace(v) => Ok(v),
+            _ => Err(Error::WrongType),
+        }
+    }
+
     /// Tries to convert the value into an alignment.
     pub fn into_alignment(self) -> Result<Alignment> {
         match self {
@@ -354,6 +490,62 @@ impl Value {
         }
     }
 
+    /// Tries to convert the value into an optional Column Alignment.
+    pub fn into_optional_column_alignment(self) -> Result<Option<ColumnAlignment>> {
+        match self {
+            Value::OptionalColumnAlignment(v) => Ok(v),
+            _ => Err(Error::WrongType),
+        }
+    }
+
+    /// Tries to convert the value into an optional Sign Style.
+    pub fn into_optional_sign_style(self) -> Result<Option<SignStyle>> {
+        match self {
+            Value::OptionalSignStyle(v) => Ok(v),
+            _ => Err(Error::WrongType),
+        }
+    }
+
+    /// Tries to convert the value into a Separator Mode.
+    pub fn into_separator_mode(self) -> Result<SeparatorMode> {
+        match self {
+            Value::SeparatorMode(v) => Ok(v),
+            _ => Err(Error::WrongType),
+        }
+    }
+
+    /// Tries to convert the value into a Skipped Segment Delta setting.
+    pub fn into_skipped_segment_delta(self) -> Result<SkippedSegmentDelta> {
+        match self {
+            Value::SkippedSegmentDelta(v) => Ok(v),
+            _ => Err(Error::WrongType),
+        }
+    }
+
+    /// Tries to convert the value into a Current Split Anchor.
+    pub fn into_current_split_anchor(self) -> Result<CurrentSplitAnchor> {
+        match self {
+            Value::CurrentSplitAnchor(v) => Ok(v),
+            _ => Err(Error::WrongType),
+        }
+    }
+
+    /// Tries to convert the value into optional medal bands.
+    pub fn into_optional_medal_bands(self) -> Result<Option<MedalBands>> {
+        match self {
+            Value::OptionalMedalBands(v) => Ok(v),
+            _ => Err(Error::WrongType),
+        }
+    }
+
+    /// Tries to convert the value into optional medal colors.
+    pub fn into_optional_medal_colors(self) -> Result<Option<MedalColors>> {
+        match self {
+            Value::OptionalMedalColors(v) => Ok(v),
+            _ => Err(Error::WrongType),
+        }
+    }
+
     /// Tries to convert the value into a hotkey.
     pub fn into_hotkey(self) -> Result<Option<Hotkey>> {
         match self {
@@ -429,6 +621,12 @@ impl From<Value> for i64 {
     }
 }
 
+impl From<Value> for f64 {
+    fn from(value: Value) -> Self {
+        value.into_float().unwrap()
+    }
+}
+
 impl From<Value> for String {
     fn from(value: Value) -> Self {
         value.into_string().unwrap()
@@ -441,12 +639,24 @@ impl From<Value> for Option<String> {
     }
 }
 
+impl From<Value> for Option<f64> {
+    fn from(value: Value) -> Self {
+        value.into_optional_float().unwrap()
+    }
+}
+
 impl From<Value> for Accuracy {
     fn from(value: Value) -> Self {
         value.into_accuracy().unwrap()
     }
 }
 
+impl From<Value> for Option<Accuracy> {
+    fn from(value: Value) -> Self {
+        value.into_optional_accuracy().unwrap()
+    }
+}
+
 impl From<Value> for DigitsFormat {
     fn from(value: Value) -> Self {
         value.into_digits_format().unwrap()
@@ -483,6 +693,12 @@ impl From<Value> for ListGradient {
     }
 }
 
+impl From<Value> for GradientColorSpace {
+    fn from(value: Value) -> Self {
+        value.into_gradient_color_space().unwrap()
+    }
+}
+
 impl From<Value> for Alignment {
     fn from(value: Value) -> Self {
         value.into_alignment().unwrap()
@@ -507,6 +723,48 @@ impl From<Value> for ColumnUpdateTrigger {
     }
 }
 
+impl From<Value> for Option<ColumnAlignment> {
+    fn from(value: Value) -> Self {
+        value.into_optional_column_alignment().unwrap()
+    }
+}
+
+impl From<Value> for Option<SignStyle> {
+    fn from(value: Value) -> Self {
+        value.into_optional_sign_style().unwrap()
+    }
+}
+
+impl From<Value> for SeparatorMode {
+    fn from(value: Value) -> Self {
+        value.into_separator_mode().unwrap()
+    }
+}
+
+impl From<Value> for SkippedSegmentDelta {
+    fn from(value: Value) -> Self {
+        value.into_skipped_segment_delta().unwrap()
+    }
+}
+
+impl From<Value> for CurrentSplitAnchor {
+    fn from(value: Value) -> Self {
+        value.into_current_split_anchor().unwrap()
+    }
+}
+
+impl From<Value> for Option<MedalBands> {
+    fn from(value: Value) -> Self {
+        value.into_optional_medal_bands().unwrap()
+    }
+}
+
+impl From<Value> for Option<MedalColors> {
+    fn from(value: Value) -> Self {
+        value.into_optional_medal_colors().unwrap()
+    }
+}
+
 impl From<Value> for Option<Hotkey> {
     fn from(value: Value) -> Self {
         value.into_hotkey().unwrap()
@@ -59,6 +59,9 @@ pub const MINUS: &str = "−";
 pub const ASCII_MINUS: &str = "-";
 /// The plus symbol to use for positive numbers.
 pub const PLUS: &str = "+";
+/// The plus-minus symbol to use for numbers that are exactly zero, where a
+/// sign is supposed to be shown regardless.
+pub const PLUS_MINUS: &str = "±";
 
 const SECONDS_PER_MINUTE: u64 = 60;
 const SECONDS_PER_HOUR: u64 = 60 * SECONDS_PER_MINUTE;
@@ -17,7 +17,7 @@ pub use self::{
     time::{GameTime, RealTime, Time},
     time_span::{ParseError, TimeSpan},
     time_stamp::TimeStamp,
-    timer::{CreationError as TimerCreationError, Snapshot, Timer},
+    timer::{CreationError as TimerCreationError, RunSummary, Snapshot, Timer},
     timer_phase::TimerPhase,
     timing_method::TimingMethod,
 };
@@ -1,6 +1,7 @@
 use crate::{
     AtomicDateTime, Run, Time, TimeSpan, TimeStamp, TimingMethod,
     event::{Error, Event, Result},
+    platform::prelude::*,
 };
 
 #[derive(Debug, Clone)]
@@ -16,6 +17,8 @@ pub struct ActiveAttempt {
     pub adjusted_offset: TimeSpan,
     pub game_time_paused_at: Option<TimeSpan>,
     pub loading_times: Option<TimeSpan>,
+    /// The best segment times of all the segments when the attempt started.
+    pub best_segments_at_start: Vec<Time>,
 }
 
 #[derive(Debug, Clone)]
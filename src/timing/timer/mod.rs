@@ -2,20 +2,29 @@ use crate::{
     AtomicDateTime, Run, Segment, Time, TimeSpan, TimeStamp,
     TimerPhase::{self, *},
     TimingMethod,
-    analysis::check_best_segment,
+    analysis::{
+        check_best_segment, comparison_single_segment_time, delta, live_segment_time,
+        previous_segment_time,
+    },
     comparison::personal_best,
-    event::{Error, Event},
+    event::{Error, Event, GoldAchieved},
     platform::prelude::*,
+    run::Attempt,
+    settings::SemanticColor,
     util::PopulateString,
 };
 use core::{mem, ops::Deref};
+use hashbrown::HashMap;
 
 #[cfg(test)]
 mod tests;
 
 mod active_attempt;
+mod summary;
 use active_attempt::{ActiveAttempt, State};
 
+pub use summary::RunSummary;
+
 /// A `Timer` provides all the capabilities necessary for doing speedrun attempts.
 ///
 /// # Examples
@@ -52,8 +61,40 @@ use active_attempt::{ActiveAttempt, State};
 pub struct Timer {
     run: Run,
     current_comparison: String,
+    preview_comparison: Option<String>,
     current_timing_method: TimingMethod,
+    custom_timing_methods: Vec<(String, Option<TimeSpan>)>,
+    split_confirmation_window: Option<TimeSpan>,
+    practice_mode: bool,
     active_attempt: Option<ActiveAttempt>,
+    reset_backup: Option<Box<ResetBackup>>,
+}
+
+/// Everything the most recent reset changed, so that the reset can be undone.
+/// Only the parts of the Run that a reset changes are kept, rather than a copy
+/// of the whole Run.
+#[derive(Debug, Clone)]
+struct ResetBackup {
+    active_attempt: ActiveAttempt,
+    custom_timing_methods: Vec<(String, Option<TimeSpan>)>,
+    segments: Vec<SegmentBackup>,
+    /// The index of the attempt the reset added to the Attempt History, if
+    /// the splits were updated.
+    attempt_index: Option<i32>,
+    /// The minimum index of the Segment Histories before the reset. Anything
+    /// below is imported by the reset when the Personal Best changes.
+    min_segment_history_index: Option<i32>,
+    run_id: String,
+}
+
+/// The information of a segment that a reset changes.
+#[derive(Debug, Clone)]
+struct SegmentBackup {
+    split_time: Time,
+    variables: HashMap<String, String>,
+    custom_split_times: HashMap<String, TimeSpan>,
+    best_segment_time: Time,
+    personal_best_split_time: Time,
 }
 
 /// A snapshot represents a specific point in time that the timer was observed
@@ -66,10 +107,103 @@ pub struct Snapshot<'timer> {
 
 impl Snapshot<'_> {
     /// Returns the time the timer was at when the snapshot was taken. The Game
-    /// Time is [`None`] if the Game Time has not been initialized.
+    /// Time is [`None`] if the Game Time has not been initialized. The times
+    /// are exact and not rounded in any way, so they can be indexed by a
+    /// [`TimingMethod`] to do further calculations with them.
     pub const fn current_time(&self) -> Time {
         self.time
     }
+
+    /// Returns the Real Time and the Game Time of
+    /// [`current_time`](Self::current_time), in that order.
+    pub const fn current_time_both(&self) -> (Option<TimeSpan>, Option<TimeSpan>) {
+        let time = self.current_time();
+        (time.real_time, time.game_time)
+    }
+
+    /// Returns the best segment time of the segment specified as it was when
+    /// the active attempt started. Unlike the best segment times stored in the
+    /// run, this doesn't change for the duration of the attempt, even if the
+    /// run gets edited. If there is no active attempt, the run's current best
+    /// segment time is returned instead. Returns [`None`] if there is no such
+    /// segment or it doesn't have a best segment time.
+    pub fn best_segment_time_at_start(
+        &self,
+        index: usize,
+        method: TimingMethod,
+    ) -> Option<TimeSpan> {
+        match &self.active_attempt {
+            Some(active_attempt) => active_attempt.best_segments_at_start.get(index)?[method],
+            None => self.run.segments().get(index)?.best_segment_time()[method],
+        }
+    }
+
+    /// Returns the segment time of the current comparison for the segment the
+    /// active attempt is currently on. Returns [`None`] if there is no segment
+    /// currently being run or the comparison doesn't have a segment time for
+    /// it.
+    pub fn current_comparison_segment_time(&self, method: TimingMethod) -> Option<TimeSpan> {
+        self.comparison_segment_time(self.current_comparison(), method)
+    }
+
+    /// Returns the segment time of the comparison provided for the segment the
+    /// active attempt is currently on. Returns [`None`] if there is no segment
+    /// currently being run or the comparison doesn't have a segment time for
+    /// it.
+    pub fn comparison_segment_time(
+        &self,
+        comparison: &str,
+        method: TimingMethod,
+    ) -> Option<TimeSpan> {
+        let index = self.current_split_index()?;
+        if index >= self.run.len() {
+            return None;
+        }
+        comparison_single_segment_time(&self.run, index, comparison, method)
+    }
+
+    /// Returns the final time of the comparison provided, which is the split
+    /// time of the last segment in that comparison. Returns [`None`] if the run
+    /// has no segments or the comparison doesn't have a split time for the
+    /// last segment.
+    pub fn comparison_final_time(
+        &self,
+        comparison: &str,
+        method: TimingMethod,
+    ) -> Option<TimeSpan> {
+        self.run.segments().last()?.comparison(comparison)[method]
+    }
+
+    /// Returns the time that has passed since the previous split for the
+    /// segment the active attempt is currently on. This is the same time the
+    /// segment timer of the Detailed Timer Component shows. If earlier
+    /// segments were skipped, it is measured from the last split that has a
+    /// split time. Returns [`None`] if there is no segment currently being run
+    /// or the timing method has no current time.
+    pub fn current_segment_time(&self, method: TimingMethod) -> Option<TimeSpan> {
+        let index = self.current_split_index()?;
+        if index >= self.run.len() {
+            return None;
+        }
+        live_segment_time(self, index, method)
+    }
+
+    /// Returns how far ahead or behind the active attempt is compared to the
+    /// comparison provided, along with the semantic color of that delta. This
+    /// is the same value the Delta Component shows. While the attempt is
+    /// running, this is the delta of the last split, unless the current
+    /// segment is already losing more time than that, in which case it is the
+    /// live delta of the current segment. Returns [`None`] if there is no
+    /// delta, such as when there is no active attempt.
+    pub fn live_delta(
+        &self,
+        comparison: &str,
+        method: TimingMethod,
+    ) -> Option<(TimeSpan, SemanticColor)> {
+        let (value, use_live_delta) = delta::calculate_with_method(self, comparison, method);
+        let color = delta::semantic_color(self, value, use_live_delta, comparison, method);
+        Some((value?, color))
+    }
 }
 
 impl Deref for Snapshot<'_> {
@@ -107,10 +241,15 @@ impl Timer {
         run.regenerate_comparisons();
 
         Ok(Timer {
+            current_timing_method: run.default_timing_method(),
             run,
             current_comparison: personal_best::NAME.into(),
-            current_timing_method: TimingMethod::RealTime,
+            preview_comparison: None,
+            custom_timing_methods: Vec::new(),
+            split_confirmation_window: None,
+            practice_mode: false,
             active_attempt: None,
+            reset_backup: None,
         })
     }
 
@@ -135,7 +274,8 @@ impl Timer {
     /// and is returned as the `Err` case of the `Result`. Otherwise the Run
     /// that was in use by the Timer is being returned. Before the Run is
     /// returned, the current attempt is reset and the splits are being updated
-    /// depending on the `update_splits` parameter.
+    /// depending on the `update_splits` parameter. The current timing method
+    /// is switched to the default timing method of the new Run.
     #[allow(clippy::result_large_err)]
     pub fn replace_run(&mut self, mut run: Run, update_splits: bool) -> Result<Run, Run> {
         if run.is_empty() {
@@ -146,9 +286,16 @@ impl Timer {
         if !run.comparisons().any(|c| c == self.current_comparison) {
             self.current_comparison = personal_best::NAME.to_string();
         }
+        if let Some(preview_comparison) = &self.preview_comparison {
+            if !run.comparisons().any(|c| c == preview_comparison) {
+                self.preview_comparison = None;
+            }
+        }
 
         run.fix_splits();
         run.regenerate_comparisons();
+        self.reset_backup = None;
+        self.current_timing_method = run.default_timing_method();
 
         Ok(mem::replace(&mut self.run, run))
     }
@@ -156,12 +303,124 @@ impl Timer {
     /// Sets the Run object used by the Timer with the Run object provided. If
     /// the Run provided contains no segments, it can't be used for timing and
     /// is returned as the Err case of the Result. The Run object in use by the
-    /// Timer is dropped by this method.
+    /// Timer is dropped by this method. The current timing method is switched
+    /// to the default timing method of the new Run.
     #[allow(clippy::result_large_err)]
     pub fn set_run(&mut self, run: Run) -> Result<(), Run> {
         self.replace_run(run, false).map(drop)
     }
 
+    /// Replaces the Run object used by the Timer with an edited version of it,
+    /// without resetting the active attempt. This is meant to be used when the
+    /// runner edits the Run while an attempt is in progress, such as adding or
+    /// removing segments. The current split index of the attempt is derived
+    /// from the split times of the new segments: the attempt continues right
+    /// after the last segment that has a split time, with the segments that
+    /// were skipped right before the current segment staying skipped. It is
+    /// clamped into the range of the new segments and all the split times from
+    /// the current segment onwards are dropped, as they can't be valid anymore.
+    /// A finished attempt stays finished if all the segments up to the last one
+    /// still have a split time. Otherwise it continues on the first segment
+    /// after them. The best segment times at the start of the attempt are
+    /// matched up with the new segments by their names, with segments that
+    /// can't be matched up using their current best segment times. If the Run
+    /// provided contains no segments, it can't be used for timing and is
+    /// returned as the Err case of the Result.
+    #[allow(clippy::result_large_err)]
+    pub fn reconcile_after_edit(&mut self, mut run: Run) -> Result<Event, Run> {
+        if run.is_empty() {
+            return Err(run);
+        }
+
+        if let Some(active_attempt) = &mut self.active_attempt {
+            let old_segments = self.run.segments();
+            let has_split_time = |segment: &Segment| {
+                let split_time = segment.split_time();
+                split_time.real_time.is_some() || split_time.game_time.is_some()
+            };
+            let last_index = run.len() - 1;
+            let split_count = run
+                .segments()
+                .iter()
+                .rposition(has_split_time)
+                .map_or(0, |index| index + 1);
+
+            let current_split_index = match active_attempt.state {
+                State::NotEnded {
+                    current_split_index,
+                    ..
+                } => {
+                    let skipped = old_segments[..current_split_index.min(old_segments.len())]
+                        .iter()
+                        .rev()
+                        .take_while(|&segment| !has_split_time(segment))
+                        .count();
+                    Some((split_count + skipped).min(last_index))
+                }
+                State::Ended { .. } => (split_count <= last_index).then_some(split_count),
+            };
+
+            let mut old_index = 0;
+            let best_segments_at_start = run
+                .segments()
+                .iter()
+                .map(|segment| {
+                    let offset = old_segments[old_index..]
+                        .iter()
+                        .position(|old| old.name() == segment.name());
+                    if let Some(offset) = offset {
+                        old_index += offset + 1;
+                        if let Some(&best_segment_time) =
+                            active_attempt.best_segments_at_start.get(old_index - 1)
+                        {
+                            return best_segment_time;
+                        }
+                    }
+                    segment.best_segment_time()
+                })
+                .collect();
+            active_attempt.best_segments_at_start = best_segments_at_start;
+
+            if let Some(current_split_index) = current_split_index {
+                for segment in &mut run.segments_mut()[current_split_index..] {
+                    segment.clear_split_info();
+                }
+                if let State::NotEnded {
+                    current_split_index: index,
+                    ..
+                } = &mut active_attempt.state
+                {
+                    *index = current_split_index;
+                } else {
+                    active_attempt.state = State::NotEnded {
+                        current_split_index,
+                        time_paused_at: None,
+                    };
+                }
+            }
+        } else {
+            for segment in run.segments_mut() {
+                segment.clear_split_info();
+            }
+        }
+
+        if !run.comparisons().any(|c| c == self.current_comparison) {
+            self.current_comparison = personal_best::NAME.to_string();
+        }
+        if let Some(preview_comparison) = &self.preview_comparison {
+            if !run.comparisons().any(|c| c == preview_comparison) {
+                self.preview_comparison = None;
+            }
+        }
+
+        run.fix_splits();
+        run.regenerate_comparisons();
+        self.run = run;
+        self.reset_backup = None;
+
+        Ok(Event::AttemptReconciled)
+    }
+
     /// Accesses the Run in use by the Timer.
     #[inline]
     pub const fn run(&self) -> &Run {
@@ -175,6 +434,14 @@ impl Timer {
         self.run.mark_as_unmodified();
     }
 
+    /// Sets the amount of runs that have been attempted with the Run. This can
+    /// be used to correct the attempt count, as the attempt count is otherwise
+    /// only incremented when starting the Timer.
+    pub const fn set_attempt_count(&mut self, attempts: u32) {
+        self.run.set_attempt_count(attempts);
+        self.run.mark_as_modified();
+    }
+
     /// Returns the current Timer Phase.
     #[inline]
     pub const fn current_phase(&self) -> TimerPhase {
@@ -224,6 +491,45 @@ impl Timer {
         self.current_timing_method = method;
     }
 
+    /// Returns the split confirmation window. Splits that happen within this
+    /// amount of real time after the previous split are ignored. This is
+    /// [`None`] by default, which means that no splits are ignored.
+    #[inline]
+    pub const fn split_confirmation_window(&self) -> Option<TimeSpan> {
+        self.split_confirmation_window
+    }
+
+    /// Sets the split confirmation window. Any split that happens within this
+    /// amount of real time after the previous split is treated as a
+    /// duplicate of it and gets rejected with
+    /// [`Error::SplitWithinConfirmationWindow`]. This protects against both
+    /// accidental double presses and auto splitters splitting twice in a row.
+    /// Use [`None`] to disable this.
+    #[inline]
+    pub const fn set_split_confirmation_window(&mut self, window: Option<TimeSpan>) {
+        self.split_confirmation_window = window;
+    }
+
+    /// Returns whether the timer is in practice mode. See
+    /// [`set_practice_mode`](Self::set_practice_mode) for details.
+    #[inline]
+    pub const fn is_in_practice_mode(&self) -> bool {
+        self.practice_mode
+    }
+
+    /// Sets whether the timer is in practice mode. In practice mode, attempts
+    /// are purely ephemeral. Starting an attempt doesn't increment the attempt
+    /// count and resetting it never stores anything in the Run's history. So
+    /// neither the attempt history, the segment history, the best segments,
+    /// nor the Personal Best are ever updated, and the Run isn't marked as
+    /// modified by the attempt. The attempt itself is still timed and
+    /// visualized as usual. The mode is checked whenever an attempt is started
+    /// or reset, so it can be changed during an attempt as well.
+    #[inline]
+    pub const fn set_practice_mode(&mut self, practice_mode: bool) {
+        self.practice_mode = practice_mode;
+    }
+
     /// Toggles between the `Real Time` and `Game Time` timing methods.
     #[inline]
     pub const fn toggle_timing_method(&mut self) {
@@ -254,6 +560,36 @@ impl Timer {
         }
     }
 
+    /// Returns the comparison that is currently being previewed. Components may
+    /// choose to show this comparison instead of the current comparison, while
+    /// the current comparison itself stays unchanged. `None` is returned if no
+    /// comparison is being previewed.
+    #[inline]
+    pub fn preview_comparison(&self) -> Option<&str> {
+        self.preview_comparison.as_deref()
+    }
+
+    /// Tries to temporarily preview the comparison specified without changing
+    /// the current comparison. This is meant for hotkeys that show a different
+    /// comparison only while they are held down. If the comparison doesn't
+    /// exist `Err` is returned.
+    pub fn set_preview_comparison<S: PopulateString>(&mut self, comparison: S) -> Result<()> {
+        let as_str = comparison.as_str();
+        if self.run.comparisons().any(|c| c == as_str) {
+            comparison.populate(self.preview_comparison.get_or_insert_default());
+            Ok(())
+        } else {
+            Err(Error::ComparisonDoesntExist)
+        }
+    }
+
+    /// Stops previewing a comparison, so that components show the current
+    /// comparison again.
+    #[inline]
+    pub fn clear_preview_comparison(&mut self) {
+        self.preview_comparison = None;
+    }
+
     /// Accesses the split the attempt is currently on. If there's no attempt in
     /// progress or the run finished, `None` is returned instead.
     pub fn current_split(&self) -> Option<&Segment> {
@@ -280,7 +616,20 @@ impl Timer {
     /// Starts the Timer if there is no attempt in progress. If that's not the
     /// case, nothing happens.
     pub fn start(&mut self) -> Result {
+        self.start_attempt(true)
+    }
+
+    /// Starts the Timer if there is no attempt in progress, without
+    /// incrementing the Run's attempt count. This is useful for attempts that
+    /// aren't supposed to count, such as warm-ups. If there already is an
+    /// attempt in progress, nothing happens.
+    pub fn start_without_counting(&mut self) -> Result {
+        self.start_attempt(false)
+    }
+
+    fn start_attempt(&mut self, count_attempt: bool) -> Result {
         if self.active_attempt.is_none() {
+            self.reset_backup = None;
             let attempt_started = AtomicDateTime::now();
             let start_time = TimeStamp::now();
             let offset = self.run.offset();
@@ -296,8 +645,16 @@ impl Timer {
                 adjusted_offset: offset,
                 game_time_paused_at: None,
                 loading_times: None,
+                best_segments_at_start: self
+                    .run
+                    .segments()
+                    .iter()
+                    .map(Segment::best_segment_time)
+                    .collect(),
             });
-            self.run.start_next_run();
+            if count_attempt && !self.practice_mode {
+                self.run.start_next_run();
+            }
 
             Ok(Event::Started)
         } else {
@@ -308,8 +665,33 @@ impl Timer {
     /// If an attempt is in progress, stores the current time as the time of the
     /// current split. The attempt ends if the last split time is stored.
     pub fn split(&mut self) -> Result {
+        self.split_with_gold_callback(|_| {})
+    }
+
+    /// If an attempt is in progress, stores the current time as the time of the
+    /// current split. The attempt ends if the last split time is stored. If the
+    /// segment that just got completed is faster than the segment's best
+    /// segment time for the current timing method, the callback is called
+    /// exactly once with the old and the new best segment time. Segments that
+    /// directly follow a skipped split are never considered new best segments.
+    pub fn split_with_gold_callback(&mut self, on_gold: impl FnOnce(GoldAchieved)) -> Result {
         let active_attempt = self.active_attempt.as_mut().ok_or(Error::NoRunInProgress)?;
 
+        if let Some(window) = self.split_confirmation_window {
+            let index = active_attempt.current_split_index_overflowing(&self.run);
+            if index < self.run.len() {
+                if let Some(previous) = index
+                    .checked_sub(1)
+                    .and_then(|i| self.run.segment(i).split_time().real_time)
+                {
+                    let current = active_attempt.current_time(&self.run).real_time;
+                    if current - previous < window {
+                        return Err(Error::SplitWithinConfirmationWindow);
+                    }
+                }
+            }
+        }
+
         let (split_index, current_time, event) = active_attempt.prepare_split(&self.run)?;
 
         // FIXME: We shouldn't need to collect here.
@@ -323,8 +705,144 @@ impl Timer {
         let segment = self.run.segment_mut(split_index);
         segment.set_split_time(current_time);
         *segment.variables_mut() = variables;
+        let custom_split_times = segment.custom_split_times_mut();
+        custom_split_times.clear();
+        for (name, time) in &self.custom_timing_methods {
+            if let Some(time) = *time {
+                custom_split_times.insert(name.clone(), time);
+            }
+        }
 
-        self.run.mark_as_modified();
+        if !self.practice_mode {
+            self.run.mark_as_modified();
+        }
+
+        let method = self.current_timing_method;
+        let follows_skipped_split = split_index
+            .checked_sub(1)
+            .is_some_and(|i| self.run.segment(i).split_time()[method].is_none());
+
+        if !follows_skipped_split {
+            if let Some(new) = previous_segment_time(self, split_index, method) {
+                let old = self.run.segment(split_index).best_segment_time()[method];
+                if old.is_none_or(|old| new < old) {
+                    on_gold(GoldAchieved {
+                        segment_index: split_index,
+                        timing_method: method,
+                        old,
+                        new,
+                    });
+                }
+            }
+        }
+
+        Ok(event)
+    }
+
+    /// Starts a new attempt and stores the split times provided for all of its
+    /// segments at once, immediately finishing the attempt. This is useful
+    /// for retiming an attempt, for example from a video. A split time of
+    /// [`None`] skips the segment. There needs to be exactly one split time
+    /// per segment and the split times need to be increasing for each timing
+    /// method. The last segment can't be skipped. This is only possible if
+    /// there is no attempt in progress.
+    pub fn set_run_splits(&mut self, times: &[Option<Time>]) -> Result {
+        if self.active_attempt.is_some() {
+            return Err(Error::RunAlreadyInProgress);
+        }
+
+        if times.len() != self.run.len() {
+            return Err(Error::SplitCountMismatch);
+        }
+
+        let Some(Some(last_time)) = times.last().copied() else {
+            return Err(Error::CantSkipLastSplit);
+        };
+
+        for method in TimingMethod::all() {
+            let mut previous = TimeSpan::zero();
+            for time in times.iter().filter_map(|time| time.as_ref()?[method]) {
+                if time < TimeSpan::zero() {
+                    return Err(Error::NegativeTime);
+                }
+                if time < previous {
+                    return Err(Error::SplitTimesNotIncreasing);
+                }
+                previous = time;
+            }
+        }
+
+        self.start()?;
+
+        for (segment, time) in self.run.segments_mut().iter_mut().zip(times) {
+            segment.clear_split_info();
+            if let Some(time) = *time {
+                segment.set_split_time(time);
+            }
+        }
+
+        if let Some(active_attempt) = &mut self.active_attempt {
+            active_attempt.loading_times = catch! { last_time.real_time? - last_time.game_time? };
+            active_attempt.state = State::Ended {
+                attempt_ended: AtomicDateTime::now(),
+            };
+        }
+
+        if !self.practice_mode {
+            self.run.mark_as_modified();
+        }
+
+        Ok(Event::Finished)
+    }
+
+    /// Starts a new attempt and plays it back by splitting at each of the Game
+    /// Times provided in order. A Game Time of [`None`] skips the split. Unlike
+    /// the Real Time, the Game Time is fully under control of the caller, so
+    /// the Game Time split times are deterministic. This is useful for tests
+    /// and tools that need to produce known attempts. If fewer Game Times than
+    /// segments are provided, the attempt stays in progress. Splitting goes
+    /// through the regular splitting logic, but the split confirmation window
+    /// is not applied. This is only possible if there is no attempt in
+    /// progress. The event of the last action taken is returned.
+    pub fn apply_scripted_run(&mut self, game_times: &[Option<TimeSpan>]) -> Result {
+        if self.active_attempt.is_some() {
+            return Err(Error::RunAlreadyInProgress);
+        }
+
+        if game_times.len() > self.run.len() {
+            return Err(Error::SplitCountMismatch);
+        }
+
+        if game_times.len() == self.run.len() && game_times.last() == Some(&None) {
+            return Err(Error::CantSkipLastSplit);
+        }
+
+        if game_times
+            .iter()
+            .flatten()
+            .any(|&time| time < TimeSpan::zero())
+        {
+            return Err(Error::NegativeTime);
+        }
+
+        let mut event = self.start()?;
+        self.initialize_game_time()?;
+        self.pause_game_time()?;
+        self.set_game_time(TimeSpan::zero())?;
+
+        let split_confirmation_window = self.split_confirmation_window.take();
+        let result = game_times.iter().try_for_each(|&game_time| {
+            event = match game_time {
+                Some(game_time) => {
+                    self.set_game_time(game_time)?;
+                    self.split()?
+                }
+                None => self.skip_split()?,
+            };
+            Ok::<_, Error>(())
+        });
+        self.split_confirmation_window = split_confirmation_window;
+        result?;
 
         Ok(event)
     }
@@ -355,7 +873,9 @@ impl Timer {
 
             *current_split_index += 1;
 
-            self.run.mark_as_modified();
+            if !self.practice_mode {
+                self.run.mark_as_modified();
+            }
 
             Ok(Event::SplitSkipped)
         } else {
@@ -387,7 +907,9 @@ impl Timer {
                 .segment_mut(previous_split_index)
                 .clear_split_info();
 
-            self.run.mark_as_modified();
+            if !self.practice_mode {
+                self.run.mark_as_modified();
+            }
 
             Ok(Event::SplitUndone)
         } else {
@@ -395,6 +917,18 @@ impl Timer {
         }
     }
 
+    /// Removes the split time from the last split if an attempt is in progress
+    /// and there is a previous split. If the attempt is still on its first
+    /// split, the attempt is reset instead. If the splits are to be updated,
+    /// the information of the attempt is stored in the Run's history when
+    /// resetting. Nothing happens if there is no attempt in progress.
+    pub fn undo_split_or_reset(&mut self, update_splits: bool) -> Result {
+        match self.undo_split() {
+            Err(Error::CantUndoFirstSplit) => self.reset(update_splits),
+            result => result,
+        }
+    }
+
     /// Checks whether the current attempt has a new Personal Best for the
     /// [`TimingMethod`] specified.
     pub fn current_attempt_has_new_personal_best(&self, timing_method: TimingMethod) -> bool {
@@ -441,13 +975,148 @@ impl Timer {
             || self.current_attempt_has_new_personal_best(self.current_timing_method)
     }
 
+    /// Calculates the best final time that is still possible for the current
+    /// attempt with the [`TimingMethod`] specified. This is the current time
+    /// plus the best segment times of all the remaining segments. The current
+    /// segment is assumed to be finished with its best segment time, unless
+    /// more time than that has already passed. If the attempt has already
+    /// ended, its final time is returned. [`None`] is returned if there is no
+    /// attempt in progress or any of the remaining segments has no best
+    /// segment time.
+    pub fn best_possible_time(&self, method: TimingMethod) -> Option<TimeSpan> {
+        let current_split_index = self.current_split_index()?;
+        let segments = self.run.segments();
+
+        if current_split_index >= segments.len() {
+            return segments.last()?.split_time()[method];
+        }
+
+        let current_time = self.snapshot().current_time()[method]?;
+
+        // Skipped segments are part of the segment the runner is currently on,
+        // so the remaining time is measured from the last actual split.
+        let (last_split_index, last_split_time) = segments[..current_split_index]
+            .iter()
+            .enumerate()
+            .rev()
+            .find_map(|(index, segment)| Some((index + 1, segment.split_time()[method]?)))
+            .unwrap_or((0, TimeSpan::zero()));
+
+        let mut best_current_segment = TimeSpan::zero();
+        for segment in &segments[last_split_index..=current_split_index] {
+            best_current_segment += segment.best_segment_time()[method]?;
+        }
+
+        let mut best_possible_time = (last_split_time + best_current_segment).max(current_time);
+        for segment in &segments[current_split_index + 1..] {
+            best_possible_time += segment.best_segment_time()[method]?;
+        }
+
+        Some(best_possible_time)
+    }
+
+    /// Returns whether the current attempt is still on track to beat the sum
+    /// of best segments for the [`TimingMethod`] specified. This is the case
+    /// as long as every segment completed so far has been a new best segment.
+    /// Skipped segments are judged together with the segment they are
+    /// completed with. [`None`] is returned if there is no attempt in
+    /// progress.
+    pub fn is_on_best_possible_pace(&self, method: TimingMethod) -> Option<bool> {
+        let current_split_index = self.current_split_index()?;
+        let completed = current_split_index.min(self.run.len());
+
+        Some((0..completed).all(|segment_index| {
+            self.run.segment(segment_index).split_time()[method].is_none()
+                || check_best_segment(self, segment_index, method)
+        }))
+    }
+
+    /// Summarizes the attempt for the [`TimingMethod`] specified once it is
+    /// finished. [`None`] is returned if the attempt is not finished or if it
+    /// doesn't have a final time for the [`TimingMethod`] specified.
+    pub fn finished_summary(&self, method: TimingMethod) -> Option<RunSummary> {
+        if self.current_phase() != Ended {
+            return None;
+        }
+
+        let segments = self.run.segments();
+        let last_segment = segments.last()?;
+        let final_time = last_segment.split_time()[method]?;
+
+        let mut biggest_time_loss = None::<(usize, TimeSpan)>;
+        let mut previous = Some((TimeSpan::zero(), TimeSpan::zero()));
+        for (index, segment) in segments.iter().enumerate() {
+            let Some(split_time) = segment.split_time()[method] else {
+                continue;
+            };
+            let pb_split_time = segment.personal_best_split_time()[method];
+            let time_loss = catch! {
+                let (previous_split_time, previous_pb_split_time) = previous?;
+                (split_time - previous_split_time) - (pb_split_time? - previous_pb_split_time)
+            };
+            if let Some(time_loss) = time_loss {
+                if time_loss > TimeSpan::zero()
+                    && biggest_time_loss.is_none_or(|(_, biggest)| time_loss > biggest)
+                {
+                    biggest_time_loss = Some((index, time_loss));
+                }
+            }
+            previous = pb_split_time.map(|pb_split_time| (split_time, pb_split_time));
+        }
+
+        Some(RunSummary {
+            final_time,
+            personal_best_delta: catch! {
+                final_time - last_segment.personal_best_split_time()[method]?
+            },
+            gold_count: (0..segments.len())
+                .filter(|&index| check_best_segment(self, index, method))
+                .count(),
+            biggest_time_loss,
+            is_personal_best: self.current_attempt_has_new_personal_best(method),
+        })
+    }
+
+    /// Returns the final time the finished attempt would have had for the
+    /// [`TimingMethod`] specified, if every segment had been as fast as its
+    /// best segment time. Segments that were slower than their best segment
+    /// times are counted with their best segment times, while all the other
+    /// segments are counted with their actual segment times. Skipped segments
+    /// are judged together with the segment they are completed with. If any of
+    /// them doesn't have a best segment time, their actual time is used.
+    /// [`None`] is returned if the attempt is not finished or if it doesn't
+    /// have a final time for the [`TimingMethod`] specified.
+    pub fn finished_with_golds_time(&self, method: TimingMethod) -> Option<TimeSpan> {
+        if self.current_phase() != Ended {
+            return None;
+        }
+
+        let segments = self.run.segments();
+        segments.last()?.split_time()[method]?;
+
+        let mut total = TimeSpan::zero();
+        let mut previous_split_time = TimeSpan::zero();
+        let mut best_segments = Some(TimeSpan::zero());
+        for segment in segments {
+            best_segments = catch! { best_segments? + segment.best_segment_time()[method]? };
+            if let Some(split_time) = segment.split_time()[method] {
+                let segment_time = split_time - previous_split_time;
+                total += best_segments.map_or(segment_time, |best| best.min(segment_time));
+                previous_split_time = split_time;
+                best_segments = Some(TimeSpan::zero());
+            }
+        }
+
+        Some(total)
+    }
+
     /// Resets the current attempt if there is one in progress. If the splits
     /// are to be updated, all the information of the current attempt is stored
     /// in the Run's history. Otherwise the current attempt's information is
-    /// discarded.
+    /// discarded. In practice mode, the information is always discarded.
     pub fn reset(&mut self, update_splits: bool) -> Result {
         if self.active_attempt.is_some() {
-            self.reset_state(update_splits);
+            self.reset_state(update_splits && !self.practice_mode);
             self.reset_splits();
             Ok(Event::Reset)
         } else {
@@ -457,11 +1126,16 @@ impl Timer {
 
     /// Resets the current attempt if there is one in progress. The splits are
     /// updated such that the current attempt's split times are being stored as
-    /// the new Personal Best.
+    /// the new Personal Best. In practice mode, the attempt is discarded
+    /// instead.
     pub fn reset_and_set_attempt_as_pb(&mut self) -> Result {
         if self.active_attempt.is_some() {
-            self.reset_state(true);
-            set_run_as_pb(&mut self.run);
+            if self.practice_mode {
+                self.reset_state(false);
+            } else {
+                self.reset_state(true);
+                set_run_as_pb(&mut self.run);
+            }
             self.reset_splits();
             Ok(Event::Reset)
         } else {
@@ -469,14 +1143,104 @@ impl Timer {
         }
     }
 
+    /// Undoes the most recent reset, restoring the attempt that was in
+    /// progress with all of its split times, its phase and its current split
+    /// index. The attempt continues as if it was never reset, so the time that
+    /// passed in the meantime counts towards it. The attempt the reset added
+    /// to the history is removed again and the Best Segment Times and the
+    /// Personal Best are restored to what they were before the reset. This is
+    /// only possible until a new attempt is started or the Run is replaced.
+    pub fn undo_reset(&mut self) -> Result {
+        let backup = self.reset_backup.take().ok_or(Error::NoResetToUndo)?;
+        let ResetBackup {
+            active_attempt,
+            custom_timing_methods,
+            segments,
+            attempt_index,
+            min_segment_history_index,
+            run_id,
+        } = *backup;
+
+        if let Some(attempt_index) = attempt_index {
+            self.run.remove_attempt(attempt_index);
+        }
+        for (segment, backup) in self.run.segments_mut().iter_mut().zip(segments) {
+            if let Some(min_index) = min_segment_history_index {
+                segment
+                    .segment_history_mut()
+                    .retain(|&(index, _)| index >= min_index);
+            }
+            segment.set_split_time(backup.split_time);
+            *segment.variables_mut() = backup.variables;
+            *segment.custom_split_times_mut() = backup.custom_split_times;
+            segment.set_best_segment_time(backup.best_segment_time);
+            segment.set_personal_best_split_time(backup.personal_best_split_time);
+        }
+        self.run.metadata_mut().set_run_id(run_id);
+        self.run.fix_splits();
+        self.run.regenerate_comparisons();
+
+        self.active_attempt = Some(active_attempt);
+        for (name, time) in custom_timing_methods {
+            if let Some((_, current)) = self
+                .custom_timing_methods
+                .iter_mut()
+                .find(|(n, _)| *n == name)
+            {
+                *current = time;
+            }
+        }
+
+        Ok(Event::ResetUndone)
+    }
+
+    /// Returns whether the most recent reset can be undone with
+    /// [`undo_reset`](Self::undo_reset).
+    #[inline]
+    pub const fn can_undo_reset(&self) -> bool {
+        self.reset_backup.is_some()
+    }
+
     fn reset_state(&mut self, update_times: bool) {
         let Some(active_attempt) = self.active_attempt.take() else {
             return;
         };
 
-        if update_times {
-            active_attempt.update_times(&mut self.run, self.current_timing_method);
+        let segments = self
+            .run
+            .segments()
+            .iter()
+            .map(|segment| SegmentBackup {
+                split_time: segment.split_time(),
+                variables: segment.variables().clone(),
+                custom_split_times: segment.custom_split_times().clone(),
+                best_segment_time: segment.best_segment_time(),
+                personal_best_split_time: segment.personal_best_split_time(),
+            })
+            .collect();
+        let min_segment_history_index = self.run.min_segment_history_index();
+        let run_id = self.run.metadata().run_id().into();
+        let custom_timing_methods = self.custom_timing_methods.clone();
+
+        for (_, time) in &mut self.custom_timing_methods {
+            *time = None;
         }
+
+        let attempt_index = if update_times {
+            active_attempt.update_times(&mut self.run, self.current_timing_method);
+            self.run.attempt_history().last().map(Attempt::index)
+        } else {
+            None
+        };
+
+        self.reset_backup = Some(Box::new(ResetBackup {
+            active_attempt,
+            custom_timing_methods,
+            segments,
+            attempt_index,
+            min_segment_history_index,
+            run_id,
+        }));
     }
 
     fn reset_splits(&mut self) {
@@ -687,11 +1451,18 @@ impl Timer {
     }
 
     /// Pauses the Game Timer such that it doesn't automatically increment
-    /// similar to Real Time.
+    /// similar to Real Time. If Game Time is not initialized yet, it gets
+    /// initialized first, such that the Game Time is frozen at the current Real
+    /// Time. This way auto splitters that only ever pause and resume Game Time
+    /// to remove loading times don't need to initialize it explicitly.
     pub fn pause_game_time(&mut self) -> Result {
         let active_attempt = self.active_attempt.as_mut().ok_or(Error::NoRunInProgress)?;
 
         if active_attempt.game_time_paused_at.is_none() {
+            if active_attempt.loading_times.is_none() {
+                active_attempt.loading_times = Some(TimeSpan::zero());
+            }
+
             let current_time = active_attempt.current_time(&self.run);
 
             active_attempt.game_time_paused_at =
@@ -775,6 +1546,61 @@ impl Timer {
         }
     }
 
+    /// Registers an additional timing method with the name specified, such as
+    /// a count of in-game frames or a secondary game time. Its time can then be
+    /// set with [`set_custom_time`](Self::set_custom_time) and is kept for
+    /// every segment that gets split during the current attempt. Custom timing
+    /// methods are live only. Their times are neither stored in the splits
+    /// files nor recorded in the Segment History or the Attempt History, so
+    /// they are gone once the attempt is reset. Registering a timing method
+    /// that is already registered does nothing.
+    pub fn register_custom_timing_method<S>(&mut self, name: S)
+    where
+        S: PopulateString,
+    {
+        if !self
+            .custom_timing_methods
+            .iter()
+            .any(|(n, _)| n == name.as_str())
+        {
+            self.custom_timing_methods.push((name.into_string(), None));
+        }
+    }
+
+    /// Returns the names of all the custom timing methods that are registered.
+    pub fn custom_timing_methods(&self) -> impl Iterator<Item = &str> + '_ {
+        self.custom_timing_methods.iter().map(|(n, _)| n.as_str())
+    }
+
+    /// Accesses the current time of the custom timing method specified. `None`
+    /// is returned if the timing method is not registered or its time has not
+    /// been set during the current attempt.
+    pub fn custom_time(&self, name: &str) -> Option<TimeSpan> {
+        self.custom_timing_methods
+            .iter()
+            .find(|(n, _)| n == name)
+            .and_then(|(_, t)| *t)
+    }
+
+    /// Sets the time of the custom timing method specified. Just like the Game
+    /// Time, the time does not automatically move forward, so it needs to be
+    /// updated periodically. The time is reset at the end of every attempt.
+    pub fn set_custom_time(&mut self, name: &str, time: TimeSpan) -> Result {
+        if self.active_attempt.is_none() {
+            return Err(Error::NoRunInProgress);
+        }
+
+        let (_, current) = self
+            .custom_timing_methods
+            .iter_mut()
+            .find(|(n, _)| n == name)
+            .ok_or(Error::CustomTimingMethodDoesntExist)?;
+
+        *current = Some(time);
+
+        Ok(Event::CustomTimeSet)
+    }
+
     /// Notifies the `Timer` that the currently loaded [`Layout`](crate::Layout)
     /// has changed. If the [`Run`] has a
     /// [`LinkedLayout`](crate::run::LinkedLayout), it will be updated
@@ -0,0 +1,22 @@
+use crate::TimeSpan;
+
+/// A summary of a finished attempt, as returned by
+/// [`Timer::finished_summary`](super::Timer::finished_summary). It contains
+/// the information that is usually shown once an attempt is finished.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct RunSummary {
+    /// The final time of the attempt.
+    pub final_time: TimeSpan,
+    /// The difference between the final time and the Personal Best's final
+    /// time. A negative delta means that the attempt was faster. This is
+    /// [`None`] if there is no Personal Best to compare against.
+    pub personal_best_delta: Option<TimeSpan>,
+    /// The number of segments that are new best segments.
+    pub gold_count: usize,
+    /// The index of the segment where the most time was lost compared to the
+    /// Personal Best, along with the amount of time that was lost. This is
+    /// [`None`] if no time was lost on any of the segments.
+    pub biggest_time_loss: Option<(usize, TimeSpan)>,
+    /// Whether the attempt is a new Personal Best.
+    pub is_personal_best: bool,
+}
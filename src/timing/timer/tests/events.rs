@@ -1,7 +1,8 @@
 use crate::{
     comparison,
-    event::{Error, Event},
-    TimeSpan, Timer,
+    event::{Error, Event, GoldAchieved},
+    util::tests_helper::{run_with_splits, span, start_run},
+    TimeSpan, Timer, TimerPhase, TimingMethod,
 };
 
 use super::{run, timer};
@@ -98,6 +99,80 @@ mod split {
 
         assert_eq!(error, Error::TimerPaused);
     }
+
+    #[test]
+    fn reports_golds() {
+        let mut timer = timer();
+        run_with_splits(&mut timer, &[10.0, 20.0, 30.0]);
+
+        start_run(&mut timer);
+        let mut golds = Vec::new();
+
+        for split in [8.0, 20.0, 25.0] {
+            timer.set_game_time(span(split)).unwrap();
+            timer
+                .split_with_gold_callback(|gold| golds.push(gold))
+                .unwrap();
+        }
+
+        assert_eq!(
+            golds,
+            [
+                GoldAchieved {
+                    segment_index: 0,
+                    timing_method: TimingMethod::GameTime,
+                    old: Some(span(10.0)),
+                    new: span(8.0),
+                },
+                GoldAchieved {
+                    segment_index: 2,
+                    timing_method: TimingMethod::GameTime,
+                    old: Some(span(10.0)),
+                    new: span(5.0),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_golds_without_previous_best_segments() {
+        let mut timer = timer();
+
+        start_run(&mut timer);
+        let mut golds = Vec::new();
+
+        timer.set_game_time(span(5.0)).unwrap();
+        timer
+            .split_with_gold_callback(|gold| golds.push(gold))
+            .unwrap();
+
+        assert_eq!(
+            golds,
+            [GoldAchieved {
+                segment_index: 0,
+                timing_method: TimingMethod::GameTime,
+                old: None,
+                new: span(5.0),
+            }]
+        );
+    }
+
+    #[test]
+    fn doesnt_report_golds_after_skipped_splits() {
+        let mut timer = timer();
+        run_with_splits(&mut timer, &[10.0, 20.0, 30.0]);
+
+        start_run(&mut timer);
+        timer.skip_split().unwrap();
+        let mut golds = Vec::new();
+
+        timer.set_game_time(span(12.0)).unwrap();
+        timer
+            .split_with_gold_callback(|gold| golds.push(gold))
+            .unwrap();
+
+        assert!(golds.is_empty());
+    }
 }
 
 mod split_or_start {
@@ -265,6 +340,129 @@ mod reset_and_set_attempt_as_pb {
     }
 }
 
+mod undo_reset {
+    use super::*;
+
+    #[test]
+    fn restores_the_attempt() {
+        let mut timer = timer();
+        run_with_splits(&mut timer, &[5.0, 10.0, 15.0]);
+
+        start_run(&mut timer);
+        timer.set_game_time(span(4.0)).unwrap();
+        timer.split().unwrap();
+        timer.set_game_time(span(9.0)).unwrap();
+        timer.split().unwrap();
+        let run_before_reset = timer.run().clone();
+
+        timer.reset(true).unwrap();
+        assert_eq!(timer.run().attempt_history().len(), 2);
+        assert!(timer.can_undo_reset());
+
+        let event = timer.undo_reset().unwrap();
+
+        assert_eq!(event, Event::ResetUndone);
+        assert_eq!(timer.current_phase(), TimerPhase::Running);
+        assert_eq!(timer.current_split_index(), Some(2));
+        assert_eq!(
+            timer.run().attempt_history(),
+            run_before_reset.attempt_history()
+        );
+        for (segment, before) in timer
+            .run()
+            .segments()
+            .iter()
+            .zip(run_before_reset.segments())
+        {
+            assert_eq!(segment.split_time(), before.split_time());
+            assert_eq!(segment.best_segment_time(), before.best_segment_time());
+            assert_eq!(
+                segment.personal_best_split_time(),
+                before.personal_best_split_time()
+            );
+        }
+        assert!(!timer.can_undo_reset());
+
+        timer.set_game_time(span(14.0)).unwrap();
+        timer.split().unwrap();
+        assert_eq!(timer.current_phase(), TimerPhase::Ended);
+    }
+
+    #[test]
+    fn removes_the_history_the_reset_added() {
+        let mut timer = timer();
+        run_with_splits(&mut timer, &[5.0, 10.0, 15.0]);
+
+        start_run(&mut timer);
+        timer.set_game_time(span(4.0)).unwrap();
+        timer.split().unwrap();
+        let run_before_reset = timer.run().clone();
+
+        timer.reset_and_set_attempt_as_pb().unwrap();
+        assert_eq!(
+            timer.run().segment(0).personal_best_split_time().game_time,
+            Some(span(4.0))
+        );
+
+        timer.undo_reset().unwrap();
+
+        assert_eq!(
+            timer.run().attempt_history(),
+            run_before_reset.attempt_history()
+        );
+        for (segment, before) in timer
+            .run()
+            .segments()
+            .iter()
+            .zip(run_before_reset.segments())
+        {
+            assert_eq!(segment.segment_history(), before.segment_history());
+            assert_eq!(segment.best_segment_time(), before.best_segment_time());
+            assert_eq!(
+                segment.personal_best_split_time(),
+                before.personal_best_split_time()
+            );
+        }
+    }
+
+    #[test]
+    fn without_a_reset_fails() {
+        let mut timer = timer();
+
+        let error = timer.undo_reset().unwrap_err();
+
+        assert_eq!(error, Error::NoResetToUndo);
+    }
+
+    #[test]
+    fn starting_a_new_attempt_prevents_it() {
+        let mut timer = timer();
+
+        timer.start().unwrap();
+        timer.reset(true).unwrap();
+        timer.start().unwrap();
+        timer.reset(false).unwrap();
+        timer.start().unwrap();
+
+        assert!(!timer.can_undo_reset());
+        assert_eq!(timer.undo_reset().unwrap_err(), Error::NoResetToUndo);
+    }
+
+    #[test]
+    fn can_only_undo_the_most_recent_reset() {
+        let mut timer = timer();
+
+        timer.start().unwrap();
+        timer.reset(true).unwrap();
+        timer.undo_reset().unwrap();
+        timer.reset(true).unwrap();
+
+        timer.undo_reset().unwrap();
+        assert_eq!(timer.current_phase(), TimerPhase::Running);
+        assert_eq!(timer.undo_reset().unwrap_err(), Error::NoResetToUndo);
+    }
+}
+
 mod undo_split {
     use super::*;
 
@@ -61,6 +61,20 @@ fn when_starting_the_timer() {
     assert!(timer.run().has_been_modified());
 }
 
+#[test]
+fn not_when_starting_the_timer_without_counting() {
+    let mut timer = timer();
+    timer.start_without_counting().unwrap();
+    assert!(!timer.run().has_been_modified());
+}
+
+#[test]
+fn when_setting_the_attempt_count() {
+    let mut timer = timer();
+    timer.set_attempt_count(3);
+    assert!(timer.run().has_been_modified());
+}
+
 #[test]
 fn not_when_splitting_without_an_attempt() {
     let mut timer = timer();
@@ -1,13 +1,18 @@
 use crate::{
+    comparison::{best_segments, personal_best},
+    event::{Error, Event},
     run::Editor,
+    settings::SemanticColor,
     util::tests_helper::{
-        make_progress_run_with_splits_opt, run_with_splits, run_with_splits_opt, start_run,
+        create_run, make_progress_run_with_splits_opt, run_with_splits, run_with_splits_opt, span,
+        start_run,
     },
-    Run, Segment, TimeSpan, Timer, TimerPhase, TimingMethod,
+    Run, Segment, Time, TimeSpan, Timer, TimerPhase, TimingMethod,
 };
 
 mod events;
 mod mark_as_modified;
+mod practice_mode;
 mod variables;
 
 fn run() -> Run {
@@ -674,6 +679,107 @@ fn skipping_keeps_timer_paused() {
     assert_eq!(timer.current_split_index(), Some(0));
 }
 
+#[test]
+fn toggling_pause_only_switches_between_running_and_paused() {
+    let mut timer = timer();
+
+    assert_eq!(timer.toggle_pause(), Err(Error::NoRunInProgress));
+    assert_eq!(timer.current_phase(), TimerPhase::NotRunning);
+
+    timer.start().unwrap();
+    assert_eq!(timer.toggle_pause(), Ok(Event::Paused));
+    assert_eq!(timer.current_phase(), TimerPhase::Paused);
+
+    assert_eq!(timer.toggle_pause(), Ok(Event::Resumed));
+    assert_eq!(timer.current_phase(), TimerPhase::Running);
+
+    timer.split().unwrap();
+    timer.split().unwrap();
+    timer.split().unwrap();
+    assert_eq!(timer.toggle_pause(), Err(Error::RunFinished));
+    assert_eq!(timer.current_phase(), TimerPhase::Ended);
+}
+
+#[test]
+fn starting_increments_the_attempt_count() {
+    let mut timer = timer();
+    assert_eq!(timer.run().attempt_count(), 0);
+
+    timer.start().unwrap();
+    assert_eq!(timer.run().attempt_count(), 1);
+}
+
+#[test]
+fn starting_without_counting_keeps_the_attempt_count() {
+    let mut timer = timer();
+    timer.set_attempt_count(5);
+
+    timer.start_without_counting().unwrap();
+    assert_eq!(timer.current_phase(), TimerPhase::Running);
+    assert_eq!(timer.run().attempt_count(), 5);
+    assert_eq!(
+        timer.start_without_counting(),
+        Err(Error::RunAlreadyInProgress)
+    );
+
+    // The attempt still ends up in the history.
+    timer.split().unwrap();
+    timer.split().unwrap();
+    timer.split().unwrap();
+    timer.reset(true).unwrap();
+    assert_eq!(timer.run().attempt_count(), 5);
+    assert_eq!(timer.run().attempt_history().len(), 1);
+    assert!(
+        timer
+            .run()
+            .segment(2)
+            .personal_best_split_time()
+            .real_time
+            .is_some()
+    );
+
+    timer.start().unwrap();
+    assert_eq!(timer.run().attempt_count(), 6);
+}
+
+#[test]
+fn undo_split_or_reset_undoes_splits() {
+    let mut timer = timer();
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(5.0), Some(10.0)]);
+
+    assert_eq!(timer.undo_split_or_reset(true), Ok(Event::SplitUndone));
+    assert_eq!(timer.current_phase(), TimerPhase::Running);
+    assert_eq!(timer.current_split_index(), Some(1));
+    assert_eq!(timer.run().segment(1).split_time().game_time, None);
+    assert!(timer.run().segment(0).split_time().game_time.is_some());
+}
+
+#[test]
+fn undo_split_or_reset_resets_on_the_first_split() {
+    let mut timer = timer();
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(5.0)]);
+
+    assert_eq!(timer.undo_split_or_reset(true), Ok(Event::SplitUndone));
+    assert_eq!(timer.current_split_index(), Some(0));
+
+    assert_eq!(timer.undo_split_or_reset(true), Ok(Event::Reset));
+    assert_eq!(timer.current_phase(), TimerPhase::NotRunning);
+    assert_eq!(timer.current_split_index(), None);
+}
+
+#[test]
+fn undo_split_or_reset_does_nothing_when_not_running() {
+    let mut timer = timer();
+    let attempt_count = timer.run().attempt_count();
+
+    assert_eq!(timer.undo_split_or_reset(true), Err(Error::NoRunInProgress));
+    assert_eq!(timer.current_phase(), TimerPhase::NotRunning);
+    assert_eq!(timer.run().attempt_count(), attempt_count);
+    assert!(timer.run().attempt_history().is_empty());
+}
+
 #[test]
 fn paused_then_resumed_game_time_lags_behind_real_time() {
     let mut timer = timer();
@@ -689,3 +795,768 @@ fn paused_then_resumed_game_time_lags_behind_real_time() {
         .current_time(timer.run());
     assert!(time.game_time.unwrap() < time.real_time);
 }
+
+#[test]
+fn pausing_uninitialized_game_time_initializes_it() {
+    let mut timer = timer();
+
+    timer.start().unwrap();
+    assert!(!timer.is_game_time_initialized());
+
+    timer.pause_game_time().unwrap();
+    assert!(timer.is_game_time_initialized());
+    assert!(timer.is_game_time_paused());
+    assert_eq!(timer.loading_times(), TimeSpan::zero());
+
+    let time = timer.snapshot().current_time();
+    let game_time = time.game_time.unwrap();
+    assert!(game_time <= time.real_time.unwrap());
+
+    // The Game Time stays frozen while Real Time keeps moving.
+    timer.pause().unwrap();
+    let time = timer.snapshot().current_time();
+    assert_eq!(time.game_time, Some(game_time));
+
+    timer.resume().unwrap();
+    timer.resume_game_time().unwrap();
+    assert!(timer.is_game_time_initialized());
+    assert!(!timer.is_game_time_paused());
+}
+
+#[test]
+fn splitting_after_finishing_leaves_the_attempt_untouched() {
+    let mut timer = timer();
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(5.0), Some(10.0), Some(15.0)]);
+
+    assert_eq!(timer.current_phase(), TimerPhase::Ended);
+    let split_index = timer.current_split_index();
+    let split_times = timer
+        .run()
+        .segments()
+        .iter()
+        .map(|s| s.split_time())
+        .collect::<Vec<_>>();
+
+    timer.set_game_time(TimeSpan::from_seconds(20.0)).unwrap();
+    assert!(timer.split().is_err());
+
+    assert_eq!(timer.current_phase(), TimerPhase::Ended);
+    assert_eq!(timer.current_split_index(), split_index);
+    assert!(
+        timer
+            .run()
+            .segments()
+            .iter()
+            .map(|s| s.split_time())
+            .eq(split_times)
+    );
+}
+
+#[test]
+fn best_possible_time_adds_remaining_best_segments() {
+    let mut timer = timer();
+    run_with_splits(&mut timer, &[10.0, 25.0, 35.0]);
+    run_with_splits(&mut timer, &[12.0, 20.0, 32.0]);
+    // Best segments are now 10, 8 and 10.
+
+    assert_eq!(timer.best_possible_time(TimingMethod::GameTime), None);
+
+    start_run(&mut timer);
+    assert_eq!(
+        timer.best_possible_time(TimingMethod::GameTime),
+        Some(TimeSpan::from_seconds(28.0)),
+    );
+
+    make_progress_run_with_splits_opt(&mut timer, &[Some(11.0)]);
+    assert_eq!(
+        timer.best_possible_time(TimingMethod::GameTime),
+        Some(TimeSpan::from_seconds(11.0 + 8.0 + 10.0)),
+    );
+
+    // Taking longer than the best segment on the current segment pushes the
+    // best possible time back.
+    timer.set_game_time(TimeSpan::from_seconds(23.0)).unwrap();
+    assert_eq!(
+        timer.best_possible_time(TimingMethod::GameTime),
+        Some(TimeSpan::from_seconds(23.0 + 10.0)),
+    );
+
+    make_progress_run_with_splits_opt(&mut timer, &[Some(23.0)]);
+    assert_eq!(
+        timer.best_possible_time(TimingMethod::GameTime),
+        Some(TimeSpan::from_seconds(23.0 + 10.0)),
+    );
+
+    make_progress_run_with_splits_opt(&mut timer, &[Some(34.0)]);
+    assert_eq!(
+        timer.best_possible_time(TimingMethod::GameTime),
+        Some(TimeSpan::from_seconds(34.0)),
+    );
+}
+
+#[test]
+fn best_possible_time_requires_best_segments() {
+    let mut timer = timer();
+    run_with_splits_opt(&mut timer, &[Some(10.0), None, Some(30.0)]);
+    // The second segment has no best segment time on its own.
+
+    start_run(&mut timer);
+    assert_eq!(timer.best_possible_time(TimingMethod::GameTime), None);
+
+    // Once the segment without a best segment time is skipped, the combined
+    // best segment of the skipped segment and the next one is still unknown.
+    make_progress_run_with_splits_opt(&mut timer, &[Some(10.0), None]);
+    assert_eq!(timer.best_possible_time(TimingMethod::GameTime), None);
+}
+
+#[test]
+fn stays_on_best_possible_pace_when_only_completing_golds() {
+    let mut timer = timer();
+    run_with_splits(&mut timer, &[10.0, 25.0, 35.0]);
+    // Best segments are now 10, 15 and 10.
+
+    assert_eq!(timer.is_on_best_possible_pace(TimingMethod::GameTime), None);
+
+    start_run(&mut timer);
+    assert_eq!(
+        timer.is_on_best_possible_pace(TimingMethod::GameTime),
+        Some(true)
+    );
+
+    make_progress_run_with_splits_opt(&mut timer, &[Some(9.0)]);
+    assert_eq!(
+        timer.is_on_best_possible_pace(TimingMethod::GameTime),
+        Some(true)
+    );
+
+    make_progress_run_with_splits_opt(&mut timer, &[Some(20.0)]);
+    assert_eq!(
+        timer.is_on_best_possible_pace(TimingMethod::GameTime),
+        Some(true)
+    );
+
+    make_progress_run_with_splits_opt(&mut timer, &[Some(29.0)]);
+    assert_eq!(timer.current_phase(), TimerPhase::Ended);
+    assert_eq!(
+        timer.is_on_best_possible_pace(TimingMethod::GameTime),
+        Some(true)
+    );
+}
+
+#[test]
+fn current_comparison_segment_time() {
+    let mut timer = timer();
+    run_with_splits(&mut timer, &[10.0, 25.0, 35.0]);
+
+    assert_eq!(
+        timer
+            .snapshot()
+            .current_comparison_segment_time(TimingMethod::GameTime),
+        None
+    );
+
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(9.0)]);
+    timer.set_game_time(TimeSpan::from_seconds(12.0)).unwrap();
+
+    let run = timer.run();
+    let expected = run.segment(1).personal_best_split_time().game_time.unwrap()
+        - run.segment(0).personal_best_split_time().game_time.unwrap();
+    assert_eq!(expected, TimeSpan::from_seconds(15.0));
+    assert_eq!(
+        timer
+            .snapshot()
+            .current_comparison_segment_time(TimingMethod::GameTime),
+        Some(expected)
+    );
+
+    make_progress_run_with_splits_opt(&mut timer, &[Some(20.0), Some(29.0)]);
+    assert_eq!(timer.current_phase(), TimerPhase::Ended);
+    assert_eq!(
+        timer
+            .snapshot()
+            .current_comparison_segment_time(TimingMethod::GameTime),
+        None
+    );
+}
+
+#[test]
+fn comparison_segment_time() {
+    let mut timer = timer();
+    run_with_splits(&mut timer, &[10.0, 25.0, 35.0]);
+    run_with_splits(&mut timer, &[12.0, 24.0, 36.0]);
+
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(9.0)]);
+
+    let snapshot = timer.snapshot();
+    assert_eq!(
+        snapshot.comparison_segment_time(best_segments::NAME, TimingMethod::GameTime),
+        Some(TimeSpan::from_seconds(12.0))
+    );
+    assert_eq!(
+        snapshot.comparison_segment_time(personal_best::NAME, TimingMethod::GameTime),
+        snapshot.current_comparison_segment_time(TimingMethod::GameTime)
+    );
+}
+
+#[test]
+fn apply_scripted_run() {
+    let mut timer = timer();
+    timer.set_split_confirmation_window(Some(TimeSpan::from_seconds(5.0)));
+
+    assert_eq!(
+        timer.apply_scripted_run(&[Some(span(10.0)), None, None]),
+        Err(Error::CantSkipLastSplit)
+    );
+    assert_eq!(
+        timer.apply_scripted_run(&[Some(span(10.0)), None, Some(span(30.0)), None]),
+        Err(Error::SplitCountMismatch)
+    );
+    assert_eq!(timer.current_phase(), TimerPhase::NotRunning);
+
+    assert_eq!(
+        timer.apply_scripted_run(&[Some(span(10.0)), None, Some(span(30.0))]),
+        Ok(Event::Finished)
+    );
+    assert_eq!(timer.current_phase(), TimerPhase::Ended);
+    assert_eq!(
+        timer.split_confirmation_window(),
+        Some(TimeSpan::from_seconds(5.0))
+    );
+
+    let split_times: Vec<_> = timer
+        .run()
+        .segments()
+        .iter()
+        .map(|s| s.split_time().game_time)
+        .collect();
+    assert_eq!(split_times, [Some(span(10.0)), None, Some(span(30.0))]);
+
+    assert_eq!(
+        timer.apply_scripted_run(&[Some(span(10.0))]),
+        Err(Error::RunAlreadyInProgress)
+    );
+    timer.reset(false).unwrap();
+
+    assert_eq!(
+        timer.apply_scripted_run(&[Some(span(10.0))]),
+        Ok(Event::Splitted)
+    );
+    assert_eq!(timer.current_split_index(), Some(1));
+}
+
+#[test]
+fn finished_with_golds_time() {
+    let mut timer = timer();
+    let method = TimingMethod::GameTime;
+    run_with_splits(&mut timer, &[10.0, 25.0, 35.0]);
+
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(12.0), Some(30.0)]);
+    assert_eq!(timer.finished_with_golds_time(method), None);
+
+    make_progress_run_with_splits_opt(&mut timer, &[Some(42.0)]);
+    assert_eq!(timer.current_phase(), TimerPhase::Ended);
+
+    let sum_of_golds = timer
+        .run()
+        .segments()
+        .iter()
+        .fold(TimeSpan::zero(), |sum, s| {
+            sum + s.best_segment_time()[method].unwrap()
+        });
+    assert_eq!(sum_of_golds, TimeSpan::from_seconds(35.0));
+    assert_eq!(timer.finished_with_golds_time(method), Some(sum_of_golds));
+}
+
+#[test]
+fn comparison_final_time() {
+    let mut timer = timer();
+    let method = TimingMethod::GameTime;
+
+    assert_eq!(
+        timer
+            .snapshot()
+            .comparison_final_time(personal_best::NAME, method),
+        None
+    );
+
+    run_with_splits(&mut timer, &[10.0, 25.0, 35.0]);
+
+    let snapshot = timer.snapshot();
+    let pb_final_time = timer
+        .run()
+        .segments()
+        .last()
+        .unwrap()
+        .personal_best_split_time()[method];
+    assert_eq!(pb_final_time, Some(TimeSpan::from_seconds(35.0)));
+    assert_eq!(
+        snapshot.comparison_final_time(personal_best::NAME, method),
+        pb_final_time
+    );
+    assert_eq!(snapshot.comparison_final_time("Unknown", method), None);
+}
+
+#[test]
+fn current_segment_time() {
+    let mut timer = timer();
+    let method = TimingMethod::GameTime;
+
+    assert_eq!(timer.snapshot().current_segment_time(method), None);
+
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(9.0)]);
+    timer.set_game_time(TimeSpan::from_seconds(12.0)).unwrap();
+
+    let snapshot = timer.snapshot();
+    let first_split = timer.run().segment(0).split_time()[method].unwrap();
+    let total = snapshot.current_time()[method].unwrap();
+    let segment_time = snapshot.current_segment_time(method).unwrap();
+    assert!(segment_time < total);
+    assert_eq!(segment_time, total - first_split);
+    assert_eq!(segment_time, TimeSpan::from_seconds(3.0));
+
+    make_progress_run_with_splits_opt(&mut timer, &[Some(20.0), Some(29.0)]);
+    assert_eq!(timer.current_phase(), TimerPhase::Ended);
+    assert_eq!(timer.snapshot().current_segment_time(method), None);
+}
+
+#[test]
+fn live_delta() {
+    let mut timer = timer();
+    run_with_splits(&mut timer, &[10.0, 25.0, 35.0]);
+
+    let method = TimingMethod::GameTime;
+    assert_eq!(
+        timer.snapshot().live_delta(personal_best::NAME, method),
+        None
+    );
+
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(9.0)]);
+
+    // The current segment isn't losing time yet, so the delta of the last
+    // split is used.
+    timer.set_game_time(TimeSpan::from_seconds(12.0)).unwrap();
+    assert_eq!(
+        timer.snapshot().live_delta(personal_best::NAME, method),
+        Some((
+            TimeSpan::from_seconds(-1.0),
+            SemanticColor::AheadGainingTime
+        )),
+    );
+
+    // Now the current segment is 2 seconds behind the Personal Best.
+    timer.set_game_time(TimeSpan::from_seconds(27.0)).unwrap();
+    assert_eq!(
+        timer.snapshot().live_delta(personal_best::NAME, method),
+        Some((TimeSpan::from_seconds(2.0), SemanticColor::BehindLosingTime)),
+    );
+}
+
+#[test]
+fn finished_summary() {
+    let mut timer = timer();
+    run_with_splits(&mut timer, &[10.0, 25.0, 35.0]);
+
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(9.0), Some(27.0)]);
+    assert_eq!(timer.finished_summary(TimingMethod::GameTime), None);
+
+    make_progress_run_with_splits_opt(&mut timer, &[Some(34.0)]);
+    assert_eq!(timer.current_phase(), TimerPhase::Ended);
+
+    // The segments took 9, 18 and 7 seconds, while the Personal Best's
+    // segments took 10, 15 and 10 seconds.
+    let summary = timer.finished_summary(TimingMethod::GameTime).unwrap();
+    assert_eq!(summary.final_time, TimeSpan::from_seconds(34.0));
+    assert_eq!(
+        summary.personal_best_delta,
+        Some(TimeSpan::from_seconds(-1.0))
+    );
+    assert_eq!(summary.gold_count, 2);
+    assert_eq!(
+        summary.biggest_time_loss,
+        Some((1, TimeSpan::from_seconds(3.0)))
+    );
+    assert!(summary.is_personal_best);
+
+    timer.reset(true).unwrap();
+    assert_eq!(timer.finished_summary(TimingMethod::GameTime), None);
+}
+
+#[test]
+fn falls_off_best_possible_pace_after_a_non_gold_segment() {
+    let mut timer = timer();
+    run_with_splits(&mut timer, &[10.0, 25.0, 35.0]);
+
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(9.0)]);
+    assert_eq!(
+        timer.is_on_best_possible_pace(TimingMethod::GameTime),
+        Some(true)
+    );
+
+    // 16 seconds is slower than the best segment of 15 seconds.
+    make_progress_run_with_splits_opt(&mut timer, &[Some(25.0)]);
+    assert_eq!(
+        timer.is_on_best_possible_pace(TimingMethod::GameTime),
+        Some(false)
+    );
+
+    // A gold afterwards doesn't bring the attempt back on pace.
+    make_progress_run_with_splits_opt(&mut timer, &[Some(30.0)]);
+    assert_eq!(
+        timer.is_on_best_possible_pace(TimingMethod::GameTime),
+        Some(false)
+    );
+}
+
+#[test]
+fn reports_the_game_time_state() {
+    let mut timer = timer();
+    assert!(!timer.is_game_time_initialized());
+    assert!(!timer.is_game_time_paused());
+
+    timer.start().unwrap();
+    assert!(!timer.is_game_time_initialized());
+    assert!(!timer.is_game_time_paused());
+
+    timer.initialize_game_time().unwrap();
+    assert!(timer.is_game_time_initialized());
+    assert!(!timer.is_game_time_paused());
+
+    timer.pause_game_time().unwrap();
+    assert!(timer.is_game_time_initialized());
+    assert!(timer.is_game_time_paused());
+
+    timer.resume_game_time().unwrap();
+    assert!(timer.is_game_time_initialized());
+    assert!(!timer.is_game_time_paused());
+
+    timer.pause_game_time().unwrap();
+    timer.deinitialize_game_time();
+    assert!(!timer.is_game_time_initialized());
+    assert!(timer.is_game_time_paused());
+
+    timer.reset(false).unwrap();
+    assert!(!timer.is_game_time_initialized());
+    assert!(!timer.is_game_time_paused());
+}
+
+#[test]
+fn current_time_is_exact() {
+    let mut timer = timer();
+    timer.start().unwrap();
+    timer.initialize_game_time().unwrap();
+    timer.pause_game_time().unwrap();
+
+    let game_time = TimeSpan::from(crate::platform::Duration::new(1, 234_567_891));
+    timer.set_game_time(game_time).unwrap();
+
+    let time = timer.snapshot().current_time()[TimingMethod::GameTime].unwrap();
+    assert_eq!(time, game_time);
+    assert_eq!(time.to_seconds_and_subsec_nanoseconds(), (1, 234_567_891));
+}
+
+#[test]
+fn split_confirmation_window_rejects_double_splits() {
+    let mut timer = timer();
+    timer.set_split_confirmation_window(Some(TimeSpan::from_seconds(60.0)));
+
+    timer.start().unwrap();
+    assert_eq!(timer.split(), Ok(Event::Splitted));
+    assert_eq!(timer.split(), Err(Error::SplitWithinConfirmationWindow));
+    assert_eq!(timer.current_split_index(), Some(1));
+
+    timer.skip_split().unwrap();
+    assert_eq!(timer.split(), Ok(Event::Finished));
+    assert_eq!(timer.current_phase(), TimerPhase::Ended);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn split_confirmation_window_allows_splits_outside_of_it() {
+    let mut timer = timer();
+    timer.set_split_confirmation_window(Some(TimeSpan::from_milliseconds(1.0)));
+
+    timer.start().unwrap();
+    assert_eq!(timer.split(), Ok(Event::Splitted));
+    std::thread::sleep(core::time::Duration::from_millis(20));
+    assert_eq!(timer.split(), Ok(Event::Splitted));
+    assert_eq!(timer.current_split_index(), Some(2));
+}
+
+#[test]
+fn split_confirmation_window_is_off_by_default() {
+    let mut timer = timer();
+    assert_eq!(timer.split_confirmation_window(), None);
+
+    timer.start().unwrap();
+    assert_eq!(timer.split(), Ok(Event::Splitted));
+    assert_eq!(timer.split(), Ok(Event::Splitted));
+    assert_eq!(timer.current_split_index(), Some(2));
+}
+
+#[test]
+fn reconciling_after_removing_a_segment_ahead() {
+    let mut timer = Timer::new(create_run(&["A", "B", "C", "D"])).unwrap();
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(5.0), Some(10.0)]);
+
+    let mut run = timer.run().clone();
+    run.segments_mut().remove(3);
+    assert_eq!(
+        timer.reconcile_after_edit(run),
+        Ok(Event::AttemptReconciled)
+    );
+
+    assert_eq!(timer.run().len(), 3);
+    assert_eq!(timer.current_phase(), TimerPhase::Running);
+    assert_eq!(timer.current_split_index(), Some(2));
+    assert_eq!(
+        timer.run().segment(1).split_time().game_time,
+        Some(TimeSpan::from_seconds(10.0))
+    );
+    assert_eq!(timer.run().segment(2).split_time().game_time, None);
+
+    make_progress_run_with_splits_opt(&mut timer, &[Some(15.0)]);
+    assert_eq!(timer.current_phase(), TimerPhase::Ended);
+}
+
+#[test]
+fn reconciling_clamps_the_current_split() {
+    let mut timer = Timer::new(create_run(&["A", "B", "C", "D"])).unwrap();
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(5.0), Some(10.0)]);
+
+    let mut run = timer.run().clone();
+    run.segments_mut().truncate(2);
+    timer.reconcile_after_edit(run).unwrap();
+
+    // The attempt continues on the last remaining segment, so its split time
+    // is dropped.
+    assert_eq!(timer.current_phase(), TimerPhase::Running);
+    assert_eq!(timer.current_split_index(), Some(1));
+    assert_eq!(
+        timer.run().segment(0).split_time().game_time,
+        Some(TimeSpan::from_seconds(5.0))
+    );
+    assert_eq!(timer.run().segment(1).split_time().game_time, None);
+}
+
+#[test]
+fn reconciling_a_finished_attempt() {
+    let mut timer = Timer::new(create_run(&["A", "B"])).unwrap();
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(5.0), Some(10.0)]);
+    assert_eq!(timer.current_phase(), TimerPhase::Ended);
+
+    let mut run = timer.run().clone();
+    run.segments_mut().remove(0);
+    timer.reconcile_after_edit(run).unwrap();
+    assert_eq!(timer.current_phase(), TimerPhase::Ended);
+
+    let mut run = timer.run().clone();
+    run.push_segment(Segment::new("C"));
+    timer.reconcile_after_edit(run).unwrap();
+    assert_eq!(timer.current_phase(), TimerPhase::Running);
+    assert_eq!(timer.current_split_index(), Some(1));
+
+    assert!(timer.reconcile_after_edit(Run::new()).is_err());
+    assert_eq!(timer.run().len(), 2);
+}
+
+#[test]
+fn reconciling_after_removing_a_segment_before_the_current_one() {
+    let mut timer = timer();
+    run_with_splits(&mut timer, &[5.0, 12.0, 20.0]);
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(4.0), Some(8.0)]);
+
+    let mut run = timer.run().clone();
+    run.segments_mut().remove(0);
+    timer.reconcile_after_edit(run).unwrap();
+
+    assert_eq!(timer.current_phase(), TimerPhase::Running);
+    assert_eq!(timer.current_split_index(), Some(1));
+    assert_eq!(
+        timer.run().segment(0).split_time().game_time,
+        Some(span(8.0))
+    );
+
+    let best_at_start = |index| {
+        timer
+            .snapshot()
+            .best_segment_time_at_start(index, TimingMethod::GameTime)
+    };
+    assert_eq!(best_at_start(0), Some(span(7.0)));
+    assert_eq!(best_at_start(1), Some(span(8.0)));
+
+    make_progress_run_with_splits_opt(&mut timer, &[Some(15.0)]);
+    assert_eq!(timer.current_phase(), TimerPhase::Ended);
+}
+
+#[test]
+fn reconciling_keeps_skipped_segments_skipped() {
+    let mut timer = timer();
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(5.0), None]);
+
+    let mut run = timer.run().clone();
+    run.segments_mut().insert(0, Segment::new("Intro"));
+    timer.reconcile_after_edit(run).unwrap();
+
+    assert_eq!(timer.current_split_index(), Some(3));
+    assert_eq!(
+        timer.run().segment(1).split_time().game_time,
+        Some(span(5.0))
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn real_time_advances_while_game_time_is_paused() {
+    let mut timer = timer();
+    start_run(&mut timer);
+    timer.set_game_time(TimeSpan::from_seconds(3.0)).unwrap();
+
+    let (first_real_time, first_game_time) = timer.snapshot().current_time_both();
+    std::thread::sleep(core::time::Duration::from_millis(20));
+    let (second_real_time, second_game_time) = timer.snapshot().current_time_both();
+
+    assert!(second_real_time.unwrap() > first_real_time.unwrap());
+    assert_eq!(first_game_time, Some(TimeSpan::from_seconds(3.0)));
+    assert_eq!(second_game_time, first_game_time);
+
+    let snapshot = timer.snapshot();
+    let time = snapshot.current_time();
+    assert_eq!(
+        snapshot.current_time_both(),
+        (time.real_time, time.game_time)
+    );
+}
+
+#[test]
+fn set_run_splits_writes_an_entire_attempt() {
+    let mut timer = timer();
+    let time = |real, game| {
+        Time::new()
+            .with_real_time(Some(TimeSpan::from_seconds(real)))
+            .with_game_time(Some(TimeSpan::from_seconds(game)))
+    };
+
+    assert_eq!(
+        timer.set_run_splits(&[Some(time(5.0, 4.0)), Some(time(10.0, 8.0))]),
+        Err(Error::SplitCountMismatch)
+    );
+    assert_eq!(
+        timer.set_run_splits(&[Some(time(5.0, 4.0)), Some(time(10.0, 8.0)), None]),
+        Err(Error::CantSkipLastSplit)
+    );
+    assert_eq!(
+        timer.set_run_splits(&[
+            Some(time(5.0, 4.0)),
+            Some(time(10.0, 3.0)),
+            Some(time(15.0, 12.0))
+        ]),
+        Err(Error::SplitTimesNotIncreasing)
+    );
+    assert_eq!(
+        timer.set_run_splits(&[Some(time(5.0, 4.0)), None, Some(time(3.0, 12.0))]),
+        Err(Error::SplitTimesNotIncreasing)
+    );
+    assert_eq!(timer.current_phase(), TimerPhase::NotRunning);
+
+    let times = [Some(time(5.0, 4.0)), None, Some(time(15.0, 12.0))];
+    assert_eq!(timer.set_run_splits(&times), Ok(Event::Finished));
+    assert_eq!(timer.current_phase(), TimerPhase::Ended);
+    assert_eq!(timer.run().attempt_count(), 1);
+
+    let split_times: Vec<_> = timer
+        .run()
+        .segments()
+        .iter()
+        .map(|s| s.split_time())
+        .collect();
+    assert_eq!(
+        split_times,
+        [time(5.0, 4.0), Time::default(), time(15.0, 12.0)]
+    );
+    assert_eq!(timer.snapshot().current_time(), time(15.0, 12.0));
+
+    assert_eq!(
+        timer.set_run_splits(&times),
+        Err(Error::RunAlreadyInProgress)
+    );
+
+    timer.reset(true).unwrap();
+    let attempt = &timer.run().attempt_history()[0];
+    assert_eq!(attempt.time(), time(15.0, 12.0));
+}
+
+#[test]
+fn best_segment_time_at_start_stays_the_same_for_the_attempt() {
+    let mut timer = timer();
+    run_with_splits(&mut timer, &[5.0, 10.0, 15.0]);
+
+    let best_at_start = |timer: &Timer| {
+        timer
+            .snapshot()
+            .best_segment_time_at_start(1, TimingMethod::GameTime)
+    };
+
+    assert_eq!(best_at_start(&timer), Some(TimeSpan::from_seconds(5.0)));
+
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(6.0)]);
+
+    // A new gold gets set mid-run by editing the run.
+    let mut run = timer.run().clone();
+    run.segment_mut(1).best_segment_time_mut().game_time = Some(TimeSpan::from_seconds(1.0));
+    timer.reconcile_after_edit(run).unwrap();
+
+    assert_eq!(
+        timer.run().segment(1).best_segment_time().game_time,
+        Some(TimeSpan::from_seconds(1.0))
+    );
+    assert_eq!(best_at_start(&timer), Some(TimeSpan::from_seconds(5.0)));
+    assert_eq!(
+        timer
+            .snapshot()
+            .best_segment_time_at_start(3, TimingMethod::GameTime),
+        None
+    );
+
+    timer.reset(true).unwrap();
+    assert_eq!(best_at_start(&timer), Some(TimeSpan::from_seconds(1.0)));
+
+    start_run(&mut timer);
+    assert_eq!(best_at_start(&timer), Some(TimeSpan::from_seconds(1.0)));
+}
+
+#[test]
+fn custom_split_times_are_live_only() {
+    let mut timer = timer();
+    timer.register_custom_timing_method("Frames");
+
+    timer.start().unwrap();
+    timer.set_custom_time("Frames", span(60.0)).unwrap();
+    timer.split().unwrap();
+    assert_eq!(
+        timer.run().segment(0).custom_split_time("Frames"),
+        Some(span(60.0))
+    );
+
+    let mut saved = String::new();
+    crate::run::saver::livesplit::save_timer(&timer, &mut saved).unwrap();
+    assert!(!saved.contains("Frames"));
+
+    timer.reset(true).unwrap();
+    assert_eq!(timer.run().segment(0).custom_split_time("Frames"), None);
+    assert!(timer.run().segment(0).custom_split_times().is_empty());
+    assert_eq!(timer.custom_time("Frames"), None);
+}
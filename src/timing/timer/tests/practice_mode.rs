@@ -0,0 +1,76 @@
+use super::timer;
+use crate::{
+    TimeSpan, TimingMethod,
+    util::tests_helper::{run_with_splits, start_run},
+};
+
+#[test]
+fn leaves_the_run_untouched() {
+    let mut timer = timer();
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0]);
+    timer.mark_as_unmodified();
+    let before = timer.run().clone();
+
+    timer.set_practice_mode(true);
+    assert!(timer.is_in_practice_mode());
+
+    // This attempt is faster in every segment, so it would update both the
+    // best segments and the Personal Best.
+    start_run(&mut timer);
+    for split in [5.0, 10.0, 15.0] {
+        timer.set_game_time(TimeSpan::from_seconds(split)).unwrap();
+        timer.split().unwrap();
+    }
+    assert_eq!(
+        timer.snapshot().current_time().game_time,
+        Some(TimeSpan::from_seconds(15.0)),
+    );
+    timer.reset(true).unwrap();
+
+    let run = timer.run();
+    assert!(!run.has_been_modified());
+    assert_eq!(run.attempt_count(), before.attempt_count());
+    assert_eq!(run.attempt_history(), before.attempt_history());
+    for (segment, old) in run.segments().iter().zip(before.segments()) {
+        assert_eq!(
+            segment.personal_best_split_time(),
+            old.personal_best_split_time()
+        );
+        assert_eq!(segment.best_segment_time(), old.best_segment_time());
+        assert_eq!(segment.segment_history(), old.segment_history());
+    }
+}
+
+#[test]
+fn doesnt_set_the_attempt_as_pb() {
+    let mut timer = timer();
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0]);
+
+    timer.set_practice_mode(true);
+    start_run(&mut timer);
+    timer.set_game_time(TimeSpan::from_seconds(40.0)).unwrap();
+    timer.split().unwrap();
+    timer.reset_and_set_attempt_as_pb().unwrap();
+
+    let run = timer.run();
+    assert_eq!(run.attempt_count(), 1);
+    assert_eq!(
+        run.segment(0).personal_best_split_time()[TimingMethod::GameTime],
+        Some(TimeSpan::from_seconds(10.0)),
+    );
+}
+
+#[test]
+fn history_is_updated_again_after_leaving_it() {
+    let mut timer = timer();
+
+    timer.set_practice_mode(true);
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0]);
+    assert_eq!(timer.run().attempt_count(), 0);
+    assert!(timer.run().attempt_history().is_empty());
+
+    timer.set_practice_mode(false);
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0]);
+    assert_eq!(timer.run().attempt_count(), 1);
+    assert_eq!(timer.run().attempt_history().len(), 1);
+}
@@ -2,7 +2,7 @@
 //! based on the order the pairs were inserted into the map.
 
 use crate::{platform::prelude::*, util::PopulateString};
-use core::{fmt, marker::PhantomData};
+use core::{fmt, marker::PhantomData, mem};
 use serde::{
     Deserialize, Deserializer, Serialize, Serializer,
     de::{MapAccess, Visitor},
@@ -66,12 +66,42 @@ impl<V> Map<V> {
         Iter(self.0.iter())
     }
 
+    /// Trims the whitespace around the keys and merges the key-value pairs
+    /// whose keys only differ in their capitalization. The pairs get merged
+    /// into the position of the first of them, keeping the key of the most
+    /// recent one. The merge function is called with the merged value and the
+    /// more recent value.
+    pub fn canonicalize_keys(&mut self, mut merge: impl FnMut(&mut V, V)) {
+        for (key, value) in mem::take(&mut self.0) {
+            let key = if key.trim().len() == key.len() {
+                key
+            } else {
+                key.trim().into()
+            };
+
+            if let Some((existing_key, existing_value)) =
+                self.0.iter_mut().find(|(k, _)| eq_ignore_case(k, &key))
+            {
+                *existing_key = key;
+                merge(existing_value, value);
+            } else {
+                self.0.push((key, value));
+            }
+        }
+    }
+
     /// Remove all key-value pairs in the [`Map`], while preserving its capacity.
     pub fn clear(&mut self) {
         self.0.clear();
     }
 }
 
+fn eq_ignore_case(a: &str, b: &str) -> bool {
+    a.chars()
+        .flat_map(char::to_lowercase)
+        .eq(b.chars().flat_map(char::to_lowercase))
+}
+
 /// Entry for an existing key-value pair or a vacant location to insert one.
 pub struct Entry<'a, K, V> {
     map: &'a mut Map<V>,
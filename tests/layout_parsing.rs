@@ -45,4 +45,34 @@ mod parse {
         assert_eq!(columns[0].name, "Time");
         assert_eq!(columns[1].name, "+/−");
     }
+
+    #[test]
+    fn thin_separators_of_older_layouts() {
+        use livesplit_core::{
+            component::splits::SeparatorMode,
+            layout::{Component, LayoutSettings},
+        };
+
+        for (json, separator_mode) in [
+            (
+                r#"{"components":[{"Splits":{"show_thin_separators":false}}],"general":{}}"#,
+                SeparatorMode::None,
+            ),
+            (
+                r#"{"components":[{"Splits":{"show_thin_separators":true}}],"general":{}}"#,
+                SeparatorMode::All,
+            ),
+            (
+                r#"{"components":[{"Splits":{"separator_mode":"SectionBoundaries"}}],"general":{}}"#,
+                SeparatorMode::SectionBoundaries,
+            ),
+        ] {
+            let settings: LayoutSettings = serde_json::from_str(json).unwrap();
+            let layout = Layout::from_settings(settings);
+            let Component::Splits(component) = &layout.components[0] else {
+                panic!("The component is not a splits component");
+            };
+            assert_eq!(component.settings().separator_mode, separator_mode);
+        }
+    }
 }
@@ -14,7 +14,7 @@ use livesplit_core::{
     layout::{self, Component, ComponentState, Layout, LayoutDirection, LayoutState},
     rendering,
     run::parser::{livesplit, llanfair, wsplit},
-    settings::ImageCache,
+    settings::{Color, Gradient, GradientColorSpace, ImageCache, LayoutBackground},
 };
 use std::{fs, path::PathBuf};
 
@@ -480,6 +480,492 @@ fn text_shadow() {
     );
 }
 
+#[test]
+fn letter_spacing() {
+    let mut run = tests_helper::create_run(&["A", "B", "C", "D"]);
+    run.set_game_name("Some Game Name");
+    run.set_category_name("Some Category Name");
+    run.set_attempt_count(1337);
+    let mut timer = Timer::new(run).unwrap();
+    let mut layout = Layout::default_layout();
+
+    tests_helper::start_run(&mut timer);
+    tests_helper::make_progress_run_with_splits_opt(&mut timer, &[Some(5.0), None, Some(10.0)]);
+
+    let mut image_cache = ImageCache::new();
+
+    layout.general_settings_mut().letter_spacing = 0.0;
+    let state = layout.state(&mut image_cache, &timer.snapshot());
+
+    // A letter spacing of 0 needs to match the default layout exactly.
+    check(
+        &state,
+        &image_cache,
+        "613e94c31d4d76c3",
+        "c2ae6252eec1d1b5",
+        "letter_spacing_zero",
+    );
+
+    #[cfg(feature = "software-rendering")]
+    for letter_spacing in [0.1, -0.05] {
+        layout.general_settings_mut().letter_spacing = letter_spacing;
+        let state = layout.state(&mut image_cache, &timer.snapshot());
+
+        let mut renderer = rendering::software::Renderer::new();
+        renderer.render(&state, &image_cache, [300, 500]);
+        let calculated_hash = format!("{:016x}", seahash::hash(&renderer.image()));
+
+        assert_ne!(calculated_hash, "613e94c31d4d76c3");
+    }
+}
+
+#[cfg(feature = "software-rendering")]
+#[test]
+fn rotation() {
+    use rendering::software::{Renderer, Rotation};
+
+    let run = tests_helper::create_run(&["A", "B", "C", "D"]);
+    let mut timer = Timer::new(run).unwrap();
+    let mut layout = Layout::default_layout();
+
+    tests_helper::start_run(&mut timer);
+    tests_helper::make_progress_run_with_splits_opt(&mut timer, &[Some(5.0), None, Some(10.0)]);
+
+    let mut image_cache = ImageCache::new();
+    let state = layout.state(&mut image_cache, &timer.snapshot());
+
+    let (width, height) = (300, 500);
+
+    let mut renderer = Renderer::new();
+    renderer.render(&state, &image_cache, [width, height]);
+    let unrotated = renderer.into_image();
+
+    let mut renderer = Renderer::new();
+    renderer.set_rotation(Rotation::Cw90);
+    renderer.render(&state, &image_cache, [width, height]);
+    let rotated = renderer.into_image();
+
+    assert_eq!(rotated.dimensions(), (height, width));
+
+    for y in 0..height {
+        for x in 0..width {
+            assert_eq!(
+                unrotated.get_pixel(x, y),
+                rotated.get_pixel(height - 1 - y, x),
+            );
+        }
+    }
+}
+
+#[cfg(feature = "software-rendering")]
+#[test]
+fn changing_the_rotation_rotates_the_rendered_image() {
+    use rendering::software::{Renderer, Rotation};
+
+    let run = tests_helper::create_run(&["A", "B", "C", "D"]);
+    let timer = Timer::new(run).unwrap();
+    let mut layout = Layout::default_layout();
+    let mut image_cache = ImageCache::new();
+    let state = layout.state(&mut image_cache, &timer.snapshot());
+
+    let mut expected = Renderer::new();
+    expected.set_rotation(Rotation::Ccw90);
+    expected.render(&state, &image_cache, [300, 500]);
+
+    let mut renderer = Renderer::new();
+    renderer.render(&state, &image_cache, [300, 500]);
+    let unrotated = renderer.image_data().to_vec();
+
+    renderer.set_rotation(Rotation::Ccw90);
+    assert_eq!(renderer.image().dimensions(), (500, 300));
+    assert!(renderer.image_data() == expected.image_data());
+
+    renderer.set_rotation(Rotation::None);
+    assert!(renderer.image_data() == unrotated);
+}
+
+#[cfg(feature = "software-rendering")]
+#[test]
+fn transparency_checkerboard() {
+    use livesplit_core::settings::{Gradient, LayoutBackground};
+
+    let timer = Timer::new(tests_helper::create_run(&["A"])).unwrap();
+    let mut layout = Layout::new();
+    layout.push(component::blank_space::Component::new());
+    layout.general_settings_mut().background = LayoutBackground::Gradient(Gradient::Transparent);
+
+    let mut image_cache = ImageCache::new();
+    let state = layout.state(&mut image_cache, &timer.snapshot());
+
+    let mut renderer = rendering::software::Renderer::new();
+    renderer.render(&state, &image_cache, [64, 64]);
+    let transparent = renderer.into_image();
+    assert_eq!(transparent.get_pixel(0, 0)[3], 0);
+    assert_eq!(transparent.get_pixel(63, 63)[3], 0);
+
+    let mut renderer = rendering::software::Renderer::new();
+    renderer.set_transparency_checkerboard(true);
+    renderer.render(&state, &image_cache, [64, 64]);
+    let checkerboard = renderer.into_image();
+
+    let (top_left, bottom_right) = (checkerboard.get_pixel(0, 0), checkerboard.get_pixel(63, 63));
+    assert_ne!(top_left[3], 0);
+    assert_ne!(bottom_right[3], 0);
+    // Both corners lie on squares of the same color, while the squares next to
+    // them have the other color.
+    assert_eq!(top_left, bottom_right);
+    assert_ne!(top_left, checkerboard.get_pixel(8, 0));
+}
+
+#[cfg(feature = "software-rendering")]
+#[test]
+fn disabling_the_transparency_checkerboard_clears_it() {
+    use livesplit_core::settings::{Gradient, LayoutBackground};
+
+    let timer = Timer::new(tests_helper::create_run(&["A"])).unwrap();
+    let mut layout = Layout::new();
+    layout.push(component::blank_space::Component::new());
+    layout.general_settings_mut().background = LayoutBackground::Gradient(Gradient::Transparent);
+
+    let mut image_cache = ImageCache::new();
+    let state = layout.state(&mut image_cache, &timer.snapshot());
+
+    let mut renderer = rendering::software::Renderer::new();
+    renderer.set_transparency_checkerboard(true);
+    renderer.render(&state, &image_cache, [64, 64]);
+    assert!(renderer.image().pixels().all(|pixel| pixel[3] != 0));
+
+    renderer.set_transparency_checkerboard(false);
+    renderer.render(&state, &image_cache, [64, 64]);
+    assert!(renderer.image().pixels().all(|pixel| pixel[3] == 0));
+}
+
+#[cfg(feature = "software-rendering")]
+#[test]
+fn monospace_text_digits() {
+    let timer = Timer::new(tests_helper::create_run(&["A"])).unwrap();
+    let mut image_cache = ImageCache::new();
+
+    // Returns the rightmost column where the rendered text differs from an
+    // empty text component.
+    let mut text_end = |text: &str| {
+        let images = [String::new(), String::from(text)].map(|text| {
+            let mut layout = Layout::new();
+            let mut component = component::text::Component::new();
+            component.settings_mut().text = component::text::Text::Split(text, String::new());
+            layout.push(component);
+            layout.general_settings_mut().monospace_text_digits = true;
+
+            let state = layout.state(&mut image_cache, &timer.snapshot());
+            let mut renderer = rendering::software::Renderer::new();
+            renderer.render(&state, &image_cache, [300, 40]);
+            renderer.into_image()
+        });
+        let [empty, text] = &images;
+        (0..300)
+            .rev()
+            .find(|&x| (0..40).any(|y| empty.get_pixel(x, y) != text.get_pixel(x, y)))
+    };
+
+    // The bar after the number ends up at the same position regardless of
+    // which digits are shown.
+    let end = text_end("Deaths: 11|");
+    assert!(end.is_some());
+    for text in ["Deaths: 00|", "Deaths: 47|", "Deaths: 88|"] {
+        assert_eq!(text_end(text), end);
+    }
+}
+
+#[cfg(feature = "software-rendering")]
+#[test]
+fn hidden_segment_icons_reserve_no_space() {
+    let mut image_cache = ImageCache::new();
+
+    let mut render = |run: Run, show_icons: bool| {
+        let timer = Timer::new(run).unwrap();
+        let mut layout = Layout::default_layout();
+        for component in &mut layout.components {
+            if let Component::Splits(splits) = component {
+                splits.settings_mut().show_icons = show_icons;
+            }
+        }
+
+        let state = layout.state(&mut image_cache, &timer.snapshot());
+        let mut renderer = rendering::software::Renderer::new();
+        renderer.render(&state, &image_cache, [300, 500]);
+        renderer.into_image()
+    };
+
+    let run = lss(run_files::LIVESPLIT_1_0);
+    assert!(run.segments().iter().any(|s| !s.icon().is_empty()));
+
+    let mut run_without_icons = run.clone();
+    for segment in run_without_icons.segments_mut() {
+        segment.set_icon(Default::default());
+    }
+
+    let with_icons = render(run.clone(), true);
+    let icons_hidden = render(run, false);
+    let without_icons = render(run_without_icons, true);
+
+    assert!(with_icons != icons_hidden);
+    assert!(icons_hidden == without_icons);
+}
+
+#[cfg(feature = "software-rendering")]
+#[test]
+fn gradient_color_space() {
+    let timer = Timer::new(tests_helper::create_run(&["A"])).unwrap();
+    let mut image_cache = ImageCache::new();
+
+    let mut render = |color_space: Option<GradientColorSpace>| {
+        let mut layout = Layout::new();
+        layout.push(component::blank_space::Component::new());
+        let settings = layout.general_settings_mut();
+        settings.background = LayoutBackground::Gradient(Gradient::Vertical(
+            Color::rgba(1.0, 0.0, 0.0, 1.0),
+            Color::rgba(0.0, 0.0, 1.0, 1.0),
+        ));
+        if let Some(color_space) = color_space {
+            settings.gradient_color_space = color_space;
+        }
+
+        let state = layout.state(&mut image_cache, &timer.snapshot());
+        let mut renderer = rendering::software::Renderer::new();
+        renderer.render(&state, &image_cache, [100, 100]);
+        renderer.into_image()
+    };
+
+    let default = render(None);
+    let srgb = render(Some(GradientColorSpace::Srgb));
+    let linear = render(Some(GradientColorSpace::Linear));
+    let oklab = render(Some(GradientColorSpace::Oklab));
+
+    assert!(default == srgb);
+    assert!(linear != srgb);
+    assert!(oklab != srgb);
+    assert!(oklab != linear);
+}
+
+#[test]
+fn draw_commands_gradient_color_space() {
+    use rendering::commands::Renderer;
+
+    let timer = Timer::new(tests_helper::create_run(&["A"])).unwrap();
+    let mut image_cache = ImageCache::new();
+    let (red, blue) = ([1.0, 0.0, 0.0, 1.0], [0.0, 0.0, 1.0, 1.0]);
+
+    let mut stops = |color_space: GradientColorSpace| {
+        let mut layout = Layout::new();
+        layout.push(component::blank_space::Component::new());
+        layout.general_settings_mut().gradient_color_space = color_space;
+
+        let state = layout.state(&mut image_cache, &timer.snapshot());
+        let mut renderer = Renderer::new();
+        renderer.render(&state, &image_cache, [100.0, 100.0]);
+        assert_eq!(renderer.gradient_color_space(), color_space);
+        renderer.gradient_stops(&red, &blue).collect::<Vec<_>>()
+    };
+
+    assert_eq!(stops(GradientColorSpace::Srgb), [(0.0, red), (1.0, blue)]);
+    let linear = stops(GradientColorSpace::Linear);
+    let oklab = stops(GradientColorSpace::Oklab);
+    assert!(linear.len() > 2);
+    assert_eq!(linear.first(), Some(&(0.0, red)));
+    assert_eq!(linear.last(), Some(&(1.0, blue)));
+    assert!(oklab != linear);
+}
+
+#[cfg(feature = "software-rendering")]
+#[test]
+fn automatic_column_alignment_is_unchanged() {
+    use component::splits::ColumnAlignment;
+
+    let mut timer = Timer::new(tests_helper::create_run(&["A", "B", "C"])).unwrap();
+    tests_helper::run_with_splits(&mut timer, &[5.0, 70.0, 1000.0]);
+    let mut image_cache = ImageCache::new();
+
+    let mut render = |alignment: Option<ColumnAlignment>| {
+        let mut layout = Layout::new();
+        let mut component = component::splits::Component::new();
+        for column in &mut component.settings_mut().columns {
+            column.alignment = alignment;
+        }
+        layout.push(component);
+
+        let state = layout.state(&mut image_cache, &timer.snapshot());
+        let mut renderer = rendering::software::Renderer::new();
+        renderer.render(&state, &image_cache, [300, 200]);
+        renderer.into_image()
+    };
+
+    let automatic = render(None);
+    let end = render(Some(ColumnAlignment::End));
+    let center = render(Some(ColumnAlignment::Center));
+
+    assert!(automatic == end);
+    assert!(automatic != center);
+}
+
+#[test]
+fn draw_commands() {
+    use rendering::commands::{DrawCommand, Renderer};
+
+    let mut run = tests_helper::create_run(&["A", "B", "C", "D"]);
+    run.set_game_name("Some Game Name");
+    run.set_category_name("Some Category Name");
+    let mut timer = Timer::new(run).unwrap();
+    let mut layout = Layout::default_layout();
+
+    tests_helper::start_run(&mut timer);
+    tests_helper::make_progress_run_with_splits_opt(&mut timer, &[Some(5.0), None, Some(10.0)]);
+
+    let mut image_cache = ImageCache::new();
+    let state = layout.state(&mut image_cache, &timer.snapshot());
+
+    let commands = Renderer::new().render(&state, &image_cache, [300.0, 500.0]);
+
+    assert!(matches!(commands.first(), Some(DrawCommand::Background(_))));
+
+    let mut paths = 0;
+    let mut texts = 0;
+    for command in &commands[1..] {
+        match command {
+            DrawCommand::FillPath { path, .. } | DrawCommand::StrokePath { path, .. } => {
+                assert!(!path.is_empty());
+                paths += 1;
+            }
+            DrawCommand::Text { glyphs, .. } => {
+                for glyph in glyphs {
+                    assert!(glyph.transform.scale_x > 0.0 && glyph.transform.scale_y > 0.0);
+                    assert!((0.0..=300.0).contains(&glyph.transform.x));
+                    assert!((0.0..=500.0).contains(&glyph.transform.y));
+                }
+                texts += 1;
+            }
+            DrawCommand::Background(_) | DrawCommand::BackgroundImage { .. } => {
+                panic!("The background is only drawn first")
+            }
+            DrawCommand::Image { .. } => {}
+        }
+    }
+    assert!(paths > 0);
+    assert!(texts > 0);
+}
+
+#[test]
+fn segment_time_below_column_value() {
+    use rendering::commands::{DrawCommand, Renderer};
+
+    let mut timer = Timer::new(tests_helper::create_run(&["A", "B", "C", "D"])).unwrap();
+    tests_helper::run_with_splits(&mut timer, &[5.0, 10.0, 15.0, 20.0]);
+
+    let mut image_cache = ImageCache::new();
+
+    // Returns the scales of all the glyphs of all the texts.
+    let mut render = |show_segment_time_below: bool| {
+        let mut component = component::splits::Component::new();
+        component.settings_mut().columns.truncate(1);
+        if let component::splits::ColumnKind::Time(column) =
+            &mut component.settings_mut().columns[0].kind
+        {
+            column.show_segment_time_below = show_segment_time_below;
+        }
+        let mut layout = Layout::new();
+        layout.push(component);
+
+        let state = layout.state(&mut image_cache, &timer.snapshot());
+        Renderer::new()
+            .render(&state, &image_cache, [300.0, 500.0])
+            .into_iter()
+            .filter_map(|command| match command {
+                DrawCommand::Text { glyphs, .. } => Some(
+                    glyphs
+                        .iter()
+                        .map(|g| g.transform.scale_y)
+                        .collect::<Vec<_>>(),
+                ),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let without = render(false);
+    let with = render(true);
+
+    // Each of the four segments shows its segment time in addition to its
+    // split time.
+    assert_eq!(with.len(), without.len() + 4);
+
+    // The segment times are shown smaller than the rest of the texts. The
+    // rows get taller, so all the texts get scaled down when rendering at the
+    // same resolution. That's why the scales are compared relative to each
+    // other.
+    let relative_scale = |texts: &[Vec<f32>]| {
+        let scales = texts.iter().flatten().copied();
+        let smallest = scales.clone().fold(f32::INFINITY, f32::min);
+        let largest = scales.fold(0.0, f32::max);
+        smallest / largest
+    };
+    assert!(relative_scale(&with) < relative_scale(&without));
+}
+
+#[test]
+fn medal_backgrounds() {
+    use component::splits::{MedalBands, MedalColors};
+    use rendering::{
+        FillShader,
+        commands::{DrawCommand, Renderer},
+    };
+
+    let mut timer = Timer::new(tests_helper::create_run(&["A", "B", "C", "D"])).unwrap();
+    tests_helper::run_with_splits_opt(&mut timer, &[Some(5.0), Some(10.0), Some(15.0), Some(20.0)]);
+
+    // The segments are 0, 1.5 and 3.5 seconds slower than their best segments.
+    tests_helper::start_run(&mut timer);
+    tests_helper::make_progress_run_with_splits_opt(
+        &mut timer,
+        &[Some(5.0), Some(11.5), Some(20.0)],
+    );
+
+    let mut image_cache = ImageCache::new();
+
+    // Returns all the distinct solid colors that are drawn.
+    let mut render = |medal_backgrounds: Option<MedalColors>| {
+        let mut component = component::splits::Component::new();
+        let settings = component.settings_mut();
+        settings.medal_bands = Some(MedalBands {
+            gold: TimeSpan::from_seconds(1.0),
+            silver: TimeSpan::from_seconds(2.0),
+            bronze: TimeSpan::from_seconds(5.0),
+        });
+        settings.medal_backgrounds = medal_backgrounds;
+        let mut layout = Layout::new();
+        layout.push(component);
+
+        let state = layout.state(&mut image_cache, &timer.snapshot());
+        let mut colors = Vec::new();
+        for command in Renderer::new().render(&state, &image_cache, [300.0, 500.0]) {
+            if let DrawCommand::FillPath {
+                shader: FillShader::SolidColor(color),
+                ..
+            } = command
+            {
+                if !colors.contains(&color) {
+                    colors.push(color);
+                }
+            }
+        }
+        colors
+    };
+
+    let without = render(None);
+    let with = render(Some(MedalColors::default()));
+
+    let medal_colors: Vec<_> = with.iter().filter(|c| !without.contains(c)).collect();
+    assert_eq!(medal_colors.len(), 3);
+}
+
 #[track_caller]
 fn check(
     state: &LayoutState,
@@ -611,3 +1097,39 @@ actual: {calculated_hash} {}",
         );
     }
 }
+
+#[cfg(feature = "software-rendering")]
+#[test]
+fn layout_transform() {
+    use component::blank_space;
+
+    let timer = Timer::new(tests_helper::create_run(&["A"])).unwrap();
+    let mut layout = Layout::new();
+    for size in [24, 72] {
+        layout.push(blank_space::Component::with_settings(
+            blank_space::Settings {
+                size,
+                ..Default::default()
+            },
+        ));
+    }
+
+    let mut image_cache = ImageCache::new();
+    let state = layout.state(&mut image_cache, &timer.snapshot());
+
+    let mut renderer = rendering::software::Renderer::new();
+    renderer.render(&state, &image_cache, [200, 400]);
+    let transform = renderer.layout_transform();
+
+    // The layout is 4 units tall, so each unit is 100 pixels. The second
+    // component spans from 1 to 4 units vertically and the whole width of 2
+    // units horizontally.
+    let to_pixels = |[x, y]: [f32; 2]| {
+        [
+            transform.x + transform.scale_x * x,
+            transform.y + transform.scale_y * y,
+        ]
+    };
+    assert_eq!(to_pixels([0.0, 1.0]), [0.0, 100.0]);
+    assert_eq!(to_pixels([2.0, 4.0]), [200.0, 400.0]);
+}
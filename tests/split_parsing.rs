@@ -10,6 +10,7 @@ mod parse {
             source_live_timer, speedrun_igt, splitterino, splitterz, time_split_tracker, urn,
             wsplit,
         },
+        timing::formatter::Accuracy,
     };
 
     #[track_caller]
@@ -52,6 +53,12 @@ mod parse {
         livesplit(run_files::LIVESPLIT_1_6);
     }
 
+    #[test]
+    fn livesplit_1_6_has_no_time_precision_hint() {
+        let run = livesplit(run_files::LIVESPLIT_1_6);
+        assert_eq!(run.time_precision_hint(), None);
+    }
+
     #[test]
     fn livesplit_1_6_gametime() {
         livesplit(run_files::LIVESPLIT_1_6_GAMETIME);
@@ -125,6 +132,12 @@ mod parse {
         splitterz::parse(run_files::SPLITTERZ, false).unwrap();
     }
 
+    #[test]
+    fn splitterz_has_a_time_precision_hint_of_hundredths() {
+        let run = splitterz::parse(run_files::SPLITTERZ, false).unwrap();
+        assert_eq!(run.time_precision_hint(), Some(Accuracy::Hundredths));
+    }
+
     #[test]
     fn wsplit() {
         wsplit::parse(run_files::WSPLIT, false).unwrap();
@@ -145,6 +158,48 @@ mod parse {
         flitter::parse(run_files::FLITTER).unwrap();
     }
 
+    #[test]
+    fn flitter_toml() {
+        let run = flitter::parse_toml(run_files::FLITTER_TOML).unwrap();
+
+        assert_eq!(run.game_name(), "Super Monkey Ball 2: Monkeyed Ball");
+        assert_eq!(run.category_name(), "Story Mode All Levels");
+        assert_eq!(run.attempt_count(), 3316);
+
+        let names = run.segments().iter().map(|s| s.name()).collect::<Vec<_>>();
+        assert_eq!(names, ["World 1", "World 2", "World 3", "World 4"]);
+
+        let pb_splits = run
+            .segments()
+            .iter()
+            .map(|s| s.personal_best_split_time().real_time)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            pb_splits,
+            ["2:12.5", "4:33.1", "6:58.75", "1:09:16.2"].map(|t| t.parse::<TimeSpan>().ok()),
+        );
+
+        assert_eq!(
+            run.segment(0).best_segment_time().real_time,
+            "2:11.832".parse::<TimeSpan>().ok(),
+        );
+        assert_eq!(run.segment(2).best_segment_time().real_time, None);
+    }
+
+    #[test]
+    fn flitter_toml_skips_unknown_tables() {
+        let source = format!(
+            "{}\n[settings] # Not part of the splits\ntitle = \"Dark\"\n",
+            run_files::FLITTER_TOML,
+        );
+        let run = flitter::parse_toml(&source).unwrap();
+        assert_eq!(run.len(), 4);
+        assert_eq!(run.segment(3).name(), "World 4");
+
+        let source = format!("{}\n[settings\n", run_files::FLITTER_TOML);
+        assert!(flitter::parse_toml(&source).is_err());
+    }
+
     #[test]
     fn source_live_timer() {
         source_live_timer::parse(run_files::SOURCE_LIVE_TIMER).unwrap();
@@ -205,4 +260,10 @@ mod parse {
         let run = composite::parse(run_files::FLITTER.as_bytes(), None).unwrap();
         assert_eq!(run.kind, TimerKind::Flitter);
     }
+
+    #[test]
+    fn flitter_toml_prefers_parsing_as_itself() {
+        let run = composite::parse(run_files::FLITTER_TOML.as_bytes(), None).unwrap();
+        assert_eq!(run.kind, TimerKind::Flitter);
+    }
 }